@@ -0,0 +1,120 @@
+//! Comparison of SIMD-accelerated and generic operations.
+//!
+//! Run with `cargo bench --features simd --bench simd`.
+
+use std::{hint::black_box, time::Instant};
+use vecmat::{matrix::Matrix4x4, prelude::*, Quaternion, Vector};
+
+const ITERATIONS: usize = 1_000_000;
+
+fn measure<F: FnMut()>(mut f: F) -> f64 {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed().as_nanos() as f64 / ITERATIONS as f64
+}
+
+fn compare<A: Copy, R, G: Fn(A, A) -> R, S: Fn(A, A) -> R>(name: &str, x: A, generic: G, simd: S) {
+    let generic = measure(|| {
+        black_box(generic(black_box(x), black_box(x)));
+    });
+    let simd = measure(|| {
+        black_box(simd(black_box(x), black_box(x)));
+    });
+    println!(
+        "{}: generic {:.1} ns, simd {:.1} ns (x{:.1})",
+        name,
+        generic,
+        simd,
+        generic / simd,
+    );
+}
+
+fn main() {
+    let mut k = 0.0;
+    let mut next = || {
+        k += 1.0;
+        1.0 / k
+    };
+    let vf4 = Vector::<f32, 4>::init(|| next() as f32);
+    let vd2 = Vector::<f64, 2>::init(&mut next);
+    let vd4 = Vector::<f64, 4>::init(&mut next);
+    let m =
+        Matrix4x4::<f32>::from_vector_of_vectors(Vector::init(|| Vector::init(|| next() as f32)));
+    let qf = Quaternion::<f32>::from_vector(Vector::init(|| next() as f32));
+    let qd = Quaternion::<f64>::from_vector(Vector::init(&mut next));
+
+    compare(
+        "Vector<f32, 4> add",
+        vf4,
+        |a, b| a + b,
+        |a, b| a.simd_add(b),
+    );
+    compare(
+        "Vector<f32, 4> mul",
+        vf4,
+        |a, b| a * b,
+        |a, b| a.simd_mul(b),
+    );
+    compare(
+        "Vector<f32, 4> div",
+        vf4,
+        |a, b| a / b,
+        |a, b| a.simd_div(b),
+    );
+    compare(
+        "Vector<f32, 4> dot",
+        vf4,
+        |a, b| a.dot(b),
+        |a, b| a.simd_dot(b),
+    );
+    compare(
+        "Vector<f64, 2> add",
+        vd2,
+        |a, b| a + b,
+        |a, b| a.simd_add(b),
+    );
+    compare(
+        "Vector<f64, 2> dot",
+        vd2,
+        |a, b| a.dot(b),
+        |a, b| a.simd_dot(b),
+    );
+    compare(
+        "Vector<f64, 4> add",
+        vd4,
+        |a, b| a + b,
+        |a, b| a.simd_add(b),
+    );
+    compare(
+        "Vector<f64, 4> dot",
+        vd4,
+        |a, b| a.dot(b),
+        |a, b| a.simd_dot(b),
+    );
+    compare(
+        "Matrix4x4<f32> dot",
+        m,
+        |a, b| a.dot(b),
+        |a, b| a.simd_dot(b),
+    );
+    compare(
+        "Matrix4x4<f32> dot vector",
+        m,
+        |a, b| a.dot(b.row(0)),
+        |a, b| a.simd_dot_vector(b.row(0)),
+    );
+    compare(
+        "Quaternion<f32> mul",
+        qf,
+        |a, b| a * b,
+        |a, b| a.simd_mul(b),
+    );
+    compare(
+        "Quaternion<f64> mul",
+        qd,
+        |a, b| a * b,
+        |a, b| a.simd_mul(b),
+    );
+}
@@ -0,0 +1,53 @@
+//! Comparison of closed-form and Laplace expansion determinant and adjugate of small matrices.
+//!
+//! Run with `cargo bench --bench square`.
+
+use std::{hint::black_box, time::Instant};
+use vecmat::Matrix;
+
+const ITERATIONS: usize = 100_000;
+
+fn sample<const N: usize>() -> Matrix<f64, N, N> {
+    Matrix::indices().map(|(i, j)| 1.0 / (1.0 + i as f64 + 2.0 * j as f64) + (i == j) as u8 as f64)
+}
+
+fn measure<F: FnMut()>(mut f: F) -> f64 {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed().as_nanos() as f64 / ITERATIONS as f64
+}
+
+fn bench<const N: usize>() {
+    let m = sample::<N>();
+    let det = measure(|| {
+        black_box(black_box(&m).det());
+    });
+    let det_laplace = measure(|| {
+        black_box(black_box(&m).det_laplace());
+    });
+    let adj = measure(|| {
+        black_box(black_box(&m).adj());
+    });
+    let adj_laplace = measure(|| {
+        black_box(black_box(&m).adj_laplace());
+    });
+    println!(
+        "{}x{}: det {:.1} ns (laplace {:.1} ns, x{:.1}), adj {:.1} ns (laplace {:.1} ns, x{:.1})",
+        N,
+        N,
+        det,
+        det_laplace,
+        det_laplace / det,
+        adj,
+        adj_laplace,
+        adj_laplace / adj,
+    );
+}
+
+fn main() {
+    bench::<2>();
+    bench::<3>();
+    bench::<4>();
+}
@@ -0,0 +1,16 @@
+use super::Jet;
+use approx::{abs_diff_eq, AbsDiffEq};
+
+impl<T, const N: usize> AbsDiffEq for Jet<T, N>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.value(), other.value(), epsilon = epsilon)
+            && abs_diff_eq!(self.grad(), other.grad(), epsilon = epsilon)
+    }
+}
@@ -0,0 +1,295 @@
+use super::Jet;
+use core::num::FpCategory;
+use num_traits::{Float, FloatConst, Num, NumCast, ToPrimitive, Zero};
+
+impl<T, const N: usize> Jet<T, N>
+where
+    T: Float,
+{
+    /// Apply scalar function which value is `val` and derivative is `deriv` at the current point.
+    fn chain_rule(self, val: T, deriv: T) -> Self {
+        Self::new(val, self.grad().map(|g| g * deriv))
+    }
+}
+
+impl<T, const N: usize> Num for Jet<T, N>
+where
+    T: Float,
+{
+    type FromStrRadixErr = T::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+impl<T, const N: usize> ToPrimitive for Jet<T, N>
+where
+    T: ToPrimitive,
+{
+    fn to_i64(&self) -> Option<i64> {
+        self.value_ref().to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.value_ref().to_u64()
+    }
+    fn to_f32(&self) -> Option<f32> {
+        self.value_ref().to_f32()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        self.value_ref().to_f64()
+    }
+}
+
+impl<T, const N: usize> NumCast for Jet<T, N>
+where
+    T: NumCast + Zero,
+{
+    fn from<U: ToPrimitive>(n: U) -> Option<Self> {
+        <T as NumCast>::from(n).map(Self::constant)
+    }
+}
+
+macro_rules! const_fn {
+    ($f:ident) => {
+        fn $f() -> Self {
+            Self::constant(T::$f())
+        }
+    };
+}
+macro_rules! value_fn {
+    ($f:ident, $R:ty) => {
+        fn $f(self) -> $R {
+            self.value().$f()
+        }
+    };
+}
+macro_rules! step_fn {
+    ($f:ident) => {
+        fn $f(self) -> Self {
+            Self::constant(self.value().$f())
+        }
+    };
+}
+
+impl<T, const N: usize> Float for Jet<T, N>
+where
+    T: Float,
+{
+    const_fn!(nan);
+    const_fn!(infinity);
+    const_fn!(neg_infinity);
+    const_fn!(neg_zero);
+    const_fn!(min_value);
+    const_fn!(min_positive_value);
+    const_fn!(max_value);
+    const_fn!(epsilon);
+
+    value_fn!(is_nan, bool);
+    value_fn!(is_infinite, bool);
+    value_fn!(is_finite, bool);
+    value_fn!(is_normal, bool);
+    value_fn!(classify, FpCategory);
+    value_fn!(is_sign_positive, bool);
+    value_fn!(is_sign_negative, bool);
+    value_fn!(integer_decode, (u64, i16, i8));
+
+    step_fn!(floor);
+    step_fn!(ceil);
+    step_fn!(round);
+    step_fn!(trunc);
+    step_fn!(signum);
+
+    fn fract(self) -> Self {
+        Self::new(self.value().fract(), self.grad())
+    }
+    fn abs(self) -> Self {
+        if self.value().is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+    fn recip(self) -> Self {
+        let r = self.value().recip();
+        self.chain_rule(r, -r * r)
+    }
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            // Avoid `0 * inf` in the derivative at zero.
+            return Self::constant(T::one());
+        }
+        let x = self.value();
+        self.chain_rule(x.powi(n), T::from(n).unwrap() * x.powi(n - 1))
+    }
+    fn powf(self, n: Self) -> Self {
+        let (x, p) = (self.value(), n.value());
+        let val = x.powf(p);
+        let base = self.chain_rule(val, p * x.powf(p - T::one()));
+        if n.grad().is_zero() {
+            base
+        } else {
+            Self::new(val, base.grad() + n.grad() * (val * x.ln()))
+        }
+    }
+    fn sqrt(self) -> Self {
+        let s = self.value().sqrt();
+        self.chain_rule(s, (s + s).recip())
+    }
+    fn cbrt(self) -> Self {
+        let c = self.value().cbrt();
+        self.chain_rule(c, (T::from(3).unwrap() * c * c).recip())
+    }
+    fn exp(self) -> Self {
+        let e = self.value().exp();
+        self.chain_rule(e, e)
+    }
+    fn exp2(self) -> Self {
+        let e = self.value().exp2();
+        self.chain_rule(e, e * T::from(2).unwrap().ln())
+    }
+    fn exp_m1(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.exp_m1(), x.exp())
+    }
+    fn ln(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.ln(), x.recip())
+    }
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+    fn log2(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.log2(), (x * T::from(2).unwrap().ln()).recip())
+    }
+    fn log10(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.log10(), (x * T::from(10).unwrap().ln()).recip())
+    }
+    fn ln_1p(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.ln_1p(), (T::one() + x).recip())
+    }
+    fn max(self, other: Self) -> Self {
+        if self.value() < other.value() || self.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+    fn min(self, other: Self) -> Self {
+        if other.value() < self.value() || self.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        if self.value() <= other.value() {
+            Self::zero()
+        } else {
+            self - other
+        }
+    }
+    fn hypot(self, other: Self) -> Self {
+        let h = self.value().hypot(other.value());
+        Self::new(
+            h,
+            (self.grad() * self.value() + other.grad() * other.value()) / h,
+        )
+    }
+    fn sin(self) -> Self {
+        let (s, c) = self.value().sin_cos();
+        self.chain_rule(s, c)
+    }
+    fn cos(self) -> Self {
+        let (s, c) = self.value().sin_cos();
+        self.chain_rule(c, -s)
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = self.value().sin_cos();
+        (self.chain_rule(s, c), self.chain_rule(c, -s))
+    }
+    fn tan(self) -> Self {
+        let t = self.value().tan();
+        self.chain_rule(t, T::one() + t * t)
+    }
+    fn asin(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.asin(), (T::one() - x * x).sqrt().recip())
+    }
+    fn acos(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.acos(), -(T::one() - x * x).sqrt().recip())
+    }
+    fn atan(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.atan(), (T::one() + x * x).recip())
+    }
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self.value(), other.value());
+        let r2 = x * x + y * y;
+        Self::new(y.atan2(x), (self.grad() * x - other.grad() * y) / r2)
+    }
+    fn sinh(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.sinh(), x.cosh())
+    }
+    fn cosh(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.cosh(), x.sinh())
+    }
+    fn tanh(self) -> Self {
+        let t = self.value().tanh();
+        self.chain_rule(t, T::one() - t * t)
+    }
+    fn asinh(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.asinh(), (x * x + T::one()).sqrt().recip())
+    }
+    fn acosh(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.acosh(), (x * x - T::one()).sqrt().recip())
+    }
+    fn atanh(self) -> Self {
+        let x = self.value();
+        self.chain_rule(x.atanh(), (T::one() - x * x).recip())
+    }
+}
+
+macro_rules! float_const {
+    ($($f:ident),* $(,)?) => {
+        $(
+            fn $f() -> Self {
+                Self::constant(T::$f())
+            }
+        )*
+    };
+}
+
+impl<T, const N: usize> FloatConst for Jet<T, N>
+where
+    T: FloatConst + Zero,
+{
+    float_const!(
+        E,
+        FRAC_1_PI,
+        FRAC_1_SQRT_2,
+        FRAC_2_PI,
+        FRAC_2_SQRT_PI,
+        FRAC_PI_2,
+        FRAC_PI_3,
+        FRAC_PI_4,
+        FRAC_PI_6,
+        FRAC_PI_8,
+        LN_10,
+        LN_2,
+        LOG10_E,
+        LOG2_E,
+        PI,
+        SQRT_2,
+    );
+}
@@ -0,0 +1,27 @@
+use super::Jet;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+impl<T: Debug, const N: usize> Debug for Jet<T, N> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Jet({:?}, {:?})", self.value_ref(), self.grad_ref())
+    }
+}
+impl<T: Display, const N: usize> Display for Jet<T, N> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Jet({}, {})", self.value_ref(), self.grad_ref())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::Vector;
+    use std::format;
+
+    #[test]
+    fn jet() {
+        let x = Jet::<i32, 2>::new(1, Vector::from([-2, 3]));
+        assert_eq!(format!("{:?}", x), format!("Jet(1, {:?})", x.grad()));
+        assert_eq!(format!("{}", x), format!("Jet(1, {})", x.grad()));
+    }
+}
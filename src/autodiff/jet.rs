@@ -0,0 +1,350 @@
+use crate::{Matrix, Vector};
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+use num_traits::{Float, Num, One, Zero};
+
+/// Number with the gradient over `N` independent variables attached.
+///
+/// All arithmetic operations and elementary functions propagate the gradient
+/// according to the chain rule (forward-mode automatic differentiation).
+///
+/// Comparison operators only take the value into account.
+/// In contrast, [`Zero::is_zero`] checks that the number is the additive identity,
+/// so a variable at zero compares equal to `Jet::zero()` but is not zero itself.
+#[derive(Clone, Copy, Default)]
+pub struct Jet<T, const N: usize> {
+    val: T,
+    grad: Vector<T, N>,
+}
+
+/// Dual number `a + b ε` where `ε² = 0`.
+///
+/// This is a scalar for differentiation of functions of one variable,
+/// see `complex::DualNumber` for the two-dimensional algebra element.
+pub type Dual<T> = Jet<T, 1>;
+
+impl<T, const N: usize> Jet<T, N> {
+    /// Construct from value and gradient.
+    pub fn new(val: T, grad: Vector<T, N>) -> Self {
+        Self { val, grad }
+    }
+    /// Split into value and gradient.
+    pub fn split(self) -> (T, Vector<T, N>) {
+        (self.val, self.grad)
+    }
+    /// Get value reference.
+    pub fn value_ref(&self) -> &T {
+        &self.val
+    }
+    /// Get value mutable reference.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
+    /// Get gradient reference.
+    pub fn grad_ref(&self) -> &Vector<T, N> {
+        &self.grad
+    }
+    /// Get gradient mutable reference.
+    pub fn grad_mut(&mut self) -> &mut Vector<T, N> {
+        &mut self.grad
+    }
+}
+
+impl<T, const N: usize> Jet<T, N>
+where
+    T: Copy,
+{
+    /// Value of the number.
+    pub fn value(&self) -> T {
+        self.val
+    }
+    /// Gradient of the number.
+    pub fn grad(&self) -> Vector<T, N> {
+        self.grad
+    }
+}
+
+impl<T, const N: usize> Jet<T, N>
+where
+    T: Zero,
+{
+    /// Number that doesn't depend on any variable.
+    pub fn constant(val: T) -> Self {
+        Self::new(val, Vector::zero())
+    }
+}
+
+impl<T, const N: usize> Jet<T, N>
+where
+    T: Zero + One,
+{
+    /// `i`-th independent variable with specified value.
+    pub fn variable(val: T, i: usize) -> Self {
+        assert!(i < N);
+        let mut grad = Vector::<T, N>::zero();
+        grad[i] = T::one();
+        Self::new(val, grad)
+    }
+}
+
+impl<T> Dual<T> {
+    /// Construct dual number from real and infinitesimal parts.
+    pub fn from_parts(re: T, eps: T) -> Self {
+        Self::new(re, [eps].into())
+    }
+}
+impl<T: Copy> Dual<T> {
+    /// Real part of the dual number.
+    pub fn re(&self) -> T {
+        self.val
+    }
+    /// Infinitesimal part of the dual number.
+    pub fn eps(&self) -> T {
+        self.grad[0]
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Zero + One,
+{
+    /// Treat each component of the vector as an independent variable.
+    pub fn into_variables(self) -> Vector<Jet<T, N>, N> {
+        self.enumerate().map(|(i, x)| Jet::variable(x, i))
+    }
+}
+
+impl<T, const M: usize, const N: usize> Vector<Jet<T, N>, M> {
+    /// Values of the vector components.
+    pub fn values(self) -> Vector<T, M> {
+        self.map(|x| x.val)
+    }
+    /// Matrix which rows are gradients of the vector components.
+    pub fn jacobian(self) -> Matrix<T, M, N> {
+        Matrix::from_vector_of_vectors(self.map(|x| x.grad))
+    }
+}
+
+impl<T, const N: usize> From<T> for Jet<T, N>
+where
+    T: Zero,
+{
+    fn from(val: T) -> Self {
+        Self::constant(val)
+    }
+}
+
+impl<T, const N: usize> PartialEq for Jet<T, N>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.val == other.val
+    }
+}
+impl<T, const N: usize> PartialOrd for Jet<T, N>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.val.partial_cmp(&other.val)
+    }
+}
+
+impl<T, const N: usize> Neg for Jet<T, N>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.val, -self.grad)
+    }
+}
+
+impl<T, const N: usize> Add for Jet<T, N>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.val + other.val, self.grad + other.grad)
+    }
+}
+impl<T, const N: usize> Sub for Jet<T, N>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.val - other.val, self.grad - other.grad)
+    }
+}
+impl<T, const N: usize> Mul for Jet<T, N>
+where
+    T: Add<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.val * other.val,
+            self.grad * other.val + other.grad * self.val,
+        )
+    }
+}
+impl<T, const N: usize> Div for Jet<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        let val = self.val / other.val;
+        Self::new(val, (self.grad - other.grad * val) / other.val)
+    }
+}
+impl<T, const N: usize> Rem for Jet<T, N>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        let q = (self.val / other.val).trunc();
+        Self::new(self.val % other.val, self.grad - other.grad * q)
+    }
+}
+
+impl<T, const N: usize> Add<T> for Jet<T, N>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: T) -> Self {
+        Self::new(self.val + other, self.grad)
+    }
+}
+impl<T, const N: usize> Sub<T> for Jet<T, N>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: T) -> Self {
+        Self::new(self.val - other, self.grad)
+    }
+}
+impl<T, const N: usize> Mul<T> for Jet<T, N>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: T) -> Self {
+        Self::new(self.val * other, self.grad * other)
+    }
+}
+impl<T, const N: usize> Div<T> for Jet<T, N>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    fn div(self, other: T) -> Self {
+        Self::new(self.val / other, self.grad / other)
+    }
+}
+
+impl<T, const N: usize> AddAssign for Jet<T, N>
+where
+    Self: Add<Output = Self> + Copy,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+impl<T, const N: usize> SubAssign for Jet<T, N>
+where
+    Self: Sub<Output = Self> + Copy,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+impl<T, const N: usize> MulAssign for Jet<T, N>
+where
+    Self: Mul<Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+impl<T, const N: usize> DivAssign for Jet<T, N>
+where
+    Self: Div<Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+impl<T, const N: usize> RemAssign for Jet<T, N>
+where
+    Self: Rem<Output = Self> + Copy,
+{
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+macro_rules! reverse_ops {
+    ($T:ident) => {
+        /// Workaround for reverse addition.
+        impl<const N: usize> Add<Jet<$T, N>> for $T {
+            type Output = Jet<$T, N>;
+            fn add(self, other: Jet<$T, N>) -> Self::Output {
+                other + self
+            }
+        }
+        /// Workaround for reverse subtraction.
+        impl<const N: usize> Sub<Jet<$T, N>> for $T {
+            type Output = Jet<$T, N>;
+            fn sub(self, other: Jet<$T, N>) -> Self::Output {
+                -other + self
+            }
+        }
+        /// Workaround for reverse multiplication.
+        impl<const N: usize> Mul<Jet<$T, N>> for $T {
+            type Output = Jet<$T, N>;
+            fn mul(self, other: Jet<$T, N>) -> Self::Output {
+                other * self
+            }
+        }
+        /// Workaround for reverse division.
+        impl<const N: usize> Div<Jet<$T, N>> for $T {
+            type Output = Jet<$T, N>;
+            fn div(self, other: Jet<$T, N>) -> Self::Output {
+                Jet::constant(self) / other
+            }
+        }
+    };
+}
+
+reverse_ops!(f32);
+reverse_ops!(f64);
+
+impl<T, const N: usize> Zero for Jet<T, N>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+    /// Both value and gradient are zero, unlike `==` that compares values only.
+    fn is_zero(&self) -> bool {
+        self.val.is_zero() && self.grad.is_zero()
+    }
+}
+impl<T, const N: usize> One for Jet<T, N>
+where
+    T: Zero + One + Copy,
+{
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
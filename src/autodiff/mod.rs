@@ -0,0 +1,15 @@
+//! Forward-mode automatic differentiation.
+//!
+//! `Jet<T, N>` implements `num_traits::Float` when `T` does,
+//! so it could be used as a scalar type of vectors, matrices and transformations
+//! to compute exact derivatives of any computation over them.
+
+#[cfg(feature = "approx")]
+mod approx;
+mod float;
+mod format;
+mod jet;
+#[cfg(all(test, feature = "approx"))]
+mod tests;
+
+pub use jet::*;
@@ -0,0 +1,144 @@
+use super::*;
+use crate::{
+    complex::{Complex, Moebius, Quaternion},
+    matrix::Matrix3x3,
+    traits::Dot,
+    transform::{Affine3, Jacobian, Linear, Rotation3, Shift},
+    vector::Vector3,
+    Map,
+};
+use ::approx::assert_abs_diff_eq;
+use num_traits::{Float, Zero};
+
+const EPS: f64 = 1e-12;
+
+type J3 = Jet<f64, 3>;
+
+#[test]
+fn dual_arithmetic() {
+    let x = Dual::<f64>::variable(3.0, 0);
+    let y = x * x * 2.0 - x / 3.0 + 1.0;
+    assert_abs_diff_eq!(y.re(), 18.0, epsilon = EPS);
+    assert_abs_diff_eq!(y.eps(), 12.0 - 1.0 / 3.0, epsilon = EPS);
+
+    let z = 1.0 / x;
+    assert_abs_diff_eq!(z.re(), 1.0 / 3.0, epsilon = EPS);
+    assert_abs_diff_eq!(z.eps(), -1.0 / 9.0, epsilon = EPS);
+
+    let c = Dual::<f64>::constant(5.0);
+    assert_abs_diff_eq!((c * x).eps(), 5.0, epsilon = EPS);
+    assert_abs_diff_eq!((c + c).eps(), 0.0, epsilon = EPS);
+}
+
+#[test]
+fn dual_functions() {
+    for i in 1..16 {
+        let v = 0.0625 * i as f64;
+        let x = Dual::<f64>::variable(v, 0);
+        assert_abs_diff_eq!(x.sin().eps(), v.cos(), epsilon = EPS);
+        assert_abs_diff_eq!(x.cos().eps(), -v.sin(), epsilon = EPS);
+        assert_abs_diff_eq!(x.tan().eps(), v.cos().powi(-2), epsilon = EPS);
+        assert_abs_diff_eq!(x.exp().eps(), v.exp(), epsilon = EPS);
+        assert_abs_diff_eq!(x.ln().eps(), 1.0 / v, epsilon = EPS);
+        assert_abs_diff_eq!(x.sqrt().eps(), 0.5 / v.sqrt(), epsilon = EPS);
+        assert_abs_diff_eq!(x.powi(3).eps(), 3.0 * v * v, epsilon = EPS);
+        assert_abs_diff_eq!(x.powf(x).eps(), v.powf(v) * (v.ln() + 1.0), epsilon = EPS);
+        assert_abs_diff_eq!(x.asin().eps(), 1.0 / (1.0 - v * v).sqrt(), epsilon = EPS);
+        assert_abs_diff_eq!(x.atan().eps(), 1.0 / (1.0 + v * v), epsilon = EPS);
+        assert_abs_diff_eq!(x.tanh().eps(), 1.0 - v.tanh().powi(2), epsilon = EPS);
+        assert_abs_diff_eq!(x.log10().eps(), 1.0 / (v * 10.0.ln()), epsilon = EPS);
+    }
+}
+
+#[test]
+fn gradient() {
+    let (x, y, z) = Vector3::from([0.5, -1.5, 2.0]).into_variables().into();
+    let f = x * y.sin() + z.exp() / x + y.atan2(z);
+    let (xv, yv, zv) = (0.5, -1.5, 2.0f64);
+    let r2 = yv * yv + zv * zv;
+    assert_abs_diff_eq!(
+        f,
+        J3::new(
+            xv * yv.sin() + zv.exp() / xv + yv.atan2(zv),
+            Vector3::from([
+                yv.sin() - zv.exp() / (xv * xv),
+                xv * yv.cos() + zv / r2,
+                zv.exp() / xv - yv / r2,
+            ]),
+        ),
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn linear() {
+    let m = Matrix3x3::from([[1.0, -2.0, 0.5], [0.0, 3.0, 1.0], [-1.0, 0.0, 2.0]]);
+    let x = Vector3::from([0.3, -0.7, 1.1]);
+    let y = m.map(J3::constant).dot(x.into_variables());
+    assert_abs_diff_eq!(y.values(), m.dot(x), epsilon = EPS);
+    assert_abs_diff_eq!(y.jacobian(), m, epsilon = EPS);
+}
+
+#[test]
+fn transform_deriv() {
+    let x = Vector3::from([0.3, -0.7, 1.1]);
+    let d = Vector3::from([-0.2, 0.9, 0.4]);
+    let jx = x.into_variables();
+
+    let q = Quaternion::new(0.5, -0.1, 0.7, 0.3).normalize();
+    let r = Rotation3::from_quaternion(q);
+    let jr = Rotation3::from_quaternion(Quaternion::from_vector(q.into_vector().map(J3::constant)));
+    let y = jr.apply(jx);
+    assert_abs_diff_eq!(y.values(), r.apply(x), epsilon = EPS);
+    assert_abs_diff_eq!(y.jacobian(), r.jacobian(x), epsilon = EPS);
+    assert_abs_diff_eq!(y.jacobian().dot(d), r.deriv(x, d), epsilon = EPS);
+
+    let m = Matrix3x3::from([[1.0, -2.0, 0.5], [0.0, 3.0, 1.0], [-1.0, 0.0, 2.0]]);
+    let s = Vector3::from([1.0, 2.0, -3.0]);
+    let a = Affine3::new(Shift::from_vector(s), Linear::from_matrix(m));
+    let ja = Affine3::new(
+        Shift::from_vector(s.map(J3::constant)),
+        Linear::from_matrix(m.map(J3::constant)),
+    );
+    let y = ja.apply(jx);
+    assert_abs_diff_eq!(y.values(), a.apply(x), epsilon = EPS);
+    assert_abs_diff_eq!(y.jacobian().dot(d), a.deriv(x, d), epsilon = EPS);
+    assert_abs_diff_eq!(y.jacobian().det(), a.jacobian_det(x), epsilon = EPS);
+}
+
+#[test]
+fn moebius_deriv() {
+    type J2 = Jet<f64, 2>;
+    let m = Moebius::new(
+        Complex::new(1.0, 0.5),
+        Complex::new(-0.3, 2.0),
+        Complex::new(0.7, -1.0),
+        Complex::new(2.0, 0.1),
+    );
+    let jm = Moebius::from_matrix(
+        m.into_matrix()
+            .map(|c| Complex::from_vector(c.into_vector().map(J2::constant))),
+    );
+    let z = Complex::new(0.4, -0.9);
+    let w = jm.apply(Complex::from_vector(z.into_vector().into_variables()));
+    let w = w.into_vector();
+    assert_abs_diff_eq!(w.values(), m.apply(z).into_vector(), epsilon = EPS);
+    assert_abs_diff_eq!(w.jacobian(), m.jacobian(z), epsilon = EPS);
+}
+
+#[test]
+fn zero() {
+    assert!(J3::zero().is_zero());
+    assert!(!J3::variable(0.0, 1).is_zero());
+    assert!(J3::variable(0.0, 1) == J3::zero());
+    assert_abs_diff_eq!(J3::from(2.0).grad(), Vector3::zero());
+}
+
+#[test]
+fn powi_zero() {
+    let x = J3::variable(0.0, 0);
+    assert_abs_diff_eq!(x.powi(0).value(), 1.0);
+    assert_abs_diff_eq!(x.powi(0).grad(), Vector3::zero());
+    assert_abs_diff_eq!(x.powi(1).grad(), Vector3::from([1.0, 0.0, 0.0]));
+    assert_abs_diff_eq!(x.powi(2).grad(), Vector3::zero());
+}
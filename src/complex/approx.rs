@@ -1,5 +1,8 @@
-use crate::complex::{Complex, Moebius, Quaternion};
+use crate::complex::{
+    Complex, DualNumber, Moebius, Octonion, ProjectiveComplex, Quaternion, SplitComplex,
+};
 use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::Float;
 
 impl<T> AbsDiffEq for Complex<T>
 where
@@ -14,6 +17,32 @@ where
     }
 }
 
+impl<T> AbsDiffEq for SplitComplex<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.into_vector(), other.into_vector(), epsilon = epsilon)
+    }
+}
+
+impl<T> AbsDiffEq for DualNumber<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.into_vector(), other.into_vector(), epsilon = epsilon)
+    }
+}
+
 impl<T> AbsDiffEq for Quaternion<T>
 where
     T: AbsDiffEq<Epsilon = T> + Copy,
@@ -27,6 +56,19 @@ where
     }
 }
 
+impl<T> AbsDiffEq for Octonion<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.into_vector(), other.into_vector(), epsilon = epsilon)
+    }
+}
+
 impl<T> AbsDiffEq for Moebius<T>
 where
     T: AbsDiffEq<Epsilon = T> + Copy,
@@ -39,3 +81,17 @@ where
         abs_diff_eq!(self.into_matrix(), other.into_matrix(), epsilon = epsilon)
     }
 }
+
+/// Points are compared on the Riemann sphere, so the infinity is handled properly.
+impl<T> AbsDiffEq for ProjectiveComplex<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Float,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.to_sphere(), other.to_sphere(), epsilon = epsilon)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::traits::{Conj, Dot};
+use core::ops::{Add, Mul, Neg, Sub};
+use num_traits::{One, Zero};
+
+/// Hypercomplex number obtained by Cayley-Dickson construction over algebra `A`.
+///
+/// Pair `(re, im)` represents `re + im l` where `l` is a new imaginary unit.
+/// Multiplication is defined as `(a, b) (c, d) = (a c - conj(d) b, d a + b conj(c))`.
+///
+/// E.g. `CayleyDickson<Complex<T>>` is isomorphic to `Quaternion<T>`,
+/// `CayleyDickson<Quaternion<T>>` is isomorphic to `Octonion<T>`,
+/// and `CayleyDickson<Octonion<T>>` gives sedenions.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct CayleyDickson<A> {
+    re: A,
+    im: A,
+}
+
+impl<A> CayleyDickson<A> {
+    /// Construct from two halves.
+    pub fn new(re: A, im: A) -> Self {
+        Self { re, im }
+    }
+    /// Split into two halves.
+    pub fn split(self) -> (A, A) {
+        (self.re, self.im)
+    }
+    /// Get first half reference.
+    pub fn re_ref(&self) -> &A {
+        &self.re
+    }
+    /// Get second half reference.
+    pub fn im_ref(&self) -> &A {
+        &self.im
+    }
+}
+
+impl<A> CayleyDickson<A>
+where
+    A: Copy,
+{
+    /// First half of the number.
+    pub fn re(&self) -> A {
+        self.re
+    }
+    /// Second half of the number.
+    pub fn im(&self) -> A {
+        self.im
+    }
+}
+
+impl<A> From<(A, A)> for CayleyDickson<A> {
+    fn from((re, im): (A, A)) -> Self {
+        Self::new(re, im)
+    }
+}
+impl<A> From<CayleyDickson<A>> for (A, A) {
+    fn from(cd: CayleyDickson<A>) -> Self {
+        cd.split()
+    }
+}
+
+impl<A> Neg for CayleyDickson<A>
+where
+    A: Neg<Output = A>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<A> Conj for CayleyDickson<A>
+where
+    A: Conj + Neg<Output = A>,
+{
+    fn conj(self) -> Self {
+        Self::new(self.re.conj(), -self.im)
+    }
+}
+
+impl<A> Add for CayleyDickson<A>
+where
+    A: Add<Output = A>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+impl<A> Sub for CayleyDickson<A>
+where
+    A: Sub<Output = A>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<A> Mul for CayleyDickson<A>
+where
+    A: Mul<Output = A> + Add<Output = A> + Sub<Output = A> + Conj + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = self.split();
+        let (c, d) = other.split();
+        Self::new(a * c - d.conj() * b, d * a + b * c.conj())
+    }
+}
+
+impl<A> Zero for CayleyDickson<A>
+where
+    A: Zero,
+{
+    fn zero() -> Self {
+        Self::new(A::zero(), A::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+impl<A> One for CayleyDickson<A>
+where
+    A: Zero + One + Sub<Output = A> + Conj + Copy,
+{
+    fn one() -> Self {
+        Self::new(A::one(), A::zero())
+    }
+}
+
+impl<A, T> Dot for CayleyDickson<A>
+where
+    A: Dot<Output = T>,
+    T: Add<Output = T>,
+{
+    type Output = T;
+    fn dot(self, other: Self) -> T {
+        self.re.dot(other.re) + self.im.dot(other.im)
+    }
+}
+
+impl<A, T> CayleyDickson<A>
+where
+    A: Dot<Output = T> + Copy,
+    T: Add<Output = T>,
+{
+    /// Square of the norm, i.e. `self * conj(self)`.
+    pub fn norm_sqr(self) -> T {
+        self.dot(self)
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
 };
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 use num_complex::{Complex as NumComplex, ParseComplexError};
-use num_traits::{Float, Num, One, Zero, Inv};
+use num_traits::{ConstOne, ConstZero, Float, Num, One, Zero, Inv};
 
 /// Complex number.
 #[repr(transparent)]
@@ -15,14 +15,18 @@ pub struct Complex<T> {
 }
 
 impl<T> Complex<T> {
-    pub fn new(w: T, x: T) -> Self {
-        Self { vec: [w, x].into() }
+    pub const fn new(w: T, x: T) -> Self {
+        Self {
+            vec: Vector2::from_array([w, x]),
+        }
     }
-    pub fn from_vector(vec: Vector2<T>) -> Self {
+    pub const fn from_vector(vec: Vector2<T>) -> Self {
         Self { vec }
     }
-    pub fn from_array(arr: [T; 2]) -> Self {
-        Self { vec: arr.into() }
+    pub const fn from_array(arr: [T; 2]) -> Self {
+        Self {
+            vec: Vector2::from_array(arr),
+        }
     }
     pub fn from_tuple(tup: (T, T)) -> Self {
         Self { vec: tup.into() }
@@ -312,6 +316,15 @@ where
     }
 }
 
+impl<T> Complex<T>
+where
+    T: ConstZero + ConstOne,
+{
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ZERO);
+    pub const I: Self = Self::new(T::ZERO, T::ONE);
+}
+
 impl<T> Complex<T>
 where
     T: Add<Output = T> + Mul<Output = T> + Copy,
@@ -1,5 +1,5 @@
 use crate::{
-    complex::{Complex, Moebius, Quaternion},
+    complex::{Complex, DualNumber, Moebius, Octonion, Quaternion, SplitComplex},
     distr::*,
     matrix::Matrix,
     vector::Vector,
@@ -35,6 +35,60 @@ where
     }
 }
 
+impl<T> Distribution<SplitComplex<T>> for Normal
+where
+    Normal: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SplitComplex<T> {
+        rng.sample(self).into()
+    }
+}
+
+impl<T: Float> Distribution<SplitComplex<T>> for NonZero
+where
+    NonZero: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SplitComplex<T> {
+        rng.sample(Self).into()
+    }
+}
+
+impl<T: Float> Distribution<SplitComplex<T>> for Unit
+where
+    Unit: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SplitComplex<T> {
+        rng.sample(Self).into()
+    }
+}
+
+impl<T> Distribution<DualNumber<T>> for Normal
+where
+    Normal: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DualNumber<T> {
+        rng.sample(self).into()
+    }
+}
+
+impl<T: Float> Distribution<DualNumber<T>> for NonZero
+where
+    NonZero: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DualNumber<T> {
+        rng.sample(Self).into()
+    }
+}
+
+impl<T: Float> Distribution<DualNumber<T>> for Unit
+where
+    Unit: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DualNumber<T> {
+        rng.sample(Self).into()
+    }
+}
+
 impl<T> Distribution<Quaternion<T>> for Normal
 where
     Normal: Distribution<Vector<T, 4>>,
@@ -62,6 +116,33 @@ where
     }
 }
 
+impl<T> Distribution<Octonion<T>> for Normal
+where
+    Normal: Distribution<Vector<T, 8>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Octonion<T> {
+        rng.sample(self).into()
+    }
+}
+
+impl<T: Float> Distribution<Octonion<T>> for NonZero
+where
+    NonZero: Distribution<Vector<T, 8>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Octonion<T> {
+        rng.sample(Self).into()
+    }
+}
+
+impl<T: Float> Distribution<Octonion<T>> for Unit
+where
+    Unit: Distribution<Vector<T, 8>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Octonion<T> {
+        rng.sample(Self).into()
+    }
+}
+
 impl<T: Neg<Output = T> + Num + Copy> Distribution<Moebius<T>> for Invertible
 where
     Invertible: Distribution<Matrix<T, 2, 2>>,
@@ -0,0 +1,99 @@
+use crate::{
+    autodiff::Dual,
+    matrix::Matrix2x2,
+    traits::{Conj, Dot, NormL1, NormL2, Normalize},
+    vector::Vector2,
+};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_traits::{Float, Inv, Num, One, Zero};
+
+planar_algebra!(
+    /// Dual number `a + b ε` where `ε² = 0`.
+    ///
+    /// Unlike `autodiff::Dual`, which is a scalar carrying a derivative, this is a two-dimensional
+    /// algebra element in line with `Complex` and `SplitComplex`. The two types convert into each other.
+    ///
+    /// Neither type is built on the other because their semantics differ: `Dual` implements `Float`,
+    /// so it compares and orders by the value only and its `abs`, `sqrt`, etc. act on the value
+    /// to keep the derivative of a real function. `DualNumber` compares both components,
+    /// has the Euclidean `norm`, the matrix representation and the vector-like API
+    /// (`into_vector`, `Dot`, `Normalize`, random distributions) shared with the other
+    /// two-dimensional algebras.
+    ///
+    /// Note that `norm` is the Euclidean norm of `(a, b)`, while the multiplicative
+    /// (degenerate) modulus `a²` is given by `modulus_sqr`.
+    DualNumber,
+    0
+);
+
+impl<T> From<Dual<T>> for DualNumber<T>
+where
+    T: Copy,
+{
+    fn from(d: Dual<T>) -> Self {
+        Self::new(d.re(), d.eps())
+    }
+}
+impl<T> From<DualNumber<T>> for Dual<T> {
+    fn from(d: DualNumber<T>) -> Self {
+        let (re, eps) = d.into();
+        Dual::from_parts(re, eps)
+    }
+}
+
+impl<T> DualNumber<T>
+where
+    T: Zero + Copy,
+{
+    pub fn into_matrix(self) -> Matrix2x2<T> {
+        let (re, im) = self.into();
+        Matrix2x2::from([[re, T::zero()], [im, re]])
+    }
+}
+
+impl<T> DualNumber<T>
+where
+    T: Zero + One,
+{
+    pub fn eps() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+}
+
+impl<T> DualNumber<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    /// Multiplicative modulus `a²`, equal to `self * conj(self)`.
+    pub fn modulus_sqr(self) -> T {
+        self.re() * self.re()
+    }
+}
+
+impl<T: Float> DualNumber<T> {
+    /// Argument `b / a`, i.e. the slope of the number.
+    pub fn arg(self) -> T {
+        self.im() / self.re()
+    }
+    /// Construct from modulus and argument, `r (1 + φ ε)`.
+    pub fn from_polar(r: T, phi: T) -> Self {
+        Self::new(r, r * phi)
+    }
+    pub fn ln(self) -> Self {
+        Self::new(self.re().ln(), self.arg())
+    }
+    pub fn sqrt(self) -> Self {
+        let s = self.re().sqrt();
+        Self::new(s, self.im() / (s + s))
+    }
+}
+
+impl<T> DualNumber<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Multiplicative inverse, exists only if the real part is non-zero.
+    pub fn inv(self) -> Self {
+        self.conj() / self.modulus_sqr()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::complex::{Complex, Quaternion};
+use crate::complex::{
+    Complex, DualNumber, Octonion, ProjectiveComplex, Quaternion, SplitComplex,
+};
 use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
 impl<T: Debug> Debug for Complex<T> {
@@ -12,6 +14,28 @@ impl<T: Display> Display for Complex<T> {
     }
 }
 
+impl<T: Debug> Debug for SplitComplex<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "SplitComplex({:?}, {:?})", self.re_ref(), self.im_ref())
+    }
+}
+impl<T: Display> Display for SplitComplex<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "SplitComplex({}, {})", self.re_ref(), self.im_ref())
+    }
+}
+
+impl<T: Debug> Debug for DualNumber<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "DualNumber({:?}, {:?})", self.re_ref(), self.im_ref())
+    }
+}
+impl<T: Display> Display for DualNumber<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "DualNumber({}, {})", self.re_ref(), self.im_ref())
+    }
+}
+
 impl<T: Debug> Debug for Quaternion<T> {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         write!(
@@ -37,6 +61,52 @@ impl<T: Display> Display for Quaternion<T> {
     }
 }
 
+impl<T: Debug> Debug for Octonion<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Octonion(")?;
+        for i in 0..8 {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", self.e_ref(i))?;
+        }
+        write!(f, ")")
+    }
+}
+impl<T: Display> Display for Octonion<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Octonion(")?;
+        for i in 0..8 {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", self.e_ref(i))?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T: Debug> Debug for ProjectiveComplex<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "ProjectiveComplex({:?}, {:?})",
+            self.num_ref(),
+            self.den_ref(),
+        )
+    }
+}
+impl<T: Display> Display for ProjectiveComplex<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "ProjectiveComplex({}, {})",
+            self.num_ref(),
+            self.den_ref(),
+        )
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
@@ -48,4 +118,34 @@ mod tests {
         assert_eq!(format!("{:?}", q), "Quaternion(1, -2, 3, -4)");
         assert_eq!(format!("{}", q), "Quaternion(1, -2, 3, -4)");
     }
+
+    #[test]
+    fn projective() {
+        let p = ProjectiveComplex::new(Complex::<i32>::new(1, -2), Complex::new(0, 1));
+        assert_eq!(
+            format!("{:?}", p),
+            "ProjectiveComplex(Complex(1, -2), Complex(0, 1))"
+        );
+        assert_eq!(
+            format!("{}", p),
+            "ProjectiveComplex(Complex(1, -2), Complex(0, 1))"
+        );
+    }
+
+    #[test]
+    fn octonion() {
+        let o = Octonion::<i32>::new(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq!(format!("{:?}", o), "Octonion(1, -2, 3, -4, 5, -6, 7, -8)");
+        assert_eq!(format!("{}", o), "Octonion(1, -2, 3, -4, 5, -6, 7, -8)");
+    }
+
+    #[test]
+    fn split_and_dual() {
+        let s = SplitComplex::<i32>::new(1, -2);
+        assert_eq!(format!("{:?}", s), "SplitComplex(1, -2)");
+        assert_eq!(format!("{}", s), "SplitComplex(1, -2)");
+        let d = DualNumber::<i32>::new(3, 4);
+        assert_eq!(format!("{:?}", d), "DualNumber(3, 4)");
+        assert_eq!(format!("{}", d), "DualNumber(3, 4)");
+    }
 }
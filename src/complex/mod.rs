@@ -1,18 +1,33 @@
+#[macro_use]
+mod planar;
 #[cfg(feature = "approx")]
 mod approx;
+mod cayley_dickson;
 mod complex_;
+mod dual;
 #[cfg(feature = "rand")]
 mod distr;
 mod format;
 mod moebius;
+mod octonion;
+mod projective;
 mod quaternion;
+pub mod roots;
+mod split;
+mod su2;
 #[cfg(all(test, feature = "approx"))]
 mod tests;
 
+pub use cayley_dickson::*;
 pub use complex_::*;
+pub use dual::*;
 #[cfg(feature = "rand")]
 pub use distr::*;
 pub use moebius::*;
+pub use octonion::*;
+pub use projective::*;
 #[cfg(feature = "rand")]
 pub use num_complex::ComplexDistribution;
 pub use quaternion::*;
+pub use split::*;
+pub use su2::*;
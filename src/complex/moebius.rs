@@ -4,7 +4,7 @@ use crate::{
     traits::{Dot, Normalize},
 };
 use core::ops::{Add, Div, Mul, Neg};
-use num_traits::{Num, NumCast, One, Zero};
+use num_traits::{Float, Num, NumCast, One, Zero};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Moebius<T> {
@@ -169,6 +169,147 @@ where
     }
 }
 
+impl<T> Moebius<T>
+where
+    T: Add<Output = T> + Copy,
+{
+    /// Trace of the transformation matrix.
+    pub fn trace(&self) -> T {
+        self.a() + self.d()
+    }
+}
+
+impl<T> Moebius<Complex<T>>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Transformation that maps `z[0]`, `z[1]` and `z[2]` to `0`, `1` and infinity respectively.
+    fn to_standard(z: [Complex<T>; 3]) -> Self {
+        let (p, q) = (z[1] - z[2], z[1] - z[0]);
+        Self::new(p, -z[0] * p, q, -z[2] * q)
+    }
+
+    /// Unique transformation that maps three distinct points `src` to three distinct points `dst`.
+    ///
+    /// If points in any triple coincide then the resulting matrix is degenerate (has zero determinant).
+    pub fn from_points(src: [Complex<T>; 3], dst: [Complex<T>; 3]) -> Self {
+        Self::to_standard(dst).inv().chain(Self::to_standard(src))
+    }
+}
+
+/// Conjugacy class of Moebius transformation determined by its trace.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MoebiusClass {
+    /// Identity transformation.
+    Identity,
+    /// Conjugate to rotation, has two fixed points.
+    Elliptic,
+    /// Conjugate to translation, has single fixed point.
+    Parabolic,
+    /// Conjugate to real dilation, has attracting and repelling fixed points.
+    Hyperbolic,
+    /// Conjugate to complex dilation (rotation with scaling), has attracting and repelling fixed points.
+    Loxodromic,
+}
+
+impl<T> Moebius<Complex<T>>
+where
+    T: Float,
+{
+    /// Transformation with unit determinant.
+    ///
+    /// Of two possible square roots the one that makes the real part of trace non-negative is chosen.
+    pub fn normalize_sl2(self) -> Self {
+        let m = self.into_matrix() / self.det().sqrt();
+        let tr = m[(0, 0)] + m[(1, 1)];
+        if tr.re() < T::zero() {
+            Self::from(-m)
+        } else {
+            Self::from(m)
+        }
+    }
+
+    /// Squared trace of the normalized transformation, invariant under conjugation.
+    pub fn trace_sqr_norm(&self) -> Complex<T> {
+        let tr = self.trace();
+        tr * tr / self.det()
+    }
+
+    /// Determine conjugacy class of the transformation with tolerance `eps`.
+    pub fn classify(&self, eps: T) -> MoebiusClass {
+        let s = self.trace_sqr_norm();
+        let four = T::from(4).unwrap();
+        if s.im().abs() > eps || s.re() < -eps {
+            MoebiusClass::Loxodromic
+        } else if (s.re() - four).abs() <= eps {
+            let n = self.normalize_sl2();
+            if (n.a() - n.d()).norm() <= eps && n.b().norm() <= eps && n.c().norm() <= eps {
+                MoebiusClass::Identity
+            } else {
+                MoebiusClass::Parabolic
+            }
+        } else if s.re() < four {
+            MoebiusClass::Elliptic
+        } else {
+            MoebiusClass::Hyperbolic
+        }
+    }
+
+    /// Fixed points of the transformation, `None` stands for the point at infinity.
+    ///
+    /// If the transformation has attracting fixed point then it goes first.
+    /// Parabolic transformation has single fixed point, so both values are the same.
+    /// Every point is fixed by the identity transformation, in that case the result is meaningless.
+    pub fn fixed_points(&self) -> [Option<Complex<T>>; 2] {
+        let (a, b, c, d) = (self.a(), self.b(), self.c(), self.d());
+        let zero = Complex::zero();
+        if c == zero {
+            if a == d {
+                [None, None]
+            } else {
+                let z = b / (d - a);
+                // Derivative at finite fixed point is `a / d`.
+                if a.norm_sqr() < d.norm_sqr() {
+                    [Some(z), None]
+                } else {
+                    [None, Some(z)]
+                }
+            }
+        } else {
+            let tr = a + d;
+            let disc = (tr * tr - self.det() * T::from(4).unwrap()).sqrt();
+            let two_c = c * T::from(2).unwrap();
+            let (p, q) = ((a - d + disc) / two_c, (a - d - disc) / two_c);
+            // Derivative at fixed point `z` is `det / (c z + d)^2`.
+            if (c * p + d).norm_sqr() >= (c * q + d).norm_sqr() {
+                [Some(p), Some(q)]
+            } else {
+                [Some(q), Some(p)]
+            }
+        }
+    }
+
+    /// Fractional iteration of the transformation.
+    ///
+    /// `power(0)` is identity, `power(1)` is the transformation itself
+    /// and `power(s).chain(power(t))` is `power(s + t)`.
+    /// For elliptic transformations the rotation by the smallest angle is taken.
+    pub fn power(self, t: T) -> Self {
+        // `M^t = (sinh((1 - t) mu) I + sinh(t mu) M) / sinh(mu)` where `cosh(mu) = tr(M) / 2`.
+        let m = self.normalize_sl2();
+        let one = T::one();
+        let two = T::from(2).unwrap();
+        let mu = ((m.trace() - two) / T::from(4).unwrap()).sqrt().asinh() * two;
+        let (alpha, beta) = if mu.norm() <= T::epsilon() {
+            (Complex::new(one - t, T::zero()), Complex::new(t, T::zero()))
+        } else {
+            let s = mu.sinh();
+            ((mu * (one - t)).sinh() / s, (mu * t).sinh() / s)
+        };
+        Self::from(m.into_matrix() * beta + Matrix2x2::one() * alpha)
+    }
+}
+
 impl<T> From<Matrix2x2<T>> for Moebius<T> {
     fn from(mat: Matrix2x2<T>) -> Self {
         Self::from_matrix(mat)
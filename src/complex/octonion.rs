@@ -0,0 +1,475 @@
+use crate::{
+    complex::{CayleyDickson, Quaternion},
+    traits::{Conj, Dot, NormL1, NormL2, Normalize},
+    vector::Vector,
+};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_traits::{Float, Inv, Num, One, Zero};
+
+/// Octonion.
+///
+/// Multiplication of octonions is neither commutative nor associative, but it is alternative,
+/// so any two octonions generate an associative subalgebra.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Octonion<T> {
+    vec: Vector<T, 8>,
+}
+
+impl<T> Octonion<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(e0: T, e1: T, e2: T, e3: T, e4: T, e5: T, e6: T, e7: T) -> Self {
+        Self {
+            vec: [e0, e1, e2, e3, e4, e5, e6, e7].into(),
+        }
+    }
+    pub fn from_vector(vec: Vector<T, 8>) -> Self {
+        Self { vec }
+    }
+    pub fn from_array(arr: [T; 8]) -> Self {
+        Self { vec: arr.into() }
+    }
+    pub fn into_vector(self) -> Vector<T, 8> {
+        self.vec
+    }
+    pub fn into_array(self) -> [T; 8] {
+        self.vec.into()
+    }
+    pub fn as_vector(&self) -> &Vector<T, 8> {
+        &self.vec
+    }
+    pub fn as_mut_vector(&mut self) -> &mut Vector<T, 8> {
+        &mut self.vec
+    }
+    /// Construct from two quaternions `a + b l`.
+    pub fn from_quaternions(a: Quaternion<T>, b: Quaternion<T>) -> Self {
+        let (a0, a1, a2, a3) = a.into();
+        let (b0, b1, b2, b3) = b.into();
+        Self::new(a0, a1, a2, a3, b0, b1, b2, b3)
+    }
+    /// Split into two quaternions `a + b l`.
+    pub fn into_quaternions(self) -> (Quaternion<T>, Quaternion<T>) {
+        let [a0, a1, a2, a3, b0, b1, b2, b3] = self.into_array();
+        (
+            Quaternion::new(a0, a1, a2, a3),
+            Quaternion::new(b0, b1, b2, b3),
+        )
+    }
+    /// Convert into generic Cayley-Dickson representation.
+    pub fn into_cayley_dickson(self) -> CayleyDickson<Quaternion<T>> {
+        self.into_quaternions().into()
+    }
+    /// Convert from generic Cayley-Dickson representation.
+    pub fn from_cayley_dickson(cd: CayleyDickson<Quaternion<T>>) -> Self {
+        let (a, b) = cd.into();
+        Self::from_quaternions(a, b)
+    }
+}
+
+impl<T> From<Vector<T, 8>> for Octonion<T> {
+    fn from(vec: Vector<T, 8>) -> Self {
+        Self::from_vector(vec)
+    }
+}
+impl<T> From<Octonion<T>> for Vector<T, 8> {
+    fn from(oct: Octonion<T>) -> Self {
+        oct.into_vector()
+    }
+}
+impl<T> From<[T; 8]> for Octonion<T> {
+    fn from(arr: [T; 8]) -> Self {
+        Self::from_array(arr)
+    }
+}
+impl<T> From<Octonion<T>> for [T; 8] {
+    fn from(oct: Octonion<T>) -> Self {
+        oct.into_array()
+    }
+}
+impl<T> From<(Quaternion<T>, Quaternion<T>)> for Octonion<T> {
+    fn from((a, b): (Quaternion<T>, Quaternion<T>)) -> Self {
+        Self::from_quaternions(a, b)
+    }
+}
+impl<T> From<Octonion<T>> for (Quaternion<T>, Quaternion<T>) {
+    fn from(oct: Octonion<T>) -> Self {
+        oct.into_quaternions()
+    }
+}
+impl<T> From<CayleyDickson<Quaternion<T>>> for Octonion<T> {
+    fn from(cd: CayleyDickson<Quaternion<T>>) -> Self {
+        Self::from_cayley_dickson(cd)
+    }
+}
+impl<T> From<Octonion<T>> for CayleyDickson<Quaternion<T>> {
+    fn from(oct: Octonion<T>) -> Self {
+        oct.into_cayley_dickson()
+    }
+}
+impl<T> From<Quaternion<T>> for Octonion<T>
+where
+    T: Zero,
+{
+    fn from(quat: Quaternion<T>) -> Self {
+        Self::from_quaternions(quat, Quaternion::zero())
+    }
+}
+
+impl<T> Octonion<T>
+where
+    T: Copy,
+{
+    /// Real (scalar) part.
+    pub fn re(&self) -> T {
+        self.vec[0]
+    }
+    /// Component at `i`-th basis unit.
+    pub fn e(&self, i: usize) -> T {
+        self.vec[i]
+    }
+}
+
+impl<T> Octonion<T> {
+    pub fn e_ref(&self, i: usize) -> &T {
+        &self.vec[i]
+    }
+    pub fn e_mut(&mut self, i: usize) -> &mut T {
+        &mut self.vec[i]
+    }
+}
+
+impl<T> Octonion<T>
+where
+    T: Zero + One,
+{
+    /// `i`-th basis unit, `unit(0)` is one and the rest are imaginary.
+    pub fn unit(i: usize) -> Self {
+        assert!(i < 8);
+        let mut vec = Vector::zero();
+        vec[i] = T::one();
+        Self::from_vector(vec)
+    }
+}
+
+impl<T> Neg for Octonion<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        (-self.vec).into()
+    }
+}
+
+impl<T> Octonion<T>
+where
+    T: Neg<Output = T>,
+{
+    pub fn conj(self) -> Self {
+        let mut iter = self.vec.into_iter();
+        let re = iter.next().unwrap();
+        let vec = Vector::try_from_iter(core::iter::once(re).chain(iter.map(|x| -x))).unwrap();
+        Self::from_vector(vec)
+    }
+}
+
+impl<T> Conj for Octonion<T>
+where
+    T: Neg<Output = T>,
+{
+    fn conj(self) -> Self {
+        Octonion::conj(self)
+    }
+}
+
+impl<T> Add for Octonion<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        (self.vec + other.vec).into()
+    }
+}
+impl<T> Add<T> for Octonion<T>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+    fn add(mut self, other: T) -> Self {
+        self.vec[0] = self.vec[0] + other;
+        self
+    }
+}
+impl<T> Sub for Octonion<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        (self.vec - other.vec).into()
+    }
+}
+impl<T> Sub<T> for Octonion<T>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+    fn sub(mut self, other: T) -> Self {
+        self.vec[0] = self.vec[0] - other;
+        self
+    }
+}
+
+macro_rules! reverse_add_sub {
+    ($T:ident) => {
+        /// Workaround for reverse addition.
+        impl Add<Octonion<$T>> for $T {
+            type Output = Octonion<$T>;
+            fn add(self, other: Octonion<$T>) -> Self::Output {
+                other + self
+            }
+        }
+        /// Workaround for reverse subtraction.
+        impl Sub<Octonion<$T>> for $T {
+            type Output = Octonion<$T>;
+            fn sub(self, other: Octonion<$T>) -> Self::Output {
+                -other + self
+            }
+        }
+    };
+}
+
+reverse_add_sub!(f32);
+reverse_add_sub!(f64);
+
+impl<T> AddAssign for Octonion<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.vec += other.vec;
+    }
+}
+impl<T> AddAssign<T> for Octonion<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: T) {
+        self.vec[0] += other;
+    }
+}
+impl<T> SubAssign for Octonion<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.vec -= other.vec;
+    }
+}
+impl<T> SubAssign<T> for Octonion<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: T) {
+        self.vec[0] -= other;
+    }
+}
+
+impl<T> Zero for Octonion<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self::from_vector(Vector::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.vec.is_zero()
+    }
+}
+
+impl<T> Mul for Octonion<T>
+where
+    T: Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        (self.into_cayley_dickson() * other.into_cayley_dickson()).into()
+    }
+}
+impl<T> Mul<T> for Octonion<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: T) -> Self {
+        (self.vec * other).into()
+    }
+}
+
+impl<T> MulAssign for Octonion<T>
+where
+    Self: Mul<Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+impl<T> MulAssign<T> for Octonion<T>
+where
+    Self: Mul<T, Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T> One for Octonion<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn one() -> Self {
+        Self::unit(0)
+    }
+}
+
+impl<T> Octonion<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Copy,
+{
+    pub fn norm_sqr(self) -> T {
+        self.vec.square_length()
+    }
+}
+impl<T: Float> Octonion<T> {
+    pub fn norm(self) -> T {
+        self.vec.length()
+    }
+}
+impl<T> NormL1 for Octonion<T>
+where
+    Vector<T, 8>: NormL1<Output = T>,
+{
+    type Output = T;
+    fn norm_l1(self) -> T {
+        self.vec.norm_l1()
+    }
+}
+impl<T: Float> NormL2 for Octonion<T> {
+    type Output = T;
+    fn norm_l2(self) -> T {
+        self.norm()
+    }
+    fn norm_l2_sqr(self) -> T {
+        self.norm_sqr()
+    }
+}
+
+impl<T> Div<T> for Octonion<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    fn div(self, other: T) -> Self {
+        (self.vec / other).into()
+    }
+}
+
+impl<T> Octonion<T>
+where
+    T: Float,
+{
+    pub fn normalize(self) -> Self {
+        self / self.norm()
+    }
+}
+
+impl<T> Normalize for Octonion<T>
+where
+    T: Float,
+{
+    fn normalize(self) -> Self {
+        Octonion::normalize(self)
+    }
+}
+
+impl<T> Octonion<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    pub fn inv(self) -> Self {
+        self.conj() / self.norm_sqr()
+    }
+}
+
+impl<T> Inv for Octonion<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn inv(self) -> Self {
+        Octonion::inv(self)
+    }
+}
+
+/// Right division, i.e. `a / b = a * inv(b)`.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T> Div for Octonion<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+
+impl<T> DivAssign for Octonion<T>
+where
+    Self: Div<Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+impl<T> DivAssign<T> for Octonion<T>
+where
+    Self: Div<T, Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+macro_rules! reverse_mul_div {
+    ($T:ident) => {
+        /// Workaround for reverse multiplication.
+        impl Mul<Octonion<$T>> for $T {
+            type Output = Octonion<$T>;
+            fn mul(self, other: Octonion<$T>) -> Self::Output {
+                other * self
+            }
+        }
+        /// Workaround for reverse division.
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl Div<Octonion<$T>> for $T {
+            type Output = Octonion<$T>;
+            fn div(self, other: Octonion<$T>) -> Self::Output {
+                other.inv() * self
+            }
+        }
+    };
+}
+
+reverse_mul_div!(f32);
+reverse_mul_div!(f64);
+
+impl<T> Dot for Octonion<T>
+where
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    type Output = T;
+    fn dot(self, other: Self) -> T {
+        self.vec.dot(other.vec)
+    }
+}
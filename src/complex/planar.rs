@@ -0,0 +1,422 @@
+//! Two-dimensional algebras `a + b u` with `u² = ±1` or `u² = 0`.
+//!
+//! `SplitComplex` (`u² = 1`) and `DualNumber` (`u² = 0`) share constructors and operators,
+//! which are generated by `planar_algebra!`. Only the product depends on `u²` there,
+//! the rest of type-specific functions are defined in the type modules.
+
+/// Real part of the product `(a + b u) (c + d u)` given `ac` and `bd`, for the specified `u²`.
+macro_rules! planar_re_product {
+    (1, $ac:expr, $bd:expr) => {
+        $ac + $bd
+    };
+    (0, $ac:expr, $bd:expr) => {
+        $ac
+    };
+}
+
+macro_rules! planar_reverse_add_sub {
+    ($Name:ident, $T:ident) => {
+        /// Workaround for reverse addition.
+        impl Add<$Name<$T>> for $T {
+            type Output = $Name<$T>;
+            fn add(self, other: $Name<$T>) -> Self::Output {
+                other + self
+            }
+        }
+        /// Workaround for reverse subtraction.
+        impl Sub<$Name<$T>> for $T {
+            type Output = $Name<$T>;
+            fn sub(self, other: $Name<$T>) -> Self::Output {
+                -other + self
+            }
+        }
+    };
+}
+
+macro_rules! planar_reverse_mul_div {
+    ($Name:ident, $T:ident) => {
+        /// Workaround for reverse multiplication.
+        impl Mul<$Name<$T>> for $T {
+            type Output = $Name<$T>;
+            fn mul(self, other: $Name<$T>) -> Self::Output {
+                other * self
+            }
+        }
+        /// Workaround for reverse division.
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl Div<$Name<$T>> for $T {
+            type Output = $Name<$T>;
+            fn div(self, other: $Name<$T>) -> Self::Output {
+                other.inv() * self
+            }
+        }
+    };
+}
+
+/// Common functionality of the algebra with specified square of the imaginary unit (`1` or `0`).
+macro_rules! planar_algebra {
+    ($(#[$attr:meta])* $Name:ident, $im_sqr:tt) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq)]
+        pub struct $Name<T> {
+            vec: Vector2<T>,
+        }
+
+        impl<T> $Name<T> {
+            pub fn new(w: T, x: T) -> Self {
+                Self { vec: [w, x].into() }
+            }
+            pub fn from_vector(vec: Vector2<T>) -> Self {
+                Self { vec }
+            }
+            pub fn from_array(arr: [T; 2]) -> Self {
+                Self { vec: arr.into() }
+            }
+            pub fn from_tuple(tup: (T, T)) -> Self {
+                Self { vec: tup.into() }
+            }
+            pub fn into_vector(self) -> Vector2<T> {
+                self.vec
+            }
+            pub fn into_array(self) -> [T; 2] {
+                self.vec.into()
+            }
+            pub fn into_tuple(self) -> (T, T) {
+                self.vec.into()
+            }
+        }
+
+        impl<T> From<Vector2<T>> for $Name<T> {
+            fn from(vec: Vector2<T>) -> Self {
+                Self::from_vector(vec)
+            }
+        }
+        impl<T> From<$Name<T>> for Vector2<T> {
+            fn from(comp: $Name<T>) -> Self {
+                comp.into_vector()
+            }
+        }
+        impl<T> From<[T; 2]> for $Name<T> {
+            fn from(arr: [T; 2]) -> Self {
+                Self::from_array(arr)
+            }
+        }
+        impl<T> From<$Name<T>> for [T; 2] {
+            fn from(comp: $Name<T>) -> Self {
+                comp.into_array()
+            }
+        }
+        impl<T> From<(T, T)> for $Name<T> {
+            fn from(tup: (T, T)) -> Self {
+                Self::from_tuple(tup)
+            }
+        }
+        impl<T> From<$Name<T>> for (T, T) {
+            fn from(comp: $Name<T>) -> Self {
+                comp.into_tuple()
+            }
+        }
+
+        impl<T: Copy> $Name<T> {
+            pub fn re(&self) -> T {
+                self.vec.x()
+            }
+            pub fn im(&self) -> T {
+                self.vec.y()
+            }
+        }
+
+        impl<T> $Name<T> {
+            pub fn re_ref(&self) -> &T {
+                self.vec.x_ref()
+            }
+            pub fn im_ref(&self) -> &T {
+                self.vec.y_ref()
+            }
+            pub fn re_mut(&mut self) -> &mut T {
+                self.vec.x_mut()
+            }
+            pub fn im_mut(&mut self) -> &mut T {
+                self.vec.y_mut()
+            }
+        }
+
+        impl<T> Neg for $Name<T>
+        where
+            T: Neg<Output = T>,
+        {
+            type Output = Self;
+            fn neg(self) -> Self {
+                (-self.vec).into()
+            }
+        }
+
+        impl<T> $Name<T>
+        where
+            T: Neg<Output = T>,
+        {
+            pub fn conj(self) -> Self {
+                let (w, x) = self.into();
+                Self::new(w, -x)
+            }
+        }
+
+        impl<T> Conj for $Name<T>
+        where
+            T: Neg<Output = T>,
+        {
+            fn conj(self) -> Self {
+                $Name::conj(self)
+            }
+        }
+
+        impl<T> Add for $Name<T>
+        where
+            T: Add<Output = T>,
+        {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                (self.vec + other.vec).into()
+            }
+        }
+        impl<T> Add<T> for $Name<T>
+        where
+            T: Add<Output = T>,
+        {
+            type Output = Self;
+            fn add(self, other: T) -> Self {
+                let (w, x) = self.into();
+                Self::new(w + other, x)
+            }
+        }
+        impl<T> Sub for $Name<T>
+        where
+            T: Sub<Output = T>,
+        {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                (self.vec - other.vec).into()
+            }
+        }
+        impl<T> Sub<T> for $Name<T>
+        where
+            T: Sub<Output = T>,
+        {
+            type Output = Self;
+            fn sub(self, other: T) -> Self {
+                let (w, x) = self.into();
+                Self::new(w - other, x)
+            }
+        }
+
+        planar_reverse_add_sub!($Name, f32);
+        planar_reverse_add_sub!($Name, f64);
+
+        impl<T> AddAssign for $Name<T>
+        where
+            T: AddAssign,
+        {
+            fn add_assign(&mut self, other: Self) {
+                self.vec += other.vec;
+            }
+        }
+        impl<T> AddAssign<T> for $Name<T>
+        where
+            T: AddAssign,
+        {
+            fn add_assign(&mut self, other: T) {
+                *self.re_mut() += other;
+            }
+        }
+        impl<T> SubAssign for $Name<T>
+        where
+            T: SubAssign,
+        {
+            fn sub_assign(&mut self, other: Self) {
+                self.vec -= other.vec;
+            }
+        }
+        impl<T> SubAssign<T> for $Name<T>
+        where
+            T: SubAssign,
+        {
+            fn sub_assign(&mut self, other: T) {
+                *self.re_mut() -= other;
+            }
+        }
+
+        impl<T> Zero for $Name<T>
+        where
+            T: Zero,
+        {
+            fn zero() -> Self {
+                Self::new(T::zero(), T::zero())
+            }
+            fn is_zero(&self) -> bool {
+                self.vec.is_zero()
+            }
+        }
+
+        impl<T> Mul for $Name<T>
+        where
+            T: Add<Output = T> + Mul<Output = T> + Copy,
+        {
+            type Output = Self;
+            fn mul(self, other: Self) -> Self {
+                Self::new(
+                    planar_re_product!($im_sqr, self.re() * other.re(), self.im() * other.im()),
+                    self.re() * other.im() + self.im() * other.re(),
+                )
+            }
+        }
+        impl<T> Mul<T> for $Name<T>
+        where
+            T: Mul<Output = T> + Copy,
+        {
+            type Output = Self;
+            fn mul(self, other: T) -> Self {
+                (self.vec * other).into()
+            }
+        }
+
+        impl<T> MulAssign for $Name<T>
+        where
+            Self: Mul<Output = Self> + Copy,
+        {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+        impl<T> MulAssign<T> for $Name<T>
+        where
+            Self: Mul<T, Output = Self> + Copy,
+        {
+            fn mul_assign(&mut self, other: T) {
+                *self = *self * other;
+            }
+        }
+
+        impl<T> One for $Name<T>
+        where
+            T: Zero + One + Copy,
+        {
+            fn one() -> Self {
+                Self::new(T::one(), T::zero())
+            }
+        }
+
+        impl<T> $Name<T>
+        where
+            T: Add<Output = T> + Mul<Output = T> + Copy,
+        {
+            pub fn norm_sqr(self) -> T {
+                self.vec.square_length()
+            }
+        }
+        impl<T: Float> $Name<T> {
+            pub fn norm(self) -> T {
+                self.vec.length()
+            }
+            pub fn exp(self) -> Self {
+                Self::from_polar(self.re().exp(), self.im())
+            }
+        }
+        impl<T> NormL1 for $Name<T>
+        where
+            Vector2<T>: NormL1<Output = T>,
+        {
+            type Output = T;
+            fn norm_l1(self) -> T {
+                self.vec.norm_l1()
+            }
+        }
+        impl<T: Float> NormL2 for $Name<T> {
+            type Output = T;
+            fn norm_l2(self) -> T {
+                self.norm()
+            }
+            fn norm_l2_sqr(self) -> T {
+                self.norm_sqr()
+            }
+        }
+
+        impl<T> Div<T> for $Name<T>
+        where
+            T: Div<Output = T> + Copy,
+        {
+            type Output = Self;
+            fn div(self, other: T) -> Self {
+                (self.vec / other).into()
+            }
+        }
+
+        impl<T> $Name<T>
+        where
+            T: Float,
+        {
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+        }
+        impl<T> Normalize for $Name<T>
+        where
+            T: Float,
+        {
+            fn normalize(self) -> Self {
+                $Name::normalize(self)
+            }
+        }
+
+        impl<T> Inv for $Name<T>
+        where
+            T: Float,
+        {
+            type Output = Self;
+            fn inv(self) -> Self {
+                $Name::inv(self)
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl<T> Div for $Name<T>
+        where
+            T: Neg<Output = T> + Num + Copy,
+        {
+            type Output = Self;
+            fn div(self, other: Self) -> Self {
+                self * other.inv()
+            }
+        }
+
+        impl<T> DivAssign for $Name<T>
+        where
+            Self: Div<Output = Self> + Copy,
+        {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+        impl<T> DivAssign<T> for $Name<T>
+        where
+            Self: Div<T, Output = Self> + Copy,
+        {
+            fn div_assign(&mut self, other: T) {
+                *self = *self / other;
+            }
+        }
+
+        planar_reverse_mul_div!($Name, f32);
+        planar_reverse_mul_div!($Name, f64);
+
+        impl<T> Dot for $Name<T>
+        where
+            T: Add<Output = T> + Mul<Output = T>,
+        {
+            type Output = T;
+            fn dot(self, other: Self) -> T {
+                self.vec.dot(other.vec)
+            }
+        }
+    };
+}
@@ -0,0 +1,191 @@
+use crate::{
+    complex::{Complex, Moebius},
+    traits::Normalize,
+    vector::{Vector2, Vector3},
+};
+use core::ops::Neg;
+use num_traits::{Float, Num, One, Zero};
+
+/// Point of the extended complex plane (Riemann sphere) including the point at infinity.
+///
+/// Stored in homogeneous coordinates `(num, den)` which represent the number `num / den`,
+/// so the infinity is simply `(1, 0)` and no division is needed until the value is requested.
+#[derive(Clone, Copy)]
+pub struct ProjectiveComplex<T> {
+    num: Complex<T>,
+    den: Complex<T>,
+}
+
+impl<T> ProjectiveComplex<T> {
+    /// Construct from homogeneous coordinates, at least one of them must be non-zero.
+    pub fn new(num: Complex<T>, den: Complex<T>) -> Self {
+        Self { num, den }
+    }
+    /// Split into homogeneous coordinates.
+    pub fn split(self) -> (Complex<T>, Complex<T>) {
+        (self.num, self.den)
+    }
+    /// Get numerator reference.
+    pub fn num_ref(&self) -> &Complex<T> {
+        &self.num
+    }
+    /// Get denominator reference.
+    pub fn den_ref(&self) -> &Complex<T> {
+        &self.den
+    }
+}
+
+impl<T> ProjectiveComplex<T>
+where
+    T: Copy,
+{
+    /// Numerator of homogeneous coordinates.
+    pub fn num(&self) -> Complex<T> {
+        self.num
+    }
+    /// Denominator of homogeneous coordinates.
+    pub fn den(&self) -> Complex<T> {
+        self.den
+    }
+}
+
+impl<T> ProjectiveComplex<T>
+where
+    T: Zero + One,
+{
+    /// Finite point of the complex plane.
+    pub fn from_complex(z: Complex<T>) -> Self {
+        Self::new(z, Complex::new(T::one(), T::zero()))
+    }
+    /// The point at infinity.
+    pub fn infinity() -> Self {
+        Self::new(
+            Complex::new(T::one(), T::zero()),
+            Complex::new(T::zero(), T::zero()),
+        )
+    }
+}
+
+impl<T> ProjectiveComplex<T>
+where
+    T: Zero,
+{
+    /// Check whether the point is the infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.den.re_ref().is_zero() && self.den.im_ref().is_zero()
+    }
+}
+
+impl<T> ProjectiveComplex<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Complex number represented by the point, `None` for infinity.
+    pub fn to_complex(self) -> Option<Complex<T>> {
+        if self.is_infinity() {
+            None
+        } else {
+            Some(self.num / self.den)
+        }
+    }
+}
+
+impl<T> ProjectiveComplex<T>
+where
+    T: Float,
+{
+    /// Rescale homogeneous coordinates to have unit norm, the point itself is not changed.
+    pub fn normalize(self) -> Self {
+        // Prescale by the largest component to avoid overflow in squares.
+        let max = Vector2::from([self.num, self.den])
+            .map(|c| c.re().abs().max(c.im().abs()))
+            .fold_first(|a, b| a.max(b));
+        let (num, den) = (self.num / max, self.den / max);
+        let norm = (num.norm_sqr() + den.norm_sqr()).sqrt();
+        Self::new(num / norm, den / norm)
+    }
+
+    /// Inverse stereographic projection onto the unit sphere.
+    ///
+    /// Projection is made from the north pole `(0, 0, 1)` which corresponds to the infinity,
+    /// zero corresponds to the south pole.
+    pub fn to_sphere(self) -> Vector3<T> {
+        let (p, q) = self.normalize().split();
+        let (pp, qq) = (p.norm_sqr(), q.norm_sqr());
+        let pq = p * q.conj();
+        let two = T::one() + T::one();
+        Vector3::from([two * pq.re(), two * pq.im(), pp - qq]) / (pp + qq)
+    }
+
+    /// Stereographic projection of the point of the unit sphere.
+    ///
+    /// Of two equivalent homogeneous representations the one that doesn't suffer from cancellation is chosen.
+    pub fn from_sphere(pos: Vector3<T>) -> Self {
+        let (x, y, z) = pos.into();
+        if z <= T::zero() {
+            Self::new(Complex::new(x, y), Complex::new(T::one() - z, T::zero()))
+        } else {
+            Self::new(Complex::new(T::one() + z, T::zero()), Complex::new(x, -y))
+        }
+    }
+
+    /// Chordal distance between the points on the unit sphere.
+    pub fn chordal_distance(self, other: Self) -> T {
+        (self.to_sphere() - other.to_sphere()).length()
+    }
+}
+
+impl<T> Normalize for ProjectiveComplex<T>
+where
+    T: Float,
+{
+    fn normalize(self) -> Self {
+        ProjectiveComplex::normalize(self)
+    }
+}
+
+/// Points are equal when their homogeneous coordinates are proportional.
+impl<T> PartialEq for ProjectiveComplex<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.num * other.den == other.num * self.den
+    }
+}
+
+impl<T> From<Complex<T>> for ProjectiveComplex<T>
+where
+    T: Zero + One,
+{
+    fn from(z: Complex<T>) -> Self {
+        Self::from_complex(z)
+    }
+}
+
+impl<T> From<ProjectiveComplex<T>> for Vector2<Complex<T>> {
+    fn from(p: ProjectiveComplex<T>) -> Self {
+        let (num, den) = p.split();
+        Vector2::from([num, den])
+    }
+}
+impl<T> From<Vector2<Complex<T>>> for ProjectiveComplex<T> {
+    fn from(v: Vector2<Complex<T>>) -> Self {
+        let (num, den) = v.into();
+        Self::new(num, den)
+    }
+}
+
+impl<T> Moebius<Complex<T>>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Apply transformation to the point of the extended complex plane.
+    ///
+    /// In contrast to `apply` it has no singularities: the pole is mapped to the infinity
+    /// and the infinity is mapped to `a / c`.
+    pub fn apply_projective(&self, p: ProjectiveComplex<T>) -> ProjectiveComplex<T> {
+        let (z, w) = p.split();
+        ProjectiveComplex::new(self.a() * z + self.b() * w, self.c() * z + self.d() * w)
+    }
+}
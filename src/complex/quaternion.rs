@@ -5,7 +5,7 @@ use crate::{
     vector::{Vector3, Vector4},
 };
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
-use num_traits::{Float, Num, One, Zero, Inv};
+use num_traits::{ConstOne, ConstZero, Float, Num, One, Zero, Inv};
 
 /// Quaternion.
 #[repr(transparent)]
@@ -15,16 +15,18 @@ pub struct Quaternion<T> {
 }
 
 impl<T> Quaternion<T> {
-    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+    pub const fn new(w: T, x: T, y: T, z: T) -> Self {
         Self {
-            vec: [w, x, y, z].into(),
+            vec: Vector4::from_array([w, x, y, z]),
         }
     }
-    pub fn from_vector(vec: Vector4<T>) -> Self {
+    pub const fn from_vector(vec: Vector4<T>) -> Self {
         Self { vec }
     }
-    pub fn from_array(arr: [T; 4]) -> Self {
-        Self { vec: arr.into() }
+    pub const fn from_array(arr: [T; 4]) -> Self {
+        Self {
+            vec: Vector4::from_array(arr),
+        }
     }
     pub fn from_tuple(tup: (T, T, T, T)) -> Self {
         Self { vec: tup.into() }
@@ -446,6 +448,17 @@ where
     }
 }
 
+impl<T> Quaternion<T>
+where
+    T: ConstZero + ConstOne,
+{
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ZERO, T::ZERO, T::ZERO);
+    pub const I: Self = Self::new(T::ZERO, T::ONE, T::ZERO, T::ZERO);
+    pub const J: Self = Self::new(T::ZERO, T::ZERO, T::ONE, T::ZERO);
+    pub const K: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE);
+}
+
 impl<T> Quaternion<T>
 where
     T: Add<Output = T> + Mul<Output = T> + Copy,
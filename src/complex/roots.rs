@@ -0,0 +1,347 @@
+//! Roots of polynomials with complex coefficients.
+//!
+//! Polynomials are monic and passed by their lower coefficients in ascending order,
+//! i.e. `coef` of length `N` represents `z^N + coef[N-1] z^(N-1) + ... + coef[1] z + coef[0]`.
+//! Polynomials with real coefficients could be passed as `coef.map(|x| Complex::new(x, 0.0))`.
+
+use crate::{complex::Complex, vector::Vector};
+use num_traits::{Float, FloatConst, NumCast, One, Zero};
+
+fn real<T: Zero>(x: T) -> Complex<T> {
+    Complex::new(x, T::zero())
+}
+
+fn convert<T, const M: usize, const N: usize>(v: Vector<T, M>) -> Vector<T, N> {
+    Vector::try_from_iter(v.into_iter()).unwrap()
+}
+
+/// Value of the monic polynomial at the point.
+pub fn eval<T: Float, const N: usize>(coef: Vector<Complex<T>, N>, z: Complex<T>) -> Complex<T> {
+    coef.into_iter()
+        .rev()
+        .fold(Complex::one(), |p, c| p * z + c)
+}
+
+/// Value of the monic polynomial and its derivative at the point.
+fn eval_deriv<T: Float, const N: usize>(
+    coef: Vector<Complex<T>, N>,
+    z: Complex<T>,
+) -> (Complex<T>, Complex<T>) {
+    coef.into_iter()
+        .rev()
+        .fold((Complex::one(), Complex::zero()), |(p, d), c| {
+            (p * z + c, d * z + p)
+        })
+}
+
+/// Root of `z + c0`.
+pub fn linear<T: Float>(coef: Vector<Complex<T>, 1>) -> Vector<Complex<T>, 1> {
+    Vector::from([-coef[0]])
+}
+
+/// Roots of `z^2 + c1 z + c0`.
+///
+/// The formula that doesn't suffer from cancellation is used.
+pub fn quadratic<T: Float>(coef: Vector<Complex<T>, 2>) -> Vector<Complex<T>, 2> {
+    let (c, b) = coef.into();
+    let two = T::one() + T::one();
+    let sd = (b * b - c * (two * two)).sqrt();
+    let sd = if (b.conj() * sd).re() < T::zero() {
+        -sd
+    } else {
+        sd
+    };
+    let q = -(b + sd) / two;
+    if q.is_zero() {
+        Vector::from([q, q])
+    } else {
+        Vector::from([q, c / q])
+    }
+}
+
+/// Roots of `z^3 + c2 z^2 + c1 z + c0` using Cardano's formula.
+pub fn cubic<T>(coef: Vector<Complex<T>, 3>) -> Vector<Complex<T>, 3>
+where
+    T: Float + FloatConst + NumCast,
+{
+    let (c, b, a) = coef.into();
+    let num = |x: f64| T::from(x).unwrap();
+    // Depressed cubic `t^3 + p t + q` where `z = t - a / 3`.
+    let shift = a / num(3.0);
+    let p = b - a * shift;
+    let q = shift * shift * shift * num(2.0) - b * shift + c;
+
+    let half_q = q / num(2.0);
+    let third_p = p / num(3.0);
+    let sd = (half_q * half_q + third_p * third_p * third_p).sqrt();
+    let w = if (half_q.conj() * sd).re() < T::zero() {
+        sd - half_q
+    } else {
+        -sd - half_q
+    };
+    let u = w.cbrt();
+    let omega = Complex::from_polar(T::one(), num(2.0) * T::PI() / num(3.0));
+    let mut res = Vector::<Complex<T>, 3>::zero();
+    let mut uk = u;
+    for i in 0..3 {
+        let vk = if uk.is_zero() {
+            Complex::zero()
+        } else {
+            -third_p / uk
+        };
+        res[i] = uk + vk - shift;
+        uk *= omega;
+    }
+    res
+}
+
+/// Roots of `z^4 + c3 z^3 + c2 z^2 + c1 z + c0` using Ferrari's method.
+pub fn quartic<T>(coef: Vector<Complex<T>, 4>) -> Vector<Complex<T>, 4>
+where
+    T: Float + FloatConst + NumCast,
+{
+    let (d, c, b, a) = coef.into();
+    let num = |x: f64| T::from(x).unwrap();
+    // Depressed quartic `y^4 + p y^2 + q y + r` where `z = y - a / 4`.
+    let shift = a / num(4.0);
+    let s2 = shift * shift;
+    let p = b - s2 * num(6.0);
+    let q = c - b * shift * num(2.0) + s2 * shift * num(8.0);
+    let r = d - c * shift + b * s2 - s2 * s2 * num(3.0);
+
+    let half_p = p / num(2.0);
+    // Resolvent cubic `m^3 + p m^2 + (p^2 / 4 - r) m - q^2 / 8`.
+    let ms = cubic(Vector::from([-q * q / num(8.0), half_p * half_p - r, p]));
+    let m = ms.into_iter().fold(Complex::zero(), |m: Complex<T>, x| {
+        if x.norm_sqr() > m.norm_sqr() {
+            x
+        } else {
+            m
+        }
+    });
+    let s = (m * num(2.0)).sqrt();
+
+    let ys = if s.is_zero() {
+        // Biquadratic equation.
+        let (u, v) = quadratic(Vector::from([r, p])).into();
+        let (u, v) = (u.sqrt(), v.sqrt());
+        [u, -u, v, -v]
+    } else {
+        let t = q / (s * num(2.0));
+        let (y0, y1) = quadratic(Vector::from([half_p + m - t, s])).into();
+        let (y2, y3) = quadratic(Vector::from([half_p + m + t, -s])).into();
+        [y0, y1, y2, y3]
+    };
+    Vector::from(ys).map(|y| y - shift)
+}
+
+/// Initial approximations for iterative methods, evenly distributed on the circle containing all roots.
+fn initial<T, const N: usize>(coef: Vector<Complex<T>, N>) -> Vector<Complex<T>, N>
+where
+    T: Float + FloatConst + NumCast,
+{
+    let radius = T::one() + coef.into_iter().fold(T::zero(), |m, c| m.max(c.norm()));
+    let n = T::from(N).unwrap();
+    let offset = T::from(0.4).unwrap();
+    Vector::indices().map(|k| {
+        Complex::from_polar(
+            radius,
+            (T::from(k).unwrap() * (T::PI() + T::PI()) + offset) / n,
+        )
+    })
+}
+
+/// Roots of the monic polynomial of arbitrary degree using Durand-Kerner (Weierstrass) method.
+///
+/// Iterations stop when relative correction of each root becomes less than `eps` or after `max_iter` steps.
+pub fn durand_kerner<T, const N: usize>(
+    coef: Vector<Complex<T>, N>,
+    eps: T,
+    max_iter: usize,
+) -> Vector<Complex<T>, N>
+where
+    T: Float + FloatConst + NumCast,
+{
+    let mut z = initial(coef);
+    for _ in 0..max_iter {
+        let mut done = true;
+        for k in 0..N {
+            let mut den = Complex::one();
+            for j in 0..N {
+                if j != k {
+                    den *= z[k] - z[j];
+                }
+            }
+            let dz = eval(coef, z[k]) / den;
+            z[k] = z[k] - dz;
+            done &= dz.norm() <= eps * z[k].norm();
+        }
+        if done {
+            break;
+        }
+    }
+    z
+}
+
+/// Roots of the monic polynomial of arbitrary degree using Aberth-Ehrlich method.
+///
+/// Iterations stop when relative correction of each root becomes less than `eps` or after `max_iter` steps.
+pub fn aberth<T, const N: usize>(
+    coef: Vector<Complex<T>, N>,
+    eps: T,
+    max_iter: usize,
+) -> Vector<Complex<T>, N>
+where
+    T: Float + FloatConst + NumCast,
+{
+    let mut z = initial(coef);
+    for _ in 0..max_iter {
+        let mut done = true;
+        for k in 0..N {
+            let (p, d) = eval_deriv(coef, z[k]);
+            if p.is_zero() {
+                continue;
+            }
+            let ratio = p / d;
+            let mut sum = Complex::zero();
+            for j in 0..N {
+                if j != k {
+                    sum = sum + (z[k] - z[j]).inv();
+                }
+            }
+            let dz = ratio / (-(ratio * sum) + T::one());
+            z[k] = z[k] - dz;
+            done &= dz.norm() <= eps * z[k].norm();
+        }
+        if done {
+            break;
+        }
+    }
+    z
+}
+
+/// Roots of the monic polynomial.
+///
+/// Closed form solutions are used for degrees up to 4, and Aberth method for higher degrees.
+pub fn roots<T, const N: usize>(coef: Vector<Complex<T>, N>) -> Vector<Complex<T>, N>
+where
+    T: Float + FloatConst + NumCast,
+{
+    match N {
+        0 => coef,
+        1 => convert(linear(convert(coef))),
+        2 => convert(quadratic(convert(coef))),
+        3 => convert(cubic(convert(coef))),
+        4 => convert(quartic(convert(coef))),
+        _ => aberth(coef, T::epsilon() * T::from(4.0).unwrap(), 64 * N),
+    }
+}
+
+/// Roots of the monic polynomial with real coefficients.
+pub fn real_roots<T, const N: usize>(coef: Vector<T, N>) -> Vector<Complex<T>, N>
+where
+    T: Float + FloatConst + NumCast,
+{
+    roots(coef.map(real))
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::Normal;
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    type C = Complex<f64>;
+
+    /// Coefficients of the monic polynomial with specified roots.
+    fn from_roots<const N: usize>(roots: Vector<C, N>) -> Vector<C, N> {
+        let mut coef = [C::zero(); N];
+        for (deg, r) in roots.into_iter().enumerate() {
+            for i in (0..=deg).rev() {
+                let prev = if i > 0 { coef[i - 1] } else { C::zero() };
+                let cur = if i < deg { coef[i] } else { C::one() };
+                if i < N {
+                    coef[i] = prev - cur * r;
+                }
+            }
+        }
+        coef.into()
+    }
+
+    /// Check that each expected root is found (with multiplicity).
+    fn assert_roots<const N: usize>(found: Vector<C, N>, expected: Vector<C, N>, eps: f64) {
+        let mut used = [false; N];
+        for e in expected.into_iter() {
+            let (i, d) = found
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| !used[*i])
+                .map(|(i, f)| (i, (f - e).norm()))
+                .fold((N, f64::INFINITY), |a, b| if b.1 < a.1 { b } else { a });
+            assert!(d < eps, "root {:?} not found in {:?}", e, found);
+            used[i] = true;
+        }
+    }
+
+    #[test]
+    fn from_roots_check() {
+        let r = Vector::from([C::new(1.0, 0.0), C::new(2.0, 0.0)]);
+        assert_abs_diff_eq!(
+            from_roots(r),
+            Vector::from([C::new(2.0, 0.0), C::new(-3.0, 0.0)])
+        );
+    }
+
+    #[test]
+    fn closed_form() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4007);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let r2: Vector<C, 2> = Vector::init(|| C::from_vector(rng.sample(&Normal)));
+            assert_roots(quadratic(from_roots(r2)), r2, 1e-9);
+            let r3: Vector<C, 3> = Vector::init(|| C::from_vector(rng.sample(&Normal)));
+            assert_roots(cubic(from_roots(r3)), r3, 1e-8);
+            let r4: Vector<C, 4> = Vector::init(|| C::from_vector(rng.sample(&Normal)));
+            assert_roots(quartic(from_roots(r4)), r4, 1e-7);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let z = C::zero();
+        assert_roots(
+            cubic(Vector::from([z, z, z])),
+            Vector::from([z, z, z]),
+            1e-12,
+        );
+        assert_roots(
+            quartic(Vector::from([z, z, z, z])),
+            Vector::from([z; 4]),
+            1e-12,
+        );
+        // Biquadratic `z^4 - 5 z^2 + 4`.
+        let r = real_roots(Vector::from([4.0, 0.0, -5.0, 0.0]));
+        let e = Vector::from([1.0, -1.0, 2.0, -2.0]).map(real);
+        assert_roots(r, e, 1e-12);
+        // Double root `(z - 1)^2 (z + 2)`.
+        let e = Vector::from([1.0, 1.0, -2.0]).map(real);
+        assert_roots(roots(from_roots(e)), e, 1e-6);
+    }
+
+    #[test]
+    fn iterative() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4008);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let r: Vector<C, 6> = Vector::init(|| C::from_vector(rng.sample(&Normal)));
+            let coef = from_roots(r);
+            assert_roots(roots(coef), r, 1e-6);
+            assert_roots(durand_kerner(coef, 1e-14, 1000), r, 1e-6);
+            for z in aberth(coef, 1e-14, 1000).into_iter() {
+                assert_abs_diff_eq!(eval(coef, z).norm(), 0.0, epsilon = 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    matrix::Matrix2x2,
+    traits::{Conj, Dot, NormL1, NormL2, Normalize},
+    vector::Vector2,
+};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_traits::{Float, Inv, Num, One, Zero};
+
+planar_algebra!(
+    /// Split-complex number `a + b j` where `j² = 1`.
+    ///
+    /// Unit split-complex numbers `cosh(φ) + sinh(φ) j` represent hyperbolic rotations (Lorentz boosts).
+    ///
+    /// Note that `norm` is the Euclidean norm of `(a, b)`, while the multiplicative
+    /// (indefinite) modulus `a² - b²` is given by `modulus_sqr`.
+    SplitComplex,
+    1
+);
+
+impl<T> SplitComplex<T>
+where
+    T: Copy,
+{
+    pub fn into_matrix(self) -> Matrix2x2<T> {
+        let (re, im) = self.into();
+        Matrix2x2::from([[re, im], [im, re]])
+    }
+}
+
+impl<T> SplitComplex<T>
+where
+    T: Zero + One,
+{
+    pub fn j() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+}
+
+impl<T> SplitComplex<T>
+where
+    T: Sub<Output = T> + Mul<Output = T> + Copy,
+{
+    /// Multiplicative modulus `a² - b²`, equal to `self * conj(self)`. It may be zero or negative.
+    pub fn modulus_sqr(self) -> T {
+        self.re() * self.re() - self.im() * self.im()
+    }
+}
+
+impl<T: Float> SplitComplex<T> {
+    /// Hyperbolic angle (rapidity), defined when `|b| < a`.
+    pub fn arg(self) -> T {
+        (self.im() / self.re()).atanh()
+    }
+    /// Construct from modulus and hyperbolic angle, `r (cosh(φ) + sinh(φ) j)`.
+    pub fn from_polar(r: T, phi: T) -> Self {
+        Self::new(r * phi.cosh(), r * phi.sinh())
+    }
+}
+
+impl<T> SplitComplex<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Multiplicative inverse, exists only if `modulus_sqr` is non-zero.
+    pub fn inv(self) -> Self {
+        self.conj() / self.modulus_sqr()
+    }
+}
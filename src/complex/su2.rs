@@ -0,0 +1,237 @@
+use crate::{
+    complex::{Complex, Moebius, Quaternion},
+    matrix::Matrix2x2,
+    traits::Dot,
+    transform::Rotation3,
+    vector::{Vector2, Vector3},
+};
+use core::ops::Neg;
+use num_traits::{Float, Num, One, Zero};
+
+/// Pauli matrix `σx`.
+pub fn pauli_x<T: Zero + One>() -> Matrix2x2<Complex<T>> {
+    Matrix2x2::from([
+        [
+            Complex::new(T::zero(), T::zero()),
+            Complex::new(T::one(), T::zero()),
+        ],
+        [
+            Complex::new(T::one(), T::zero()),
+            Complex::new(T::zero(), T::zero()),
+        ],
+    ])
+}
+/// Pauli matrix `σy`.
+pub fn pauli_y<T: Neg<Output = T> + Zero + One>() -> Matrix2x2<Complex<T>> {
+    Matrix2x2::from([
+        [
+            Complex::new(T::zero(), T::zero()),
+            Complex::new(T::zero(), -T::one()),
+        ],
+        [
+            Complex::new(T::zero(), T::one()),
+            Complex::new(T::zero(), T::zero()),
+        ],
+    ])
+}
+/// Pauli matrix `σz`.
+pub fn pauli_z<T: Neg<Output = T> + Zero + One>() -> Matrix2x2<Complex<T>> {
+    Matrix2x2::from([
+        [
+            Complex::new(T::one(), T::zero()),
+            Complex::new(T::zero(), T::zero()),
+        ],
+        [
+            Complex::new(T::zero(), T::zero()),
+            Complex::new(-T::one(), T::zero()),
+        ],
+    ])
+}
+
+impl<T> Quaternion<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    /// Representation of the quaternion as a complex 2x2 matrix.
+    ///
+    /// Units `i`, `j`, `k` are mapped to `-iσx`, `-iσy`, `-iσz` respectively,
+    /// so unit quaternions are mapped to special unitary matrices (SU(2)).
+    pub fn to_su2(self) -> Matrix2x2<Complex<T>> {
+        let (w, x, y, z) = self.into();
+        Matrix2x2::from([
+            [Complex::new(w, -z), Complex::new(-y, -x)],
+            [Complex::new(y, -x), Complex::new(w, z)],
+        ])
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    /// Quaternion corresponding to the complex 2x2 matrix, inverse of `to_su2`.
+    ///
+    /// The matrix is projected onto the image of `to_su2` if it doesn't belong to it.
+    pub fn from_su2(mat: Matrix2x2<Complex<T>>) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        let (m00, m01, m10, m11) = (mat[(0, 0)], mat[(0, 1)], mat[(1, 0)], mat[(1, 1)]);
+        Quaternion::new(
+            (m00 + m11).re() * half,
+            -(m01 + m10).im() * half,
+            (m10 - m01).re() * half,
+            (m11 - m00).im() * half,
+        )
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Apply the rotation represented by the unit quaternion to the spinor.
+    pub fn apply_spinor(self, spinor: Vector2<Complex<T>>) -> Vector2<Complex<T>> {
+        self.to_su2().dot(spinor)
+    }
+
+    /// Moebius transformation rotating the Riemann sphere in the same way as the quaternion rotates space.
+    ///
+    /// The sphere is identified with the extended complex plane by `ProjectiveComplex::to_sphere`.
+    pub fn to_moebius(self) -> Moebius<Complex<T>> {
+        Moebius::from_matrix(self.to_su2().map(|c| c.conj()))
+    }
+}
+
+impl<T> Moebius<Complex<T>>
+where
+    T: Float,
+{
+    /// Unit quaternion of the rotation of the Riemann sphere, inverse of `Quaternion::to_moebius`.
+    ///
+    /// The transformation must be a rotation of the sphere, the sign of the result is arbitrary.
+    pub fn to_quaternion(self) -> Quaternion<T> {
+        Quaternion::from_su2(self.normalize_sl2().into_matrix().map(|c| c.conj()))
+    }
+}
+
+impl<T> Rotation3<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Apply the rotation to the spinor.
+    pub fn apply_spinor(&self, spinor: Vector2<Complex<T>>) -> Vector2<Complex<T>> {
+        self.into_quaternion().apply_spinor(spinor)
+    }
+}
+
+impl<T> Vector2<Complex<T>>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Bloch vector of the spinor, i.e. expectation values of the Pauli matrices.
+    ///
+    /// It is rotated in the same way as the spinor by `apply_spinor`.
+    pub fn bloch_vector(self) -> Vector3<T> {
+        let (p, q) = self.into();
+        let pq = p.conj() * q;
+        let two = T::one() + T::one();
+        Vector3::from([two * pq.re(), two * pq.im(), p.norm_sqr() - q.norm_sqr()])
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        complex::ProjectiveComplex,
+        distr::{Normal, Unit},
+        Map,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-12;
+
+    type Q = Quaternion<f64>;
+    type M = Matrix2x2<Complex<f64>>;
+
+    #[test]
+    fn pauli() {
+        let (x, y, z) = (pauli_x::<f64>(), pauli_y::<f64>(), pauli_z::<f64>());
+        let i = Complex::new(0.0, 1.0);
+        for s in [x, y, z].iter() {
+            assert_abs_diff_eq!(s.dot(*s), M::one(), epsilon = EPS);
+            assert!(s.is_hermitian(EPS) && s.is_unitary(EPS));
+        }
+        assert_abs_diff_eq!(x.dot(y), z * i, epsilon = EPS);
+        assert_abs_diff_eq!(y.dot(z), x * i, epsilon = EPS);
+        assert_abs_diff_eq!(z.dot(x), y * i, epsilon = EPS);
+
+        let mi = Complex::new(0.0, -1.0);
+        assert_abs_diff_eq!(Q::i().to_su2(), x * mi, epsilon = EPS);
+        assert_abs_diff_eq!(Q::j().to_su2(), y * mi, epsilon = EPS);
+        assert_abs_diff_eq!(Q::k().to_su2(), z * mi, epsilon = EPS);
+    }
+
+    #[test]
+    fn homomorphism() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5112);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Q = rng.sample(&Normal);
+            let b: Q = rng.sample(&Normal);
+            assert_abs_diff_eq!((a * b).to_su2(), a.to_su2().dot(b.to_su2()), epsilon = EPS);
+            assert_abs_diff_eq!(a.conj().to_su2(), a.to_su2().adjoint(), epsilon = EPS);
+            assert_abs_diff_eq!(a.to_su2().det().re(), a.norm_sqr(), epsilon = EPS);
+            assert_abs_diff_eq!(Q::from_su2(a.to_su2()), a, epsilon = EPS);
+
+            let u: Q = rng.sample(&Unit);
+            assert!(u.to_su2().is_unitary(1e-10));
+        }
+    }
+
+    #[test]
+    fn spinor() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5113);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let r = Rotation3::<f64>::from_quaternion(rng.sample(&Unit));
+            let s = Vector2::from([
+                Complex::from_vector(rng.sample(&Normal)),
+                Complex::from_vector(rng.sample(&Normal)),
+            ]);
+            assert_abs_diff_eq!(
+                r.apply_spinor(s).bloch_vector(),
+                r.apply(s.bloch_vector()),
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                r.apply_spinor(s).vdot(r.apply_spinor(s)).re(),
+                s.vdot(s).re(),
+                epsilon = 1e-10
+            );
+        }
+        // Full turn changes the sign of the spinor.
+        let s = Vector2::from([Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)]);
+        let r = Rotation3::new(Vector3::from([0.0, 0.0, 1.0]), 2.0 * core::f64::consts::PI);
+        assert_abs_diff_eq!(r.apply_spinor(s), -s, epsilon = EPS);
+    }
+
+    #[test]
+    fn moebius() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5114);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let q: Q = rng.sample(&Unit);
+            let r = Rotation3::from_quaternion(q);
+            let m = q.to_moebius();
+            let p = ProjectiveComplex::from_complex(Complex::from_vector(rng.sample(&Normal)));
+            assert_abs_diff_eq!(
+                m.apply_projective(p).to_sphere(),
+                r.apply(p.to_sphere()),
+                epsilon = 1e-10
+            );
+            let s = m.to_quaternion();
+            let s = if s.dot(q) < 0.0 { -s } else { s };
+            assert_abs_diff_eq!(s, q, epsilon = 1e-10);
+        }
+    }
+}
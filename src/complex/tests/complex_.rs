@@ -77,3 +77,12 @@ fn one() {
     let a = Complex::<f32>::one();
     assert_abs_diff_eq!(a, Complex::new(1.0, 0.0));
 }
+
+#[test]
+fn consts() {
+    const C: Complex<f64> = Complex::new(1.0, 2.0);
+    type C64 = Complex<f64>;
+    assert_eq!(C, C64::ONE + C64::I * 2.0);
+    assert_eq!(Complex::<i32>::ZERO, Complex::zero());
+    assert_eq!(Complex::<i32>::I, Complex::i());
+}
@@ -0,0 +1,72 @@
+use crate::{autodiff::Dual, complex::DualNumber, distr::*, traits::Dot};
+use approx::*;
+use num_traits::{One, Zero};
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+type Df = DualNumber<f64>;
+
+#[test]
+fn units() {
+    type Di = DualNumber<i32>;
+    assert_eq!(Di::eps() * Di::eps(), Di::zero());
+    assert_eq!(Di::new(2, 3) * Di::new(4, 5), Di::new(8, 22));
+    assert_eq!(Di::new(2, 3).modulus_sqr(), 4);
+}
+
+#[test]
+fn modulus() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Df = rng.sample(&Normal);
+        let b: Df = rng.sample(&Normal);
+        assert_abs_diff_eq!(
+            (a * b).modulus_sqr(),
+            a.modulus_sqr() * b.modulus_sqr(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(a * a.conj(), Df::one() * a.modulus_sqr(), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            a.into_matrix().dot(b.into_matrix()),
+            (a * b).into_matrix(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+#[allow(clippy::eq_op)]
+fn inversion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Df = rng.sample(&NonZero);
+        if a.re().abs() < 1e-3 {
+            continue;
+        }
+        assert_abs_diff_eq!(a / a, Df::one(), epsilon = 1e-10);
+        assert_abs_diff_eq!(1.0 / a, a.inv(), epsilon = 1e-14);
+    }
+}
+
+#[test]
+fn functions() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Df = rng.sample(&Normal);
+        let b: Df = rng.sample(&Normal);
+        assert_abs_diff_eq!((a + b).exp(), a.exp() * b.exp(), epsilon = 1e-10);
+        let c = Df::new(a.re().abs() + 0.1, a.im());
+        assert_abs_diff_eq!(c.sqrt() * c.sqrt(), c, epsilon = 1e-10);
+        assert_abs_diff_eq!(c.ln().exp(), c, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn autodiff() {
+    let d = Df::new(2.0, -3.0);
+    let j: Dual<f64> = d.into();
+    assert_abs_diff_eq!(j.re(), 2.0);
+    assert_abs_diff_eq!(j.eps(), -3.0);
+    assert_eq!(Df::from(j), d);
+}
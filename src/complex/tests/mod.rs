@@ -1,4 +1,12 @@
 mod complex_;
 #[cfg(feature = "rand")]
+mod dual;
+#[cfg(feature = "rand")]
 mod moebius;
+#[cfg(feature = "rand")]
+mod octonion;
+#[cfg(feature = "rand")]
+mod projective;
 mod quaternion;
+#[cfg(feature = "rand")]
+mod split;
@@ -0,0 +1,116 @@
+use crate::{
+    complex::{Complex, Moebius, MoebiusClass},
+    distr::{Invertible, Normal},
+};
+use approx::*;
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const TRANSFORM_ATTEMPTS: usize = 64;
+const POINT_ATTEMPTS: usize = 16;
+const EPS: f64 = 1e-9;
+
+fn assert_same(a: Moebius<Complex<f64>>, b: Moebius<Complex<f64>>, rng: &mut XorShiftRng) {
+    for _ in 0..POINT_ATTEMPTS {
+        let x: Complex<f64> = rng.sample(Normal);
+        assert_abs_diff_eq!(a.apply(x), b.apply(x), epsilon = EPS);
+    }
+}
+
+#[test]
+fn from_points() {
+    let mut rng = XorShiftRng::seed_from_u64(0xDEAD10);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let m: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let src: [Complex<f64>; 3] = [rng.sample(Normal), rng.sample(Normal), rng.sample(Normal)];
+        let dst = [m.apply(src[0]), m.apply(src[1]), m.apply(src[2])];
+        let n = Moebius::from_points(src, dst);
+        for (&z, &w) in src.iter().zip(dst.iter()) {
+            assert_abs_diff_eq!(n.apply(z), w, epsilon = EPS);
+        }
+        assert_same(n, m, &mut rng);
+    }
+    let (a, b) = (Complex::new(1.0, 0.0), Complex::new(0.0, 1.0));
+    assert_abs_diff_eq!(
+        Moebius::from_points([a, a, b], [a, b, -a]).det().norm(),
+        0.0
+    );
+}
+
+#[test]
+fn classify() {
+    let one = Complex::<f64>::new(1.0, 0.0);
+    let zero = Complex::new(0.0, 0.0);
+    let rotation = Moebius::new(
+        Complex::from_polar(1.0, 0.3),
+        zero,
+        zero,
+        Complex::from_polar(1.0, -0.3),
+    );
+    let translation = Moebius::new(one, one, zero, one);
+    let dilation = Moebius::new(one * 2.0, zero, zero, one);
+    let spiral = Moebius::new(Complex::from_polar(2.0, 0.5), zero, zero, one);
+
+    let mut rng = XorShiftRng::seed_from_u64(0xDEAD11);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let c: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let conj = |m: Moebius<Complex<f64>>| c.chain(m).chain(c.inv()).classify(EPS);
+        assert_eq!(conj(Moebius::identity()), MoebiusClass::Identity);
+        assert_eq!(conj(rotation), MoebiusClass::Elliptic);
+        assert_eq!(conj(translation), MoebiusClass::Parabolic);
+        assert_eq!(conj(dilation), MoebiusClass::Hyperbolic);
+        assert_eq!(conj(spiral), MoebiusClass::Loxodromic);
+    }
+    assert_abs_diff_eq!(dilation.trace(), Complex::new(3.0, 0.0));
+}
+
+#[test]
+fn fixed_points() {
+    let mut rng = XorShiftRng::seed_from_u64(0xDEAD12);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let m: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let [p, q] = m.fixed_points();
+        let (p, q) = (p.unwrap(), q.unwrap());
+        assert_abs_diff_eq!(m.apply(p), p, epsilon = EPS);
+        assert_abs_diff_eq!(m.apply(q), q, epsilon = EPS);
+        assert!(m.deriv(p).norm() <= m.deriv(q).norm());
+    }
+
+    let one = Complex::<f64>::new(1.0, 0.0);
+    let zero = Complex::new(0.0, 0.0);
+    assert_eq!(
+        Moebius::new(one, one, zero, one).fixed_points(),
+        [None, None]
+    );
+    assert_eq!(
+        Moebius::new(one, one, zero, one * 2.0).fixed_points(),
+        [Some(one), None]
+    );
+    assert_eq!(
+        Moebius::new(one * 2.0, one, zero, one).fixed_points(),
+        [None, Some(-one)]
+    );
+}
+
+#[test]
+fn power() {
+    let mut rng = XorShiftRng::seed_from_u64(0xDEAD13);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let m: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let (s, t) = (rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+        assert_same(m.power(0.0), Moebius::identity(), &mut rng);
+        assert_same(m.power(1.0), m, &mut rng);
+        assert_same(m.power(-1.0), m.inv(), &mut rng);
+        assert_same(m.power(0.5).chain(m.power(0.5)), m, &mut rng);
+        assert_same(m.power(s).chain(m.power(t)), m.power(s + t), &mut rng);
+    }
+
+    let one = Complex::<f64>::new(1.0, 0.0);
+    let zero = Complex::new(0.0, 0.0);
+    let translation = Moebius::new(one, one, zero, one);
+    assert_same(
+        translation.power(0.25),
+        Moebius::new(one, one * 0.25, zero, one),
+        &mut rng,
+    );
+}
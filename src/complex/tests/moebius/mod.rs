@@ -1,2 +1,3 @@
+mod advanced;
 mod chain;
 mod transform;
@@ -1,6 +1,9 @@
 use crate::{
     complex::{Complex, Moebius, Quaternion},
     distr::{Invertible, Normal, Unit},
+    traits::Dot,
+    transform::Jacobian,
+    Map,
 };
 use approx::*;
 use rand_::prelude::*;
@@ -58,3 +61,23 @@ fn quaternion_directional_derivation() {
         );
     }
 }
+
+#[test]
+fn complex_jacobian() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF3);
+
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let p: Complex<f64> = rng.sample(Normal);
+        let v: Complex<f64> = rng.sample(Normal);
+
+        let jac = a.jacobian(p);
+        let deriv = Map::deriv(&a, p, v);
+        assert_abs_diff_eq!(
+            Complex::from(jac.dot(v.into_vector())),
+            deriv,
+            epsilon = 1e-12 * deriv.norm()
+        );
+        assert_abs_diff_eq!(jac.det(), a.jacobian_det(p), epsilon = 1e-12 * jac.det().abs());
+    }
+}
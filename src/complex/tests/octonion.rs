@@ -0,0 +1,118 @@
+use crate::{
+    complex::{CayleyDickson, Complex, Octonion, Quaternion},
+    distr::*,
+    traits::Conj,
+};
+use approx::*;
+use num_traits::One;
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+type Of = Octonion<f64>;
+
+#[test]
+fn imaginary_units() {
+    type Oi = Octonion<i32>;
+    for i in 1..8 {
+        assert_eq!(Oi::unit(i) * Oi::unit(i), -Oi::one());
+        for j in 1..8 {
+            if i != j {
+                assert_eq!(Oi::unit(i) * Oi::unit(j), -(Oi::unit(j) * Oi::unit(i)));
+            }
+        }
+    }
+    assert_eq!(Oi::unit(1) * Oi::unit(2), Oi::unit(3));
+    assert_eq!(Oi::unit(1) * Oi::unit(4), Oi::unit(5));
+}
+
+#[test]
+fn non_associative() {
+    type Oi = Octonion<i32>;
+    let (a, b, c) = (Oi::unit(1), Oi::unit(2), Oi::unit(4));
+    assert_eq!((a * b) * c, -(a * (b * c)));
+}
+
+#[test]
+fn alternative() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED0);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Of = rng.sample(&Normal);
+        let b: Of = rng.sample(&Normal);
+        let c: Of = rng.sample(&Normal);
+        assert_abs_diff_eq!((a * a) * b, a * (a * b), epsilon = 1e-12);
+        assert_abs_diff_eq!((a * b) * b, a * (b * b), epsilon = 1e-12);
+        assert_abs_diff_eq!((a * b) * a, a * (b * a), epsilon = 1e-12);
+        // Moufang identity.
+        assert_abs_diff_eq!(a * (b * (a * c)), ((a * b) * a) * c, epsilon = 1e-11);
+    }
+}
+
+#[test]
+fn norm() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Of = rng.sample(&Normal);
+        let b: Of = rng.sample(&Normal);
+        assert_abs_diff_eq!((a * b).norm(), a.norm() * b.norm(), epsilon = 1e-12);
+        let u: Of = rng.sample(&Unit);
+        assert_abs_diff_eq!(u.norm(), 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(a.normalize().norm(), 1.0, epsilon = 1e-12);
+    }
+}
+
+#[test]
+#[allow(clippy::eq_op)]
+fn inversion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Of = rng.sample(&NonZero);
+        let b: Of = rng.sample(&Normal);
+        assert_abs_diff_eq!(a / a, Of::one(), epsilon = 1e-14);
+        assert_abs_diff_eq!((b / a) * a, b, epsilon = 1e-12);
+        assert_abs_diff_eq!(1.0 / a, a.inv(), epsilon = 1e-14);
+    }
+}
+
+#[test]
+fn conjugation() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Of = rng.sample(&Normal);
+        let b: Of = rng.sample(&Normal);
+        assert_abs_diff_eq!((a * b).conj(), b.conj() * a.conj(), epsilon = 1e-12);
+        assert_abs_diff_eq!(a * a.conj(), Of::one() * a.norm_sqr(), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn quaternion_subalgebra() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED4);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let p: Quaternion<f64> = rng.sample(&Normal);
+        let q: Quaternion<f64> = rng.sample(&Normal);
+        assert_abs_diff_eq!(Of::from(p) * Of::from(q), Of::from(p * q), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn cayley_dickson_quaternion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED5);
+    let split = |q: Quaternion<f64>| {
+        let (w, x, y, z) = q.into();
+        CayleyDickson::new(Complex::new(w, x), Complex::new(y, z))
+    };
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let p: Quaternion<f64> = rng.sample(&Normal);
+        let q: Quaternion<f64> = rng.sample(&Normal);
+        let (a, b) = (split(p) * split(q)).split();
+        let (c, d) = split(p * q).split();
+        assert_abs_diff_eq!(a, c, epsilon = 1e-12);
+        assert_abs_diff_eq!(b, d, epsilon = 1e-12);
+        assert_abs_diff_eq!(split(p).norm_sqr(), p.norm_sqr(), epsilon = 1e-12);
+        let (a, b) = Conj::conj(split(p)).split();
+        let (c, d) = split(p.conj()).split();
+        assert_abs_diff_eq!(a, c, epsilon = 1e-12);
+        assert_abs_diff_eq!(b, d, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    complex::{Complex, Moebius, ProjectiveComplex},
+    distr::{Invertible, Normal, Unit},
+    vector::Vector3,
+};
+use approx::*;
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+const EPS: f64 = 1e-10;
+
+#[test]
+fn infinity() {
+    let inf = ProjectiveComplex::<f64>::infinity();
+    assert!(inf.is_infinity());
+    assert_eq!(inf.to_complex(), None);
+    assert_eq!(
+        inf,
+        ProjectiveComplex::new(Complex::new(0.0, 2.0), Complex::new(0.0, 0.0))
+    );
+
+    let z = Complex::new(1.0, -2.0);
+    let p = ProjectiveComplex::from(z);
+    assert!(!p.is_infinity());
+    assert_eq!(p.to_complex(), Some(z));
+    assert_eq!(p, ProjectiveComplex::new(z * 2.0, Complex::new(2.0, 0.0)));
+}
+
+#[test]
+fn moebius_pole() {
+    let mut rng = XorShiftRng::seed_from_u64(0x9C0);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let m: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let pole = ProjectiveComplex::new(-m.d(), m.c());
+        assert!(m.apply_projective(pole).is_infinity());
+        let inf = m.apply_projective(ProjectiveComplex::infinity());
+        assert_abs_diff_eq!(inf.to_complex().unwrap(), m.a() / m.c(), epsilon = EPS);
+        assert_abs_diff_eq!(
+            m.inv().apply_projective(inf),
+            ProjectiveComplex::infinity(),
+            epsilon = EPS
+        );
+
+        let z: Complex<f64> = rng.sample(Normal);
+        let w = m.apply_projective(z.into()).to_complex().unwrap();
+        assert_abs_diff_eq!(w, m.apply(z), epsilon = EPS);
+    }
+}
+
+#[test]
+fn moebius_chain() {
+    let mut rng = XorShiftRng::seed_from_u64(0x9C1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let b: Moebius<Complex<f64>> = rng.sample(Invertible);
+        let p = ProjectiveComplex::from_sphere(rng.sample(Unit));
+        assert_abs_diff_eq!(
+            a.chain(b).apply_projective(p),
+            a.apply_projective(b.apply_projective(p)),
+            epsilon = EPS
+        );
+    }
+}
+
+#[test]
+fn sphere() {
+    let north = Vector3::from([0.0, 0.0, 1.0]);
+    let south = Vector3::from([0.0, 0.0, -1.0]);
+    assert!(ProjectiveComplex::from_sphere(north).is_infinity());
+    assert_eq!(ProjectiveComplex::infinity().to_sphere(), north);
+    assert_eq!(
+        ProjectiveComplex::from_sphere(south).to_complex(),
+        Some(Complex::new(0.0, 0.0))
+    );
+    assert_eq!(
+        ProjectiveComplex::from(Complex::new(0.0, 0.0)).to_sphere(),
+        south
+    );
+    assert_abs_diff_eq!(
+        ProjectiveComplex::from(Complex::new(1.0, 0.0)).to_sphere(),
+        Vector3::from([1.0, 0.0, 0.0])
+    );
+
+    let mut rng = XorShiftRng::seed_from_u64(0x9C2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let v: Vector3<f64> = rng.sample(Unit);
+        let p = ProjectiveComplex::from_sphere(v);
+        assert_abs_diff_eq!(p.to_sphere(), v, epsilon = EPS);
+        assert_abs_diff_eq!(p.normalize().to_sphere(), v, epsilon = EPS);
+
+        let z: Complex<f64> = rng.sample(Normal);
+        let s = ProjectiveComplex::from(z).to_sphere();
+        assert_abs_diff_eq!(s.length(), 1.0, epsilon = EPS);
+        assert_abs_diff_eq!(
+            ProjectiveComplex::from_sphere(s).to_complex().unwrap(),
+            z,
+            epsilon = EPS
+        );
+    }
+}
+
+#[test]
+fn huge() {
+    let z = Complex::new(1e300, -1e300);
+    let p = ProjectiveComplex::from(z);
+    assert_abs_diff_eq!(
+        p.normalize().to_sphere(),
+        Vector3::from([0.0, 0.0, 1.0]),
+        epsilon = EPS
+    );
+    assert!(p.chordal_distance(ProjectiveComplex::infinity()) < EPS);
+}
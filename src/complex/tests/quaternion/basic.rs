@@ -112,3 +112,12 @@ fn one() {
     let a = Quaternion::<f32>::one();
     assert_abs_diff_eq!(a, Quaternion::new(1.0, 0.0, 0.0, 0.0));
 }
+
+#[test]
+fn consts() {
+    const Q: Quaternion<f64> = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    type Q64 = Quaternion<f64>;
+    assert_eq!(Q, Q64::ONE + Q64::I * 2.0 + Q64::J * 3.0 + Q64::K * 4.0);
+    assert_eq!(Quaternion::<i32>::ZERO, Quaternion::zero());
+    assert_eq!(Quaternion::<i32>::I * Quaternion::J, Quaternion::K);
+}
@@ -0,0 +1,74 @@
+use crate::{complex::SplitComplex, distr::*, traits::Dot};
+use approx::*;
+use num_traits::One;
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+type Sf = SplitComplex<f64>;
+
+#[test]
+fn units() {
+    type Si = SplitComplex<i32>;
+    assert_eq!(Si::j() * Si::j(), Si::one());
+    let (p, q) = (Si::new(1, 1), Si::new(1, -1));
+    assert_eq!(p * q, Si::new(0, 0));
+    assert_eq!(p.modulus_sqr(), 0);
+}
+
+#[test]
+fn mul() {
+    let a = Sf::new(1.0, 2.0);
+    let b = Sf::new(5.0, 6.0);
+    assert_abs_diff_eq!(a * b, Sf::new(17.0, 16.0));
+    assert_abs_diff_eq!(b * a, Sf::new(17.0, 16.0));
+    assert_abs_diff_eq!(2.0 * a, Sf::new(2.0, 4.0));
+}
+
+#[test]
+fn modulus() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5917);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Sf = rng.sample(&Normal);
+        let b: Sf = rng.sample(&Normal);
+        assert_abs_diff_eq!(
+            (a * b).modulus_sqr(),
+            a.modulus_sqr() * b.modulus_sqr(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(a * a.conj(), Sf::one() * a.modulus_sqr(), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            a.into_matrix().dot(b.into_matrix()),
+            (a * b).into_matrix(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+#[allow(clippy::eq_op)]
+fn inversion() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5918);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Sf = rng.sample(&NonZero);
+        if a.modulus_sqr().abs() < 1e-3 {
+            continue;
+        }
+        assert_abs_diff_eq!(a / a, Sf::one(), epsilon = 1e-10);
+        assert_abs_diff_eq!(1.0 / a, a.inv(), epsilon = 1e-14);
+    }
+}
+
+#[test]
+fn polar() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5919);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Sf = rng.sample(&Normal);
+        let b: Sf = rng.sample(&Normal);
+        assert_abs_diff_eq!((a + b).exp(), a.exp() * b.exp(), epsilon = 1e-10);
+        let (r, phi) = (a.re().abs() + 0.1, a.im());
+        let c = Sf::from_polar(r, phi);
+        assert_abs_diff_eq!(c.modulus_sqr(), r * r, epsilon = 1e-10);
+        assert_abs_diff_eq!(c.arg(), phi, epsilon = 1e-10);
+    }
+}
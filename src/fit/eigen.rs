@@ -0,0 +1,101 @@
+use crate::{Matrix, Vector};
+use num_traits::{Float, One};
+
+/// Maximum number of Jacobi sweeps, convergence is quadratic so it is never reached in practice.
+const MAX_SWEEPS: usize = 64;
+
+/// Eigen decomposition of symmetric matrix using cyclic Jacobi method.
+///
+/// Returns eigenvalues and matrix which columns are corresponding eigenvectors.
+pub(crate) fn symmetric_eigen<T, const N: usize>(
+    mut a: Matrix<T, N, N>,
+) -> (Vector<T, N>, Matrix<T, N, N>)
+where
+    T: Float,
+{
+    let mut v = Matrix::<T, N, N>::one();
+    let two = T::one() + T::one();
+    for _ in 0..MAX_SWEEPS {
+        let (mut off, mut diag) = (T::zero(), T::zero());
+        for i in 0..N {
+            for j in 0..N {
+                let x = a[(i, j)] * a[(i, j)];
+                if i == j {
+                    diag = diag + x;
+                } else {
+                    off = off + x;
+                }
+            }
+        }
+        if off <= T::epsilon() * T::epsilon() * diag {
+            break;
+        }
+        for p in 0..N {
+            for q in (p + 1)..N {
+                let apq = a[(p, q)];
+                if apq == T::zero() {
+                    continue;
+                }
+                let theta = (a[(q, q)] - a[(p, p)]) / (two * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = (t * t + T::one()).sqrt().recip();
+                let s = t * c;
+                for k in 0..N {
+                    let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                    a[(k, p)] = c * akp - s * akq;
+                    a[(k, q)] = s * akp + c * akq;
+                    let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                    v[(k, p)] = c * vkp - s * vkq;
+                    v[(k, q)] = s * vkp + c * vkq;
+                }
+                for k in 0..N {
+                    let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                    a[(p, k)] = c * apk - s * aqk;
+                    a[(q, k)] = s * apk + c * aqk;
+                }
+            }
+        }
+    }
+    (Vector::indices().map(|i| a[(i, i)]), v)
+}
+
+/// Eigenvector of symmetric matrix corresponding to its largest eigenvalue.
+pub(crate) fn symmetric_max_eigenvector<T, const N: usize>(a: Matrix<T, N, N>) -> Vector<T, N>
+where
+    T: Float,
+{
+    let (values, vectors) = symmetric_eigen(a);
+    let k = (1..N).fold(0, |k, i| if values[i] > values[k] { i } else { k });
+    Vector::indices().map(|i| vectors[(i, k)])
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{distr::Normal, traits::Dot};
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    #[test]
+    fn decomposition() {
+        let mut rng = XorShiftRng::seed_from_u64(0xE16E);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m: Matrix<f64, 4, 4> = rng.sample(&Normal);
+            let a = m + m.transpose();
+            let (values, vectors) = symmetric_eigen(a);
+            assert_abs_diff_eq!(
+                vectors.transpose().dot(vectors),
+                Matrix::one(),
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(
+                a.dot(vectors),
+                vectors.dot(Matrix::diagonal(values)),
+                epsilon = 1e-10
+            );
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::{centroid, Fit};
+use crate::{
+    traits::{Dot, Outer},
+    transform::{Affine, Linear, Shift},
+    Map, Matrix, Vector,
+};
+use num_traits::{Float, Zero};
+
+/// Find shift that maps `src` points onto `dst` ones.
+pub fn shift<T, const N: usize>(
+    src: &[Vector<T, N>],
+    dst: &[Vector<T, N>],
+) -> Option<Fit<Shift<T, N>, T>>
+where
+    T: Float,
+{
+    assert_eq!(src.len(), dst.len());
+    let shift = Shift::from_vector(centroid(dst)? - centroid(src)?);
+    Some(Fit::evaluate(shift, src, dst))
+}
+
+/// Find general affine transformation that maps `src` points onto `dst` ones.
+///
+/// At least `N + 1` points in general position are required.
+pub fn affine<T, const N: usize>(
+    src: &[Vector<T, N>],
+    dst: &[Vector<T, N>],
+) -> Option<Fit<Affine<T, N>, T>>
+where
+    T: Float,
+{
+    assert_eq!(src.len(), dst.len());
+    let (ca, cb) = (centroid(src)?, centroid(dst)?);
+    let (cov, cross) = src.iter().zip(dst.iter()).fold(
+        (Matrix::<T, N, N>::zero(), Matrix::<T, N, N>::zero()),
+        |(cov, cross), (&a, &b)| {
+            let (a, b) = (a - ca, b - cb);
+            (cov + a.outer(a), cross + b.outer(a))
+        },
+    );
+    let det = cov.det();
+    let trace = (0..N).fold(T::zero(), |s, i| s + cov[(i, i)]);
+    if det <= T::epsilon() * (trace / T::from(N).unwrap()).powi(N as i32) {
+        return None;
+    }
+    let lin = Linear::from_matrix(cross.dot(cov.adj() / det));
+    let affine = Affine::new(Shift::from_vector(cb - lin.apply(ca)), lin);
+    Some(Fit::evaluate(affine, src, dst))
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::{Invertible, Normal};
+    use crate::{fit::Residuals, Transform};
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const POINTS: usize = 8;
+
+    #[test]
+    fn exact_shift() {
+        let mut rng = XorShiftRng::seed_from_u64(0xF17);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let t: Shift<f64, 3> = rng.sample(&Normal);
+            let src: [Vector<f64, 3>; POINTS] = Vector::init(|| rng.sample(&Normal)).into();
+            let dst = Vector::from(src).map(|p| t.apply(p)).into_array();
+
+            let fit = shift(&src, &dst).unwrap();
+            assert_abs_diff_eq!(*fit.transform(), t, epsilon = 1e-12);
+            assert_eq!(fit.residuals().count(), POINTS);
+            assert_abs_diff_eq!(fit.residuals().max(), 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn exact_affine() {
+        let mut rng = XorShiftRng::seed_from_u64(0xF18);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Affine<f64, 3> = rng.sample(&Invertible);
+            let src: [Vector<f64, 3>; POINTS] = Vector::init(|| rng.sample(&Normal)).into();
+            let dst = Vector::from(src).map(|p| a.apply(p)).into_array();
+
+            let fit = affine(&src, &dst).unwrap();
+            assert_abs_diff_eq!(*fit.transform(), a, epsilon = 1e-8);
+            assert_abs_diff_eq!(fit.residuals().rms(), 0.0, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn least_squares() {
+        let mut rng = XorShiftRng::seed_from_u64(0xF19);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Affine<f64, 2> = rng.sample(&Invertible);
+            let src: [Vector<f64, 2>; POINTS] = Vector::init(|| rng.sample(&Normal)).into();
+            let dst = Vector::from(src)
+                .map(|p| a.apply(p) + rng.sample::<Vector<f64, 2>, _>(&Normal) * 0.1)
+                .into_array();
+
+            let fit = affine(&src, &dst).unwrap();
+            let rms = fit.residuals().rms();
+            assert!(rms <= Residuals::evaluate(&a, &src, &dst).rms());
+            let d: Shift<f64, 2> = rng.sample(&Normal);
+            let b = Affine::new(
+                Shift::from_vector(d.into_vector() * 1e-3),
+                Linear::identity(),
+            )
+            .chain(*fit.transform());
+            assert!(rms <= Residuals::evaluate(&b, &src, &dst).rms());
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let p = Vector::from([1.0, 2.0, 3.0]);
+        assert!(shift::<f64, 3>(&[], &[]).is_none());
+        assert!(affine::<f64, 3>(&[], &[]).is_none());
+        assert!(affine(&[p; 4], &[p; 4]).is_none());
+        let src = [p, p * 2.0, p * 3.0, p * 4.0];
+        assert!(affine(&src, &src).is_none());
+    }
+}
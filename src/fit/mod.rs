@@ -0,0 +1,171 @@
+//! Estimation of transformations from point correspondences.
+//!
+//! Each function takes two slices of the same length, source and destination points,
+//! and finds the transformation of the requested kind that maps the former onto the latter
+//! with the least sum of squared distances.
+//!
+//! `None` is returned when the input is empty or degenerate,
+//! so that the transformation cannot be determined.
+
+pub(crate) mod eigen;
+mod linear;
+mod moebius;
+mod rotation;
+
+pub use linear::*;
+pub use moebius::*;
+pub use rotation::*;
+
+use crate::{Map, Vector};
+use num_traits::{Float, Zero};
+
+/// Statistics of distances between the mapped source points and the destination ones.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Residuals<T> {
+    count: usize,
+    mean: T,
+    rms: T,
+    max: T,
+}
+
+impl<T> Residuals<T>
+where
+    T: Float,
+{
+    /// Compute statistics from the sequence of distances.
+    pub fn from_errors<I: IntoIterator<Item = T>>(errors: I) -> Self {
+        let (count, sum, sqr_sum, max) = errors
+            .into_iter()
+            .fold((0, T::zero(), T::zero(), T::zero()), |(n, s, q, m), e| {
+                (n + 1, s + e, q + e * e, m.max(e))
+            });
+        if count == 0 {
+            return Self {
+                count,
+                mean: T::zero(),
+                rms: T::zero(),
+                max: T::zero(),
+            };
+        }
+        let n = T::from(count).unwrap();
+        Self {
+            count,
+            mean: sum / n,
+            rms: (sqr_sum / n).sqrt(),
+            max,
+        }
+    }
+
+    /// Evaluate the residuals of the mapping on given point pairs.
+    pub fn evaluate<X, const N: usize>(map: &X, src: &[Vector<T, N>], dst: &[Vector<T, N>]) -> Self
+    where
+        X: Map<Vector<T, N>>,
+    {
+        assert_eq!(src.len(), dst.len());
+        Self::from_errors(
+            src.iter()
+                .zip(dst.iter())
+                .map(|(&a, &b)| (map.apply(a) - b).length()),
+        )
+    }
+}
+
+impl<T> Residuals<T>
+where
+    T: Copy,
+{
+    /// Number of point pairs.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Mean distance.
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+    /// Root mean square distance.
+    pub fn rms(&self) -> T {
+        self.rms
+    }
+    /// Maximum distance.
+    pub fn max(&self) -> T {
+        self.max
+    }
+}
+
+/// Estimated transformation together with its residuals.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Fit<X, T> {
+    transform: X,
+    residuals: Residuals<T>,
+}
+
+impl<X, T> Fit<X, T> {
+    /// Construct from transformation and its residuals.
+    pub fn new(transform: X, residuals: Residuals<T>) -> Self {
+        Self {
+            transform,
+            residuals,
+        }
+    }
+    /// Split into transformation and residuals.
+    pub fn split(self) -> (X, Residuals<T>) {
+        (self.transform, self.residuals)
+    }
+    /// Get transformation reference.
+    pub fn transform(&self) -> &X {
+        &self.transform
+    }
+    /// Take transformation discarding residuals.
+    pub fn into_transform(self) -> X {
+        self.transform
+    }
+    /// Get residuals reference.
+    pub fn residuals(&self) -> &Residuals<T> {
+        &self.residuals
+    }
+}
+
+impl<X, T> Fit<X, T>
+where
+    T: Float,
+{
+    /// Evaluate residuals of the vector mapping and pack them together.
+    fn evaluate<const N: usize>(transform: X, src: &[Vector<T, N>], dst: &[Vector<T, N>]) -> Self
+    where
+        X: Map<Vector<T, N>>,
+    {
+        let residuals = Residuals::evaluate(&transform, src, dst);
+        Self::new(transform, residuals)
+    }
+}
+
+/// Mean of the points.
+fn centroid<T, const N: usize>(points: &[Vector<T, N>]) -> Option<Vector<T, N>>
+where
+    T: Float,
+{
+    if points.is_empty() {
+        return None;
+    }
+    let sum = points.iter().fold(Vector::zero(), |s, &p| s + p);
+    Some(sum / T::from(points.len()).unwrap())
+}
+
+#[cfg(all(test, feature = "approx"))]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn residuals() {
+        let r = Residuals::from_errors([3.0, 0.0, 4.0, 1.0].iter().copied());
+        assert_eq!(r.count(), 4);
+        assert_abs_diff_eq!(r.mean(), 2.0);
+        assert_abs_diff_eq!(r.rms(), 6.5f64.sqrt());
+        assert_abs_diff_eq!(r.max(), 4.0);
+
+        let e = Residuals::<f64>::from_errors(None);
+        assert_eq!(e.count(), 0);
+        assert_abs_diff_eq!(e.rms(), 0.0);
+    }
+}
@@ -0,0 +1,57 @@
+use super::{Fit, Residuals};
+use crate::complex::{Complex, Moebius};
+use num_traits::Float;
+
+/// Find Moebius transformation that maps three distinct `src` points onto three distinct `dst` ones.
+///
+/// Such transformation is unique, so the residuals only reflect rounding errors.
+pub fn moebius<T>(src: [Complex<T>; 3], dst: [Complex<T>; 3]) -> Option<Fit<Moebius<Complex<T>>, T>>
+where
+    T: Float,
+{
+    let distinct = |z: [Complex<T>; 3]| z[0] != z[1] && z[1] != z[2] && z[2] != z[0];
+    if !distinct(src) || !distinct(dst) {
+        return None;
+    }
+    let m = Moebius::from_points(src, dst);
+    let residuals = Residuals::from_errors(
+        src.iter()
+            .zip(dst.iter())
+            .map(|(&z, &w)| (m.apply(z) - w).norm()),
+    );
+    Some(Fit::new(m, residuals))
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::{Invertible, Normal};
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    #[test]
+    fn exact() {
+        let mut rng = XorShiftRng::seed_from_u64(0xF40);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m: Moebius<Complex<f64>> = rng.sample(&Invertible);
+            let src: [Complex<f64>; 3] = [(); 3].map(|()| rng.sample(&Normal));
+            let dst = src.map(|z| m.apply(z));
+
+            let fit = moebius(src, dst).unwrap();
+            assert_abs_diff_eq!(fit.residuals().max(), 0.0, epsilon = 1e-8);
+            let z: Complex<f64> = rng.sample(&Normal);
+            assert_abs_diff_eq!(fit.transform().apply(z), m.apply(z), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let (a, b) = (Complex::new(1.0, 2.0), Complex::new(-1.0, 0.5));
+        let c = Complex::new(0.0, 1.0);
+        assert!(moebius([a, a, b], [a, b, c]).is_none());
+        assert!(moebius([a, b, c], [c, b, c]).is_none());
+    }
+}
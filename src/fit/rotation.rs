@@ -0,0 +1,273 @@
+use super::{centroid, eigen::symmetric_max_eigenvector, Fit};
+use crate::{
+    complex::{Complex, Quaternion},
+    traits::{Dot, Outer},
+    transform::{
+        Isometry2, Isometry3, Rotation2, Rotation3, Scale, Shift, Similarity2, Similarity3,
+    },
+    Map, Matrix, Transform, Vector,
+};
+use num_traits::{Float, Zero};
+
+/// Rotation that best aligns pairs of vectors in the plane.
+///
+/// Closed-form solution, the rotation angle is the argument of `sum(conj(a) * b)`.
+fn align2<T, I>(pairs: I) -> Rotation2<T>
+where
+    T: Float,
+    I: Iterator<Item = (Vector<T, 2>, Vector<T, 2>)>,
+{
+    let (c, s) = pairs.fold((T::zero(), T::zero()), |(c, s), (a, b)| {
+        (c + a.dot(b), s + a.x() * b.y() - a.y() * b.x())
+    });
+    let n = c.hypot(s);
+    if n > T::zero() {
+        Rotation2::from_complex(Complex::new(c / n, s / n))
+    } else {
+        Rotation2::identity()
+    }
+}
+
+/// Rotation that best aligns pairs of vectors in space.
+///
+/// Uses Horn's method: the optimal quaternion is the eigenvector
+/// corresponding to the largest eigenvalue of symmetric matrix built from cross-covariance.
+/// In contrast to Kabsch algorithm it never produces reflections.
+fn align3<T, I>(pairs: I) -> Rotation3<T>
+where
+    T: Float,
+    I: Iterator<Item = (Vector<T, 3>, Vector<T, 3>)>,
+{
+    let s = pairs.fold(Matrix::<T, 3, 3>::zero(), |s, (a, b)| s + a.outer(b));
+    let (xx, xy, xz) = (s[(0, 0)], s[(0, 1)], s[(0, 2)]);
+    let (yx, yy, yz) = (s[(1, 0)], s[(1, 1)], s[(1, 2)]);
+    let (zx, zy, zz) = (s[(2, 0)], s[(2, 1)], s[(2, 2)]);
+    let n = Matrix::from([
+        [xx + yy + zz, yz - zy, zx - xz, xy - yx],
+        [yz - zy, xx - yy - zz, xy + yx, zx + xz],
+        [zx - xz, xy + yx, yy - xx - zz, yz + zy],
+        [xy - yx, zx + xz, yz + zy, zz - xx - yy],
+    ]);
+    let q = Quaternion::from_vector(symmetric_max_eigenvector(n));
+    Rotation3::from_quaternion(q.normalize())
+}
+
+macro_rules! rigid_fit {
+    ($rotation:ident, $isometry:ident, $similarity:ident, $Rot:ident, $Iso:ident, $Sim:ident, $N:expr, $align:ident) => {
+        /// Find rotation about the origin that maps `src` points onto `dst` ones.
+        pub fn $rotation<T>(src: &[Vector<T, $N>], dst: &[Vector<T, $N>]) -> Option<Fit<$Rot<T>, T>>
+        where
+            T: Float,
+        {
+            assert_eq!(src.len(), dst.len());
+            if src.is_empty() {
+                return None;
+            }
+            let rot = $align(src.iter().copied().zip(dst.iter().copied()));
+            Some(Fit::evaluate(rot, src, dst))
+        }
+
+        /// Find rigid motion (rotation and shift) that maps `src` points onto `dst` ones.
+        pub fn $isometry<T>(src: &[Vector<T, $N>], dst: &[Vector<T, $N>]) -> Option<Fit<$Iso<T>, T>>
+        where
+            T: Float,
+        {
+            assert_eq!(src.len(), dst.len());
+            let (ca, cb) = (centroid(src)?, centroid(dst)?);
+            let rot = $align(src.iter().zip(dst.iter()).map(|(&a, &b)| (a - ca, b - cb)));
+            let iso = $Iso::new(Shift::from_vector(cb - rot.apply(ca)), rot);
+            Some(Fit::evaluate(iso, src, dst))
+        }
+
+        /// Find similarity (uniform scale, rotation and shift) that maps `src` points onto `dst` ones.
+        ///
+        /// The scale is estimated as in Umeyama's method. At least two distinct source points are required.
+        pub fn $similarity<T>(
+            src: &[Vector<T, $N>],
+            dst: &[Vector<T, $N>],
+        ) -> Option<Fit<$Sim<T>, T>>
+        where
+            T: Float,
+        {
+            assert_eq!(src.len(), dst.len());
+            let (ca, cb) = (centroid(src)?, centroid(dst)?);
+            let centered = || src.iter().zip(dst.iter()).map(|(&a, &b)| (a - ca, b - cb));
+            let rot = $align(centered());
+            let (num, den) = centered().fold((T::zero(), T::zero()), |(n, d), (a, b)| {
+                (n + rot.apply(a).dot(b), d + a.square_length())
+            });
+            if den <= T::zero() {
+                return None;
+            }
+            let mag = num / den;
+            let iso = $Iso::new(Shift::from_vector(cb - rot.apply(ca) * mag), rot);
+            Some(Fit::evaluate(
+                $Sim::new(iso, Scale::from_mag(mag)),
+                src,
+                dst,
+            ))
+        }
+    };
+}
+
+rigid_fit!(
+    rotation2,
+    isometry2,
+    similarity2,
+    Rotation2,
+    Isometry2,
+    Similarity2,
+    2,
+    align2
+);
+rigid_fit!(
+    rotation3,
+    isometry3,
+    similarity3,
+    Rotation3,
+    Isometry3,
+    Similarity3,
+    3,
+    align3
+);
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Normal, Uniform},
+        fit::Residuals,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const POINTS: usize = 6;
+    const EPS: f64 = 1e-10;
+
+    macro_rules! rigid_tests {
+        ($name:ident, $rotation:ident, $isometry:ident, $similarity:ident, $Rot:ident, $Iso:ident, $N:expr, $seed:expr) => {
+            mod $name {
+                use super::*;
+
+                fn points(rng: &mut XorShiftRng) -> [Vector<f64, $N>; POINTS] {
+                    Vector::init(|| rng.sample(&Normal)).into()
+                }
+
+                #[test]
+                fn exact() {
+                    let mut rng = XorShiftRng::seed_from_u64($seed);
+                    for _ in 0..SAMPLE_ATTEMPTS {
+                        let r: $Rot<f64> = rng.sample(&Uniform);
+                        let m: $Iso<f64> = rng.sample(&Normal);
+                        let mag = 0.5 + rng.gen::<f64>();
+                        let src = points(&mut rng);
+
+                        let dst = Vector::from(src).map(|p| r.apply(p)).into_array();
+                        let fit = $rotation(&src, &dst).unwrap();
+                        assert_abs_diff_eq!(
+                            fit.transform().to_linear(),
+                            r.to_linear(),
+                            epsilon = EPS
+                        );
+                        assert_abs_diff_eq!(fit.residuals().max(), 0.0, epsilon = EPS);
+
+                        let dst = Vector::from(src).map(|p| m.apply(p)).into_array();
+                        let fit = $isometry(&src, &dst).unwrap();
+                        assert_abs_diff_eq!(
+                            fit.transform().to_affine(),
+                            m.to_affine(),
+                            epsilon = EPS
+                        );
+                        assert_abs_diff_eq!(fit.residuals().max(), 0.0, epsilon = EPS);
+
+                        let dst = Vector::from(src).map(|p| m.apply(p * mag)).into_array();
+                        let fit = $similarity(&src, &dst).unwrap();
+                        let (iso, scale) = fit.transform().split();
+                        assert_abs_diff_eq!(scale.into_mag(), mag, epsilon = EPS);
+                        assert_abs_diff_eq!(iso.to_affine(), m.to_affine(), epsilon = EPS);
+                        assert_abs_diff_eq!(fit.residuals().max(), 0.0, epsilon = EPS);
+                    }
+                }
+
+                #[test]
+                fn least_squares() {
+                    let mut rng = XorShiftRng::seed_from_u64($seed + 1);
+                    for _ in 0..SAMPLE_ATTEMPTS {
+                        let m: $Iso<f64> = rng.sample(&Normal);
+                        let src = points(&mut rng);
+                        let dst = Vector::from(src)
+                            .map(|p| m.apply(p) + rng.sample::<Vector<f64, $N>, _>(&Normal) * 0.1)
+                            .into_array();
+
+                        let fit = $isometry(&src, &dst).unwrap();
+                        let rms = fit.residuals().rms();
+                        assert!(rms <= Residuals::evaluate(&m, &src, &dst).rms());
+                        for _ in 0..4 {
+                            let d: $Iso<f64> = rng.sample(&Normal);
+                            let (t, r) = d.split();
+                            let d = $Iso::new(
+                                Shift::from_vector(t.into_vector() * 1e-3),
+                                $Rot::identity(),
+                            )
+                            .chain(*fit.transform())
+                            .chain($Iso::new(Shift::identity(), r));
+                            assert!(rms <= Residuals::evaluate(&d, &src, &dst).rms());
+                        }
+
+                        let sim = $similarity(&src, &dst).unwrap();
+                        assert!(sim.residuals().rms() <= rms + EPS);
+                    }
+                }
+
+                #[test]
+                fn reflection() {
+                    let mut rng = XorShiftRng::seed_from_u64($seed + 2);
+                    for _ in 0..SAMPLE_ATTEMPTS {
+                        let src = points(&mut rng);
+                        let dst = Vector::from(src)
+                            .map(|p| {
+                                let mut q = p;
+                                q[0] = -q[0];
+                                q
+                            })
+                            .into_array();
+                        let fit = $isometry(&src, &dst).unwrap();
+                        let l = fit.transform().to_affine().linear().into_matrix();
+                        assert_abs_diff_eq!(l.det(), 1.0, epsilon = EPS);
+                    }
+                }
+
+                #[test]
+                fn degenerate() {
+                    let p = Vector::<f64, $N>::zero();
+                    assert!($rotation::<f64>(&[], &[]).is_none());
+                    assert!($isometry::<f64>(&[], &[]).is_none());
+                    assert!($similarity(&[p; 3], &[p; 3]).is_none());
+                }
+            }
+        };
+    }
+
+    rigid_tests!(
+        r2d,
+        rotation2,
+        isometry2,
+        similarity2,
+        Rotation2,
+        Isometry2,
+        2,
+        0xF20
+    );
+    rigid_tests!(
+        r3d,
+        rotation3,
+        isometry3,
+        similarity3,
+        Rotation3,
+        Isometry3,
+        3,
+        0xF30
+    );
+}
@@ -0,0 +1,414 @@
+//! Operations on basis blades.
+//!
+//! Multivector components are stored by the bitmask of basis vectors the blade consists of,
+//! e.g. `e13` has index `0b101`. Basis vectors in the blade are always in ascending order.
+
+use crate::Vector;
+use core::ops::Neg;
+use num_traits::{Num, Zero};
+
+/// Number of basis vectors in the blade.
+pub(crate) fn grade(mask: usize) -> usize {
+    mask.count_ones() as usize
+}
+
+/// Whether the product of blades `a` and `b` changes sign when brought to the canonical order.
+pub(crate) fn reorder_sign(a: usize, b: usize) -> bool {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    swaps % 2 == 1
+}
+
+/// Sign of the reverse of the blade of specified grade.
+pub(crate) fn reverse_sign(grade: usize) -> bool {
+    (grade * grade.saturating_sub(1) / 2) % 2 == 1
+}
+
+fn signed<T: Neg<Output = T>>(x: T, neg: bool) -> T {
+    if neg {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Product of multivectors keeping only the pairs of blades accepted by `filter`.
+///
+/// `metric` contains squares of basis vectors and must be `1`, `0` or `-1`.
+pub(crate) fn product<T, F, const N: usize>(
+    a: Vector<T, N>,
+    b: Vector<T, N>,
+    metric: &[i8],
+    filter: F,
+) -> Vector<T, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+    F: Fn(usize, usize) -> bool,
+{
+    let mut res = Vector::<T, N>::zero();
+    for i in 0..N {
+        if a[i].is_zero() {
+            continue;
+        }
+        for j in 0..N {
+            if !filter(i, j) {
+                continue;
+            }
+            let mut neg = reorder_sign(i, j);
+            let mut common = i & j;
+            let mut k = 0;
+            let mut null = false;
+            while common != 0 {
+                if common & 1 != 0 {
+                    match metric[k] {
+                        0 => null = true,
+                        m => neg ^= m < 0,
+                    }
+                }
+                common >>= 1;
+                k += 1;
+            }
+            if !null {
+                res[i ^ j] = res[i ^ j] + signed(a[i] * b[j], neg);
+            }
+        }
+    }
+    res
+}
+
+/// Multiply each component by the sign depending on the blade.
+pub(crate) fn map_sign<T, F, const N: usize>(a: Vector<T, N>, sign: F) -> Vector<T, N>
+where
+    T: Neg<Output = T>,
+    F: Fn(usize) -> bool,
+{
+    a.enumerate().map(|(i, x)| signed(x, sign(i)))
+}
+
+/// Right complement, maps each blade `e_m` to `s e_c` such that `e_m ∧ (s e_c) = I`.
+pub(crate) fn complement<T, const N: usize>(a: Vector<T, N>) -> Vector<T, N>
+where
+    T: Neg<Output = T> + Zero + Copy,
+{
+    let full = N - 1;
+    let mut res = Vector::<T, N>::zero();
+    for m in 0..N {
+        res[full ^ m] = signed(a[m], reorder_sign(m, full ^ m));
+    }
+    res
+}
+
+/// Inverse of the right complement.
+pub(crate) fn uncomplement<T, const N: usize>(a: Vector<T, N>) -> Vector<T, N>
+where
+    T: Neg<Output = T> + Zero + Copy,
+{
+    let full = N - 1;
+    let mut res = Vector::<T, N>::zero();
+    for m in 0..N {
+        res[m] = signed(a[full ^ m], reorder_sign(m, full ^ m));
+    }
+    res
+}
+
+/// Common multivector functionality for the algebra with specified metric.
+macro_rules! multivector {
+    ($(#[$attr:meta])* $Name:ident, $N:expr, $metric:expr) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq)]
+        pub struct $Name<T> {
+            vec: Vector<T, $N>,
+        }
+
+        impl<T> $Name<T> {
+            /// Squares of basis vectors.
+            pub const METRIC: &'static [i8] = &$metric;
+
+            /// Construct from components indexed by blade bitmask.
+            pub fn from_vector(vec: Vector<T, $N>) -> Self {
+                Self { vec }
+            }
+            pub fn from_array(arr: [T; $N]) -> Self {
+                Self { vec: arr.into() }
+            }
+            pub fn into_vector(self) -> Vector<T, $N> {
+                self.vec
+            }
+            pub fn into_array(self) -> [T; $N] {
+                self.vec.into()
+            }
+            /// Reference to the component of the blade with specified bitmask.
+            pub fn blade_ref(&self, mask: usize) -> &T {
+                &self.vec[mask]
+            }
+            /// Mutable reference to the component of the blade with specified bitmask.
+            pub fn blade_mut(&mut self, mask: usize) -> &mut T {
+                &mut self.vec[mask]
+            }
+        }
+
+        impl<T> From<Vector<T, $N>> for $Name<T> {
+            fn from(vec: Vector<T, $N>) -> Self {
+                Self::from_vector(vec)
+            }
+        }
+        impl<T> From<$Name<T>> for Vector<T, $N> {
+            fn from(mv: $Name<T>) -> Self {
+                mv.into_vector()
+            }
+        }
+        impl<T> From<[T; $N]> for $Name<T> {
+            fn from(arr: [T; $N]) -> Self {
+                Self::from_array(arr)
+            }
+        }
+        impl<T> From<$Name<T>> for [T; $N] {
+            fn from(mv: $Name<T>) -> Self {
+                mv.into_array()
+            }
+        }
+
+        impl<T: Copy> $Name<T> {
+            /// Component of the blade with specified bitmask.
+            pub fn blade(&self, mask: usize) -> T {
+                self.vec[mask]
+            }
+            /// Scalar (grade 0) part.
+            pub fn scalar_part(&self) -> T {
+                self.vec[0]
+            }
+        }
+
+        impl<T: Zero> $Name<T> {
+            /// Multivector containing only scalar part.
+            pub fn from_scalar(x: T) -> Self {
+                let mut res = Self::zero();
+                res.vec[0] = x;
+                res
+            }
+            /// Project onto the subspace of specified grade.
+            pub fn grade(self, k: usize) -> Self {
+                self.vec
+                    .enumerate()
+                    .map(|(i, x)| if blade::grade(i) == k { x } else { T::zero() })
+                    .into()
+            }
+            /// Even part of the multivector.
+            pub fn even(self) -> Self {
+                self.vec
+                    .enumerate()
+                    .map(|(i, x)| if blade::grade(i) % 2 == 0 { x } else { T::zero() })
+                    .into()
+            }
+        }
+
+        impl<T> $Name<T>
+        where
+            T: Neg<Output = T>,
+        {
+            /// Reverse the order of basis vectors in each blade.
+            pub fn reverse(self) -> Self {
+                blade::map_sign(self.vec, |i| blade::reverse_sign(blade::grade(i))).into()
+            }
+            /// Grade involution, negates odd grades.
+            pub fn involute(self) -> Self {
+                blade::map_sign(self.vec, |i| blade::grade(i) % 2 == 1).into()
+            }
+        }
+
+        impl<T> $Name<T>
+        where
+            T: Neg<Output = T> + Num + Copy,
+        {
+            /// Geometric product.
+            pub fn geometric(self, other: Self) -> Self {
+                blade::product(self.vec, other.vec, Self::METRIC, |_, _| true).into()
+            }
+            /// Outer (wedge) product.
+            pub fn wedge(self, other: Self) -> Self {
+                blade::product(self.vec, other.vec, Self::METRIC, |i, j| i & j == 0).into()
+            }
+            /// Inner product in the form of the left contraction.
+            pub fn inner(self, other: Self) -> Self {
+                blade::product(self.vec, other.vec, Self::METRIC, |i, j| i & !j == 0).into()
+            }
+            /// Sandwich product `self * x * reverse(self)`, applies a versor to the multivector.
+            pub fn sandwich(self, x: Self) -> Self {
+                self.geometric(x).geometric(self.reverse())
+            }
+            /// Squared norm, i.e. the scalar part of `self * reverse(self)`.
+            pub fn norm_sqr(self) -> T {
+                blade::product(self.vec, self.reverse().vec, Self::METRIC, |i, j| i == j)[0]
+            }
+        }
+
+        impl<T: Float> $Name<T> {
+            pub fn norm(self) -> T {
+                self.norm_sqr().abs().sqrt()
+            }
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+        }
+
+        impl<T: Float> Normalize for $Name<T> {
+            fn normalize(self) -> Self {
+                $Name::normalize(self)
+            }
+        }
+
+        impl<T> Neg for $Name<T>
+        where
+            T: Neg<Output = T>,
+        {
+            type Output = Self;
+            fn neg(self) -> Self {
+                (-self.vec).into()
+            }
+        }
+        impl<T> Add for $Name<T>
+        where
+            T: Add<Output = T>,
+        {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                (self.vec + other.vec).into()
+            }
+        }
+        impl<T> Sub for $Name<T>
+        where
+            T: Sub<Output = T>,
+        {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                (self.vec - other.vec).into()
+            }
+        }
+        impl<T> Mul for $Name<T>
+        where
+            T: Neg<Output = T> + Num + Copy,
+        {
+            type Output = Self;
+            fn mul(self, other: Self) -> Self {
+                self.geometric(other)
+            }
+        }
+        impl<T> Mul<T> for $Name<T>
+        where
+            T: Mul<Output = T> + Copy,
+        {
+            type Output = Self;
+            fn mul(self, other: T) -> Self {
+                (self.vec * other).into()
+            }
+        }
+        impl<T> Div<T> for $Name<T>
+        where
+            T: Div<Output = T> + Copy,
+        {
+            type Output = Self;
+            fn div(self, other: T) -> Self {
+                (self.vec / other).into()
+            }
+        }
+
+        impl<T> AddAssign for $Name<T>
+        where
+            T: AddAssign,
+        {
+            fn add_assign(&mut self, other: Self) {
+                self.vec += other.vec;
+            }
+        }
+        impl<T> SubAssign for $Name<T>
+        where
+            T: SubAssign,
+        {
+            fn sub_assign(&mut self, other: Self) {
+                self.vec -= other.vec;
+            }
+        }
+        impl<T> MulAssign for $Name<T>
+        where
+            Self: Mul<Output = Self> + Copy,
+        {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl<T> Zero for $Name<T>
+        where
+            T: Zero,
+        {
+            fn zero() -> Self {
+                Self::from_vector(Vector::zero())
+            }
+            fn is_zero(&self) -> bool {
+                self.vec.is_zero()
+            }
+        }
+        impl<T> One for $Name<T>
+        where
+            T: Neg<Output = T> + Num + Copy,
+        {
+            fn one() -> Self {
+                Self::from_scalar(T::one())
+            }
+        }
+
+        impl<T: Debug> Debug for $Name<T> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                write!(f, "{}(", stringify!($Name))?;
+                for i in 0..$N {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", self.vec[i])?;
+                }
+                write!(f, ")")
+            }
+        }
+        impl<T: Display> Display for $Name<T> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                write!(f, "{}(", stringify!($Name))?;
+                for i in 0..$N {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", self.vec[i])?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T> AbsDiffEq for $Name<T>
+        where
+            T: AbsDiffEq<Epsilon = T> + Copy,
+        {
+            type Epsilon = T;
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                abs_diff_eq!(self.vec, other.vec, epsilon = epsilon)
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl<T> Distribution<$Name<T>> for Normal
+        where
+            Normal: Distribution<Vector<T, $N>>,
+        {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $Name<T> {
+                rng.sample(self).into()
+            }
+        }
+    };
+}
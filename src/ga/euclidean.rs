@@ -0,0 +1,341 @@
+#[cfg(feature = "rand")]
+use crate::distr::Normal;
+use crate::{
+    ga::blade,
+    traits::Normalize,
+    transform::{Rotation2, Rotation3},
+    Complex, Quaternion, Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use num_traits::{Float, Num, One, Zero};
+#[cfg(feature = "rand")]
+use rand_::{distributions::Distribution, Rng};
+
+multivector!(
+    /// Multivector of the two-dimensional Euclidean geometric algebra.
+    ///
+    /// Components are `(1, e1, e2, e12)`.
+    Multivector2,
+    4,
+    [1, 1]
+);
+
+multivector!(
+    /// Multivector of the three-dimensional Euclidean geometric algebra.
+    ///
+    /// Components are `(1, e1, e2, e12, e3, e13, e23, e123)`.
+    Multivector3,
+    8,
+    [1, 1, 1]
+);
+
+impl<T> Multivector2<T>
+where
+    T: Zero,
+{
+    pub fn from_vector2(v: Vector<T, 2>) -> Self {
+        let (x, y) = v.into();
+        Self::from_array([T::zero(), x, y, T::zero()])
+    }
+    pub fn from_bivector(b: T) -> Self {
+        Self::from_array([T::zero(), T::zero(), T::zero(), b])
+    }
+}
+
+impl<T> Multivector2<T>
+where
+    T: Zero + One,
+{
+    /// Unit pseudoscalar `e12`.
+    pub fn pseudoscalar() -> Self {
+        Self::from_bivector(T::one())
+    }
+}
+
+impl<T: Copy> Multivector2<T> {
+    /// Vector (grade 1) part.
+    pub fn vector2(&self) -> Vector<T, 2> {
+        Vector::from([self.blade(0b01), self.blade(0b10)])
+    }
+    /// Bivector (grade 2) part.
+    pub fn bivector(&self) -> T {
+        self.blade(0b11)
+    }
+}
+
+impl<T> Multivector2<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Dual, i.e. `self * I⁻¹`.
+    pub fn dual(self) -> Self {
+        -self.geometric(Self::pseudoscalar())
+    }
+    /// Inverse of the dual, i.e. `self * I`.
+    pub fn undual(self) -> Self {
+        self.geometric(Self::pseudoscalar())
+    }
+
+    /// Convert the rotor to rotation.
+    ///
+    /// Rotor `R` rotates vectors by `R x reverse(R)`.
+    pub fn to_rotation2(self) -> Rotation2<T> {
+        let (s, b) = (self.scalar_part(), self.bivector());
+        let two = T::one() + T::one();
+        Rotation2::from_complex(Complex::new(s * s - b * b, -(two * s * b)))
+    }
+}
+
+impl<T: Float> Multivector2<T> {
+    /// Rotor rotating vectors by `angle` counterclockwise.
+    pub fn rotor(angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        Self::from_scalar(half.cos()) - Self::from_bivector(half.sin())
+    }
+    /// Rotor corresponding to the rotation.
+    pub fn from_rotation2(rot: Rotation2<T>) -> Self {
+        let half = rot.into_complex().sqrt();
+        Self::from_scalar(half.re()) - Self::from_bivector(half.im())
+    }
+}
+
+impl<T> Multivector3<T>
+where
+    T: Zero,
+{
+    pub fn from_vector3(v: Vector<T, 3>) -> Self {
+        let (x, y, z) = v.into();
+        let mut res = Self::zero();
+        *res.blade_mut(0b001) = x;
+        *res.blade_mut(0b010) = y;
+        *res.blade_mut(0b100) = z;
+        res
+    }
+    /// Construct pseudoscalar with specified magnitude.
+    pub fn from_trivector(t: T) -> Self {
+        let mut res = Self::zero();
+        *res.blade_mut(0b111) = t;
+        res
+    }
+}
+
+impl<T> Multivector3<T>
+where
+    T: Neg<Output = T> + Zero,
+{
+    /// Construct bivector from components `(e23, e31, e12)`.
+    pub fn from_bivector(b: Vector<T, 3>) -> Self {
+        let (yz, zx, xy) = b.into();
+        let mut res = Self::zero();
+        *res.blade_mut(0b110) = yz;
+        *res.blade_mut(0b101) = -zx;
+        *res.blade_mut(0b011) = xy;
+        res
+    }
+}
+
+impl<T> Multivector3<T>
+where
+    T: Zero + One,
+{
+    /// Unit pseudoscalar `e123`.
+    pub fn pseudoscalar() -> Self {
+        Self::from_trivector(T::one())
+    }
+}
+
+impl<T: Copy> Multivector3<T> {
+    /// Vector (grade 1) part.
+    pub fn vector3(&self) -> Vector<T, 3> {
+        Vector::from([self.blade(0b001), self.blade(0b010), self.blade(0b100)])
+    }
+    /// Trivector (grade 3) part.
+    pub fn trivector(&self) -> T {
+        self.blade(0b111)
+    }
+}
+
+impl<T> Multivector3<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    /// Bivector (grade 2) part as components `(e23, e31, e12)`.
+    ///
+    /// For the wedge product of two vectors it is equal to their cross product.
+    pub fn bivector(&self) -> Vector<T, 3> {
+        Vector::from([self.blade(0b110), -self.blade(0b101), self.blade(0b011)])
+    }
+}
+
+impl<T> Multivector3<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Dual, i.e. `self * I⁻¹`.
+    ///
+    /// Maps bivector `a ∧ b` to vector `a × b`.
+    pub fn dual(self) -> Self {
+        -self.geometric(Self::pseudoscalar())
+    }
+    /// Inverse of the dual, i.e. `self * I`.
+    pub fn undual(self) -> Self {
+        self.geometric(Self::pseudoscalar())
+    }
+
+    /// Convert the rotor to quaternion.
+    ///
+    /// Quaternion units `i`, `j`, `k` correspond to `-e23`, `-e31`, `-e12`.
+    pub fn to_quaternion(self) -> Quaternion<T> {
+        Quaternion::from_scalar_and_vector3(self.scalar_part(), -self.bivector())
+    }
+    /// Rotor corresponding to the unit quaternion.
+    pub fn from_quaternion(quat: Quaternion<T>) -> Self {
+        let (w, v) = quat.into();
+        Self::from_scalar(w) - Self::from_bivector(v)
+    }
+
+    /// Convert the rotor to rotation.
+    ///
+    /// Rotor `R` rotates vectors by `R x reverse(R)`.
+    pub fn to_rotation3(self) -> Rotation3<T> {
+        Rotation3::from_quaternion(self.to_quaternion())
+    }
+    /// Rotor corresponding to the rotation.
+    pub fn from_rotation3(rot: Rotation3<T>) -> Self {
+        Self::from_quaternion(rot.into_quaternion())
+    }
+}
+
+impl<T: Float> Multivector3<T> {
+    /// Rotor rotating vectors around `axis` by `angle`.
+    pub fn rotor(axis: Vector<T, 3>, angle: T) -> Self {
+        Self::from_rotation3(Rotation3::new(axis, angle))
+    }
+}
+
+impl<T> Vector<T, 3>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Wedge product of vectors as bivector.
+    pub fn wedge(self, other: Self) -> Multivector3<T> {
+        Multivector3::from_vector3(self).wedge(Multivector3::from_vector3(other))
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Normal, Unit},
+        traits::Dot,
+        Map, Transform,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-12;
+
+    #[test]
+    fn basis() {
+        type M = Multivector3<i32>;
+        let e = |i: usize| {
+            let mut v = M::zero();
+            *v.blade_mut(i) = 1;
+            v
+        };
+        assert_eq!(e(1) * e(1), M::one());
+        assert_eq!(e(1) * e(2), e(3));
+        assert_eq!(e(2) * e(1), -e(3));
+        assert_eq!(e(1) * e(2) * e(4), M::pseudoscalar());
+        assert_eq!(M::pseudoscalar() * M::pseudoscalar(), -M::one());
+        assert_eq!(e(1).wedge(e(1)), M::zero());
+        assert_eq!(e(1).inner(e(3)), e(2));
+        assert_eq!(e(3).inner(e(1)), M::zero());
+    }
+
+    #[test]
+    fn products() {
+        let mut rng = XorShiftRng::seed_from_u64(0x6A0);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Multivector3<f64> = rng.sample(&Normal);
+            let b: Multivector3<f64> = rng.sample(&Normal);
+            let c: Multivector3<f64> = rng.sample(&Normal);
+            assert_abs_diff_eq!((a * b) * c, a * (b * c), epsilon = EPS);
+            assert_abs_diff_eq!((a * b).reverse(), b.reverse() * a.reverse(), epsilon = EPS);
+            assert_abs_diff_eq!(a.wedge(b).wedge(c), a.wedge(b.wedge(c)), epsilon = EPS);
+
+            let u = Multivector3::<f64>::from_vector3(rng.sample(&Normal));
+            let v = Multivector3::<f64>::from_vector3(rng.sample(&Normal));
+            assert_abs_diff_eq!(u * v, u.inner(v) + u.wedge(v), epsilon = EPS);
+            assert_abs_diff_eq!(
+                u.inner(v).scalar_part(),
+                u.vector3().dot(v.vector3()),
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(u.norm_sqr(), u.vector3().square_length(), epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn cross() {
+        let mut rng = XorShiftRng::seed_from_u64(0x6A1);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Vector<f64, 3> = rng.sample(&Normal);
+            let b: Vector<f64, 3> = rng.sample(&Normal);
+            let w = a.wedge(b);
+            assert_abs_diff_eq!(w.bivector(), a.cross(b), epsilon = EPS);
+            assert_abs_diff_eq!(w.dual().vector3(), a.cross(b), epsilon = EPS);
+            assert_abs_diff_eq!(w.dual().undual(), w, epsilon = EPS);
+            assert_abs_diff_eq!(Multivector3::from_bivector(a.cross(b)), w, epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn rotor2() {
+        let mut rng = XorShiftRng::seed_from_u64(0x6A2);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let r: Rotation2<f64> = rng.sample(&crate::distr::Uniform);
+            let x: Vector<f64, 2> = rng.sample(&Normal);
+            let m = Multivector2::from_rotation2(r);
+            assert_abs_diff_eq!(m.norm(), 1.0, epsilon = EPS);
+            let y = m.sandwich(Multivector2::from_vector2(x));
+            assert_abs_diff_eq!(y.vector2(), r.apply(x), epsilon = EPS);
+            assert_abs_diff_eq!(m.to_rotation2(), r, epsilon = EPS);
+            assert_abs_diff_eq!(
+                Multivector2::rotor(r.angle()).to_rotation2(),
+                r,
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn rotor3() {
+        let mut rng = XorShiftRng::seed_from_u64(0x6A3);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let q: Quaternion<f64> = rng.sample(&Unit);
+            let r = Rotation3::from_quaternion(q);
+            let x: Vector<f64, 3> = rng.sample(&Normal);
+            let m = Multivector3::from_rotation3(r);
+            let y = m.sandwich(Multivector3::from_vector3(x));
+            assert_abs_diff_eq!(y.vector3(), r.apply(x), epsilon = EPS);
+            assert_abs_diff_eq!(m.to_quaternion(), q, epsilon = EPS);
+
+            let s = Rotation3::from_quaternion(rng.sample(&Unit));
+            let n = Multivector3::from_rotation3(s);
+            assert_abs_diff_eq!(
+                (m * n).to_quaternion(),
+                r.chain(s).into_quaternion(),
+                epsilon = EPS
+            );
+        }
+    }
+}
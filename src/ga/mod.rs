@@ -0,0 +1,15 @@
+//! Geometric (Clifford) algebra.
+//!
+//! Multivectors of the Euclidean algebras of the plane and the space, and of the corresponding
+//! projective geometric algebras (PGA) which represent points, lines and planes along with
+//! rigid motions.
+//!
+//! Geometric product is available as `*`, other products are methods.
+
+#[macro_use]
+mod blade;
+mod euclidean;
+mod pga;
+
+pub use euclidean::*;
+pub use pga::*;
@@ -0,0 +1,328 @@
+#[cfg(feature = "rand")]
+use crate::distr::Normal;
+use crate::{
+    ga::blade,
+    traits::Normalize,
+    transform::{Isometry2, Isometry3, Rotation2, Rotation3},
+    Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use num_traits::{Float, Num, One, Zero};
+#[cfg(feature = "rand")]
+use rand_::{distributions::Distribution, Rng};
+
+multivector!(
+    /// Multivector of the two-dimensional projective geometric algebra `R(2, 0, 1)`.
+    ///
+    /// Components are `(1, e0, e1, e01, e2, e02, e12, e012)` where `e0² = 0`.
+    /// Lines are vectors and points are bivectors.
+    PgaMultivector2,
+    8,
+    [0, 1, 1]
+);
+
+multivector!(
+    /// Multivector of the three-dimensional projective geometric algebra `R(3, 0, 1)`.
+    ///
+    /// Components are indexed by blade bitmask over `(e0, e1, e2, e3)` where `e0² = 0`.
+    /// Planes are vectors, lines are bivectors and points are trivectors.
+    PgaMultivector3,
+    16,
+    [0, 1, 1, 1]
+);
+
+macro_rules! pga_common {
+    ($Name:ident) => {
+        impl<T> $Name<T>
+        where
+            T: Neg<Output = T> + Zero + Copy,
+        {
+            /// Poincaré dual (right complement), `x ∧ dual(x)` is a multiple of the pseudoscalar.
+            pub fn dual(self) -> Self {
+                blade::complement(self.into_vector()).into()
+            }
+            /// Inverse of the dual.
+            pub fn undual(self) -> Self {
+                blade::uncomplement(self.into_vector()).into()
+            }
+        }
+
+        impl<T> $Name<T>
+        where
+            T: Neg<Output = T> + Num + Copy,
+        {
+            /// Regressive product, i.e. join of geometric objects.
+            pub fn regressive(self, other: Self) -> Self {
+                self.dual().wedge(other.dual()).undual()
+            }
+            /// Join, the smallest object containing both, e.g. line through two points.
+            pub fn join(self, other: Self) -> Self {
+                self.regressive(other)
+            }
+            /// Meet, the intersection of objects, e.g. point where two lines intersect.
+            pub fn meet(self, other: Self) -> Self {
+                self.wedge(other)
+            }
+        }
+    };
+}
+
+pga_common!(PgaMultivector2);
+pga_common!(PgaMultivector3);
+
+impl<T> PgaMultivector2<T>
+where
+    T: Neg<Output = T> + Zero + One + Copy,
+{
+    /// Point `x e20 + y e01 + e12`.
+    pub fn point(pos: Vector<T, 2>) -> Self {
+        let (x, y) = pos.into();
+        let mut res = Self::zero();
+        *res.blade_mut(0b110) = T::one();
+        *res.blade_mut(0b101) = -x;
+        *res.blade_mut(0b011) = y;
+        res
+    }
+}
+
+impl<T> PgaMultivector2<T>
+where
+    T: Zero,
+{
+    /// Line `a x + b y + c = 0` represented as `a e1 + b e2 + c e0`.
+    pub fn line(a: T, b: T, c: T) -> Self {
+        let mut res = Self::zero();
+        *res.blade_mut(0b010) = a;
+        *res.blade_mut(0b100) = b;
+        *res.blade_mut(0b001) = c;
+        res
+    }
+}
+
+impl<T> PgaMultivector2<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Euclidean position of the point (bivector part), the point must not be ideal.
+    pub fn to_point(&self) -> Vector<T, 2> {
+        let w = self.blade(0b110);
+        Vector::from([-self.blade(0b101), self.blade(0b011)]) / w
+    }
+    /// Motor translating by `shift`.
+    pub fn translator(shift: Vector<T, 2>) -> Self {
+        let (x, y) = shift.into();
+        let half = T::one() / (T::one() + T::one());
+        let mut res = Self::one();
+        *res.blade_mut(0b011) = -x * half;
+        *res.blade_mut(0b101) = -y * half;
+        res
+    }
+    /// Apply the motor to the point.
+    pub fn apply_point(self, pos: Vector<T, 2>) -> Vector<T, 2> {
+        self.sandwich(Self::point(pos)).to_point()
+    }
+}
+
+impl<T: Float> PgaMultivector2<T> {
+    /// Motor rotating around the origin by `angle` counterclockwise.
+    pub fn rotor(angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        let mut res = Self::from_scalar(half.cos());
+        *res.blade_mut(0b110) = -half.sin();
+        res
+    }
+    /// Motor corresponding to the rotation around the origin.
+    pub fn from_rotation2(rot: Rotation2<T>) -> Self {
+        let half = rot.into_complex().sqrt();
+        let mut res = Self::from_scalar(half.re());
+        *res.blade_mut(0b110) = -half.im();
+        res
+    }
+    /// Motor corresponding to the rigid motion.
+    pub fn from_isometry2(iso: Isometry2<T>) -> Self {
+        Self::translator(iso.translation().into_vector()) * Self::from_rotation2(iso.rotation())
+    }
+}
+
+impl<T> PgaMultivector3<T>
+where
+    T: Neg<Output = T> + Zero + One + Copy,
+{
+    /// Point `x e032 + y e013 + z e021 + e123`.
+    pub fn point(pos: Vector<T, 3>) -> Self {
+        let (x, y, z) = pos.into();
+        let mut res = Self::zero();
+        *res.blade_mut(0b1110) = T::one();
+        *res.blade_mut(0b1101) = -x;
+        *res.blade_mut(0b1011) = y;
+        *res.blade_mut(0b0111) = -z;
+        res
+    }
+}
+
+impl<T> PgaMultivector3<T>
+where
+    T: Zero,
+{
+    /// Plane `a x + b y + c z + d = 0` represented as `a e1 + b e2 + c e3 + d e0`.
+    pub fn plane(a: T, b: T, c: T, d: T) -> Self {
+        let mut res = Self::zero();
+        *res.blade_mut(0b0010) = a;
+        *res.blade_mut(0b0100) = b;
+        *res.blade_mut(0b1000) = c;
+        *res.blade_mut(0b0001) = d;
+        res
+    }
+}
+
+impl<T> PgaMultivector3<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Euclidean position of the point (trivector part), the point must not be ideal.
+    pub fn to_point(&self) -> Vector<T, 3> {
+        let w = self.blade(0b1110);
+        Vector::from([-self.blade(0b1101), self.blade(0b1011), -self.blade(0b0111)]) / w
+    }
+    /// Motor translating by `shift`.
+    pub fn translator(shift: Vector<T, 3>) -> Self {
+        let (x, y, z) = shift.into();
+        let half = T::one() / (T::one() + T::one());
+        let mut res = Self::one();
+        *res.blade_mut(0b0011) = -x * half;
+        *res.blade_mut(0b0101) = -y * half;
+        *res.blade_mut(0b1001) = -z * half;
+        res
+    }
+    /// Motor corresponding to the rotation around the origin.
+    pub fn from_rotation3(rot: Rotation3<T>) -> Self {
+        let (w, v) = rot.into_quaternion().into();
+        let (x, y, z) = v.into();
+        let mut res = Self::from_scalar(w);
+        *res.blade_mut(0b1100) = -x;
+        *res.blade_mut(0b1010) = y;
+        *res.blade_mut(0b0110) = -z;
+        res
+    }
+    /// Motor corresponding to the rigid motion.
+    pub fn from_isometry3(iso: Isometry3<T>) -> Self {
+        Self::translator(iso.translation().into_vector()) * Self::from_rotation3(iso.rotation())
+    }
+    /// Apply the motor to the point.
+    pub fn apply_point(self, pos: Vector<T, 3>) -> Vector<T, 3> {
+        self.sandwich(Self::point(pos)).to_point()
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Normal, Uniform, Unit},
+        traits::Dot,
+        transform::Shift,
+        Map, Quaternion,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-10;
+
+    #[test]
+    fn incidence2() {
+        type P = PgaMultivector2<f64>;
+        let mut rng = XorShiftRng::seed_from_u64(0x6A4);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Vector<f64, 2> = rng.sample(&Normal);
+            let b: Vector<f64, 2> = rng.sample(&Normal);
+            let l = P::point(a).join(P::point(b));
+            assert_abs_diff_eq!(l.wedge(P::point(a)).norm(), 0.0, epsilon = EPS);
+            assert_abs_diff_eq!(l.wedge(P::point(b)).norm(), 0.0, epsilon = EPS);
+            assert_abs_diff_eq!(
+                l.wedge(P::point(a * 0.3 + b * 0.7)).norm(),
+                0.0,
+                epsilon = EPS
+            );
+
+            // Intersection with the line `x = c`.
+            let c = 0.5;
+            let p = l.meet(P::line(1.0, 0.0, -c)).to_point();
+            let t = (c - a.x()) / (b.x() - a.x());
+            assert_abs_diff_eq!(p, a + (b - a) * t, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn motor2() {
+        type P = PgaMultivector2<f64>;
+        let mut rng = XorShiftRng::seed_from_u64(0x6A5);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let iso = Isometry2::new(
+                Shift::from_vector(rng.sample(&Normal)),
+                rng.sample::<Rotation2<f64>, _>(&Uniform),
+            );
+            let x: Vector<f64, 2> = rng.sample(&Normal);
+            let m = P::from_isometry2(iso);
+            assert_abs_diff_eq!(m.apply_point(x), iso.apply(x), epsilon = EPS);
+            assert_abs_diff_eq!(
+                P::rotor(iso.rotation().angle()),
+                P::from_rotation2(iso.rotation()),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn incidence3() {
+        type P = PgaMultivector3<f64>;
+        let mut rng = XorShiftRng::seed_from_u64(0x6A6);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Vector<f64, 3> = rng.sample(&Normal);
+            let b: Vector<f64, 3> = rng.sample(&Normal);
+            let c: Vector<f64, 3> = rng.sample(&Normal);
+            let plane = P::point(a).join(P::point(b)).join(P::point(c));
+            let n = Vector::from([
+                plane.blade(0b0010),
+                plane.blade(0b0100),
+                plane.blade(0b1000),
+            ]);
+            let d = plane.blade(0b0001);
+            assert_abs_diff_eq!(
+                n.normalize(),
+                (b - a).cross(c - a).normalize(),
+                epsilon = 1e-6
+            );
+            for p in [a, b, c].iter() {
+                assert_abs_diff_eq!(n.dot(*p) + d, 0.0, epsilon = EPS);
+            }
+
+            let x = P::plane(1.0, 0.0, 0.0, -a.x())
+                .meet(P::plane(0.0, 1.0, 0.0, -a.y()))
+                .meet(P::plane(0.0, 0.0, 1.0, -a.z()));
+            assert_abs_diff_eq!(x.to_point(), a, epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn motor3() {
+        type P = PgaMultivector3<f64>;
+        let mut rng = XorShiftRng::seed_from_u64(0x6A7);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let iso = Isometry3::new(
+                Shift::from_vector(rng.sample(&Normal)),
+                Rotation3::from_quaternion(rng.sample::<Quaternion<f64>, _>(&Unit)),
+            );
+            let x: Vector<f64, 3> = rng.sample(&Normal);
+            let m = P::from_isometry3(iso);
+            assert_abs_diff_eq!(m.apply_point(x), iso.apply(x), epsilon = EPS);
+            assert_abs_diff_eq!(m.norm(), 1.0, epsilon = EPS);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{traits::Dot, transform::Jacobian, Matrix, Vector};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::Float;
+
+/// Axis-aligned bounding box.
+///
+/// Box is considered to be empty if `min > max` along any axis.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Aabb<T, const N: usize> {
+    min: Vector<T, N>,
+    max: Vector<T, N>,
+}
+
+pub type Aabb2<T> = Aabb<T, 2>;
+pub type Aabb3<T> = Aabb<T, 3>;
+
+impl<T, const N: usize> Aabb<T, N> {
+    /// Construct box from its minimal and maximal corners.
+    pub const fn new(min: Vector<T, N>, max: Vector<T, N>) -> Self {
+        Self { min, max }
+    }
+    pub fn into_min_max(self) -> (Vector<T, N>, Vector<T, N>) {
+        (self.min, self.max)
+    }
+}
+
+impl<T: Copy, const N: usize> Aabb<T, N> {
+    /// Minimal corner.
+    pub fn min(&self) -> Vector<T, N> {
+        self.min
+    }
+    /// Maximal corner.
+    pub fn max(&self) -> Vector<T, N> {
+        self.max
+    }
+    /// Degenerate box containing the only point.
+    pub fn from_point(point: Vector<T, N>) -> Self {
+        Self::new(point, point)
+    }
+}
+
+impl<T: Float, const N: usize> Aabb<T, N> {
+    /// Empty box, identity element for `union`.
+    pub fn empty() -> Self {
+        Self::new(Vector::fill(T::infinity()), Vector::fill(T::neg_infinity()))
+    }
+    /// Smallest box containing both points.
+    pub fn from_corners(a: Vector<T, N>, b: Vector<T, N>) -> Self {
+        Self::new(a.vmin(b), a.vmax(b))
+    }
+    /// Construct box from its center and half extent.
+    pub fn from_center(center: Vector<T, N>, half_extent: Vector<T, N>) -> Self {
+        Self::new(center - half_extent, center + half_extent)
+    }
+    /// Smallest box containing all points, empty if there are no points.
+    pub fn bounding<I: IntoIterator<Item = Vector<T, N>>>(points: I) -> Self {
+        points.into_iter().fold(Self::empty(), |b, p| b.expand(p))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.max.vlt(self.min).any()
+    }
+    /// Center of the box.
+    pub fn center(&self) -> Vector<T, N> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+    /// Size of the box along each axis.
+    pub fn extent(&self) -> Vector<T, N> {
+        self.max - self.min
+    }
+    /// Half of the size of the box along each axis.
+    pub fn half_extent(&self) -> Vector<T, N> {
+        self.extent() / (T::one() + T::one())
+    }
+    /// Product of extents (area in 2D, volume in 3D), zero for empty box.
+    pub fn volume(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.extent().fold(T::one(), |a, x| a * x)
+        }
+    }
+
+    /// Smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.min.vmin(other.min), self.max.vmax(other.max))
+    }
+    /// Intersection of boxes, `None` if they don't intersect.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let res = Self::new(self.min.vmax(other.min), self.max.vmin(other.max));
+        if res.is_empty() {
+            None
+        } else {
+            Some(res)
+        }
+    }
+    /// Check whether boxes intersect (touching counts as intersection).
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+    /// Check whether the point is inside the box or on its boundary.
+    pub fn contains(&self, point: Vector<T, N>) -> bool {
+        self.min.vle(point).all() && point.vle(self.max).all()
+    }
+    /// Check whether other box is completely inside this one.
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        other.is_empty() || (self.contains(other.min) && self.contains(other.max))
+    }
+
+    /// Smallest box containing this box and the point.
+    pub fn expand(&self, point: Vector<T, N>) -> Self {
+        Self::new(self.min.vmin(point), self.max.vmax(point))
+    }
+    /// Box enlarged by `margin` in each direction.
+    pub fn grow(&self, margin: T) -> Self {
+        let m = Vector::fill(margin);
+        Self::new(self.min - m, self.max + m)
+    }
+
+    /// Point of the box closest to the specified one.
+    pub fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+        point.vmax(self.min).vmin(self.max)
+    }
+    /// Squared distance from the point to the box, zero if the point is inside.
+    pub fn square_distance(&self, point: Vector<T, N>) -> T {
+        (point - self.closest_point(point)).square_length()
+    }
+    /// Distance from the point to the box, zero if the point is inside.
+    pub fn distance(&self, point: Vector<T, N>) -> T {
+        self.square_distance(point).sqrt()
+    }
+
+    /// Bounding box of the transformed box.
+    ///
+    /// The result is tight for affine transformations (`Shift`, `Linear`, `Affine`, rotations, etc.).
+    /// For other transformations it is a linear approximation at the center of the box.
+    pub fn transform<M>(&self, map: &M) -> Self
+    where
+        M: Jacobian<Vector<T, N>, Output = Matrix<T, N, N>>,
+    {
+        if self.is_empty() {
+            return *self;
+        }
+        let center = self.center();
+        let half = map.jacobian(center).abs().dot(self.half_extent());
+        Self::from_center(map.apply(center), half)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T, const N: usize> AbsDiffEq for Aabb<T, N>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.min, other.min, epsilon = epsilon)
+            && abs_diff_eq!(self.max, other.max, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Invertible, Normal, Unit},
+        transform::{Affine3, Linear3, Rotation3, Shift3},
+        Quaternion,
+    };
+    use approx::assert_abs_diff_eq;
+    use num_traits::Zero;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-10;
+
+    type V = Vector<f64, 3>;
+
+    fn sample_box(rng: &mut XorShiftRng) -> Aabb3<f64> {
+        Aabb::from_corners(rng.sample(&Normal), rng.sample(&Normal))
+    }
+
+    fn corners(b: &Aabb3<f64>) -> [V; 8] {
+        let mut res = [V::zero(); 8];
+        for (k, c) in res.iter_mut().enumerate() {
+            *c = Vector::indices().map(|i| {
+                if (k >> i) & 1 == 0 {
+                    b.min()[i]
+                } else {
+                    b.max()[i]
+                }
+            });
+        }
+        res
+    }
+
+    #[test]
+    fn queries() {
+        let a = Aabb::new(Vector::from([0.0, 0.0]), Vector::from([2.0, 1.0]));
+        let b = Aabb::new(Vector::from([1.0, -1.0]), Vector::from([3.0, 0.5]));
+        assert_abs_diff_eq!(a.center(), Vector::from([1.0, 0.5]));
+        assert_abs_diff_eq!(a.extent(), Vector::from([2.0, 1.0]));
+        assert_abs_diff_eq!(a.volume(), 2.0);
+        assert_abs_diff_eq!(
+            a.union(&b),
+            Aabb::new(Vector::from([0.0, -1.0]), Vector::from([3.0, 1.0]))
+        );
+        assert_abs_diff_eq!(
+            a.intersection(&b).unwrap(),
+            Aabb::new(Vector::from([1.0, 0.0]), Vector::from([2.0, 0.5]))
+        );
+        assert!(a.intersection(&a.grow(-0.6)).is_none());
+        assert!(a.contains(Vector::from([2.0, 0.0])));
+        assert!(!a.contains(Vector::from([2.1, 0.0])));
+        assert!(a.grow(0.1).contains_aabb(&a));
+        assert_abs_diff_eq!(a.distance(Vector::from([5.0, 5.0])), 5.0);
+        assert_abs_diff_eq!(a.distance(Vector::from([1.0, 0.2])), 0.0);
+        assert!(Aabb2::<f64>::empty().is_empty());
+        assert_abs_diff_eq!(Aabb2::empty().union(&a), a);
+        assert_abs_diff_eq!(
+            Aabb::bounding(
+                [Vector::from([1.0, 5.0]), Vector::from([-1.0, 2.0])]
+                    .iter()
+                    .cloned()
+            ),
+            Aabb::new(Vector::from([-1.0, 2.0]), Vector::from([1.0, 5.0]))
+        );
+    }
+
+    #[test]
+    fn closest_point() {
+        let mut rng = XorShiftRng::seed_from_u64(0xAABB);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let b = sample_box(&mut rng);
+            let p: V = rng.sample::<V, _>(&Normal) * 2.0;
+            let c = b.closest_point(p);
+            assert!(b.contains(c));
+            for q in corners(&b).iter() {
+                assert!(b.distance(p) <= (p - *q).length() + EPS);
+            }
+        }
+    }
+
+    fn check_transform<M>(rng: &mut XorShiftRng, map: M)
+    where
+        M: Jacobian<V, Output = Matrix<f64, 3, 3>>,
+    {
+        let b = sample_box(rng);
+        let t = Aabb::bounding(corners(&b).iter().map(|c| map.apply(*c)));
+        assert_abs_diff_eq!(b.transform(&map), t, epsilon = EPS);
+    }
+
+    #[test]
+    fn transform() {
+        let mut rng = XorShiftRng::seed_from_u64(0xAABC);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let shift = Shift3::<f64>::from_vector(rng.sample(&Normal));
+            check_transform(&mut rng, shift);
+            let lin: Linear3<f64> = rng.sample(&Invertible);
+            check_transform(&mut rng, lin);
+            let aff: Affine3<f64> = rng.sample(&Invertible);
+            check_transform(&mut rng, aff);
+            let rot = Rotation3::from_quaternion(rng.sample::<Quaternion<f64>, _>(&Unit));
+            check_transform(&mut rng, rot);
+        }
+    }
+}
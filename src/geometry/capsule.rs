@@ -0,0 +1,176 @@
+use crate::{
+    geometry::{sphere::max_stretch, Aabb3, Segment, Sphere3},
+    matrix::Matrix3x3,
+    transform::Jacobian,
+    Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::Float;
+
+/// Set of points lying within the radius from the segment.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Capsule<T> {
+    segment: Segment<T, 3>,
+    radius: T,
+}
+
+impl<T> Capsule<T> {
+    pub const fn new(a: Vector<T, 3>, b: Vector<T, 3>, radius: T) -> Self {
+        Self::from_segment(Segment::new(a, b), radius)
+    }
+    pub const fn from_segment(segment: Segment<T, 3>, radius: T) -> Self {
+        Self { segment, radius }
+    }
+    pub fn into_segment_radius(self) -> (Segment<T, 3>, T) {
+        (self.segment, self.radius)
+    }
+}
+
+impl<T: Copy> Capsule<T> {
+    /// Axis of the capsule.
+    pub fn segment(&self) -> Segment<T, 3> {
+        self.segment
+    }
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+}
+
+impl<T: Float> Capsule<T> {
+    /// Check whether the point is inside the capsule or on its boundary.
+    pub fn contains(&self, point: Vector<T, 3>) -> bool {
+        self.segment.distance(point) <= self.radius
+    }
+    /// Distance from the point to the capsule, zero if the point is inside.
+    pub fn distance(&self, point: Vector<T, 3>) -> T {
+        (self.segment.distance(point) - self.radius).max(T::zero())
+    }
+    /// Check whether capsules intersect (touching counts as intersection).
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.segment.segment_distance(&other.segment) <= self.radius + other.radius
+    }
+    /// Check whether the capsule intersects the sphere.
+    pub fn intersects_sphere(&self, sphere: &Sphere3<T>) -> bool {
+        !sphere.is_empty()
+            && self.segment.distance(sphere.center()) <= self.radius + sphere.radius()
+    }
+
+    /// Bounding box of the capsule.
+    pub fn aabb(&self) -> Aabb3<T> {
+        Aabb3::from_corners(self.segment.a(), self.segment.b()).grow(self.radius)
+    }
+
+    /// Capsule containing the transformed capsule.
+    ///
+    /// Endpoints are mapped and the radius is scaled by an upper bound of the stretch factor
+    /// of the Jacobian at the middle of the axis, so the result contains the image for affine
+    /// transformations and is exact for similarities.
+    /// For other transformations it is a linear approximation at the middle of the axis.
+    pub fn transform<M>(&self, map: &M) -> Self
+    where
+        M: Jacobian<Vector<T, 3>, Output = Matrix3x3<T>>,
+    {
+        let half = T::one() / (T::one() + T::one());
+        let scale = max_stretch(map.jacobian(self.segment.at(half)));
+        Self::from_segment(self.segment.transform(map), self.radius * scale)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Capsule<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.segment, other.segment, epsilon = epsilon)
+            && abs_diff_eq!(self.radius, other.radius, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Invertible, Normal, Unit},
+        geometry::Sphere,
+        matrix::Matrix,
+        transform::{Affine3, Chain, Linear3, Map, Rotation3, Scale, Shift3},
+        Quaternion,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    type V = Vector<f64, 3>;
+
+    fn v(x: f64, y: f64, z: f64) -> V {
+        Vector::from([x, y, z])
+    }
+
+    #[test]
+    fn queries() {
+        let c = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 2.0), 0.5);
+        assert!(c.contains(v(0.5, 0.0, 1.0)));
+        assert!(c.contains(v(0.0, 0.0, 2.5)));
+        assert!(!c.contains(v(0.4, 0.0, 2.4)));
+        assert_abs_diff_eq!(c.distance(v(2.0, 0.0, 1.0)), 1.5);
+        assert!(c.intersects(&Capsule::new(v(1.0, -1.0, 1.0), v(1.0, 1.0, 1.0), 0.5)));
+        assert!(!c.intersects(&Capsule::new(v(1.0, -1.0, 1.0), v(1.0, 1.0, 1.0), 0.4)));
+        assert!(c.intersects_sphere(&Sphere::new(v(0.0, 0.0, 3.0), 0.5)));
+        assert!(!c.intersects_sphere(&Sphere::new(v(0.0, 0.0, 3.0), 0.4)));
+        assert_abs_diff_eq!(c.aabb(), Aabb3::new(v(-0.5, -0.5, -0.5), v(0.5, 0.5, 2.5)));
+    }
+
+    #[test]
+    fn transform() {
+        let mut rng = XorShiftRng::seed_from_u64(0xCA9);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let c = Capsule::new(rng.sample(&Normal), rng.sample(&Normal), 0.5);
+            let sim = Chain::new(
+                Shift3::from_vector(rng.sample(&Normal)),
+                Chain::new(
+                    Rotation3::from_quaternion(rng.sample::<Quaternion<f64>, _>(&Unit)),
+                    Scale::from_mag(2.0),
+                ),
+            );
+            let t = c.transform(&sim);
+            assert_abs_diff_eq!(t.radius(), 1.0, epsilon = EPS);
+            let d: V = rng.sample(&Unit);
+            let p = c.segment().at(0.3) + d.cross(c.segment().dir()).normalize() * 0.5;
+            assert_abs_diff_eq!(
+                t.segment().distance(sim.apply(p)),
+                t.radius(),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn transform_non_uniform() {
+        let c = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 2.0), 1.0);
+        let lin = Linear3::from(Matrix::diagonal(v(4.0, 1.0, 1.0)));
+        assert_abs_diff_eq!(
+            c.transform(&lin),
+            Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 2.0), 4.0),
+            epsilon = EPS
+        );
+
+        let mut rng = XorShiftRng::seed_from_u64(0xCAA);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let c = Capsule::new(rng.sample(&Normal), rng.sample(&Normal), 0.5);
+            let aff: Affine3<f64> = rng.sample(&Invertible);
+            let t = c.transform(&aff);
+            let d: V = rng.sample(&Unit);
+            let p = c.segment().at(rng.gen()) + d * 0.5;
+            assert!(t.segment().distance(aff.apply(p)) <= t.radius() + EPS);
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use crate::{
+    geometry::Aabb,
+    traits::Dot,
+    transform::{Directional, Map},
+    Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::Float;
+
+/// Half-line `origin + t * dir` where `t >= 0`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ray<T, const N: usize> {
+    origin: Vector<T, N>,
+    dir: Vector<T, N>,
+}
+
+/// Infinite line `point + t * dir`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Line<T, const N: usize> {
+    point: Vector<T, N>,
+    dir: Vector<T, N>,
+}
+
+/// Line segment `a + t * (b - a)` where `0 <= t <= 1`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Segment<T, const N: usize> {
+    a: Vector<T, N>,
+    b: Vector<T, N>,
+}
+
+impl<T, const N: usize> Ray<T, N> {
+    pub const fn new(origin: Vector<T, N>, dir: Vector<T, N>) -> Self {
+        Self { origin, dir }
+    }
+}
+impl<T: Copy, const N: usize> Ray<T, N> {
+    pub fn origin(&self) -> Vector<T, N> {
+        self.origin
+    }
+    pub fn dir(&self) -> Vector<T, N> {
+        self.dir
+    }
+}
+
+impl<T, const N: usize> Line<T, N> {
+    pub const fn new(point: Vector<T, N>, dir: Vector<T, N>) -> Self {
+        Self { point, dir }
+    }
+}
+impl<T: Copy, const N: usize> Line<T, N> {
+    pub fn point(&self) -> Vector<T, N> {
+        self.point
+    }
+    pub fn dir(&self) -> Vector<T, N> {
+        self.dir
+    }
+}
+
+impl<T, const N: usize> Segment<T, N> {
+    pub const fn new(a: Vector<T, N>, b: Vector<T, N>) -> Self {
+        Self { a, b }
+    }
+}
+impl<T: Copy, const N: usize> Segment<T, N> {
+    /// Start point.
+    pub fn a(&self) -> Vector<T, N> {
+        self.a
+    }
+    /// End point.
+    pub fn b(&self) -> Vector<T, N> {
+        self.b
+    }
+}
+
+impl<T: Float, const N: usize> Line<T, N> {
+    /// Line passing through two points.
+    pub fn from_points(a: Vector<T, N>, b: Vector<T, N>) -> Self {
+        Self::new(a, b - a)
+    }
+
+    /// Parameters `(s, t)` of the closest points `self.at(s)` and `other.at(t)` of two lines.
+    ///
+    /// Returns `None` if lines are parallel.
+    pub fn closest_params(&self, other: &Self) -> Option<(T, T)> {
+        let r = self.point - other.point;
+        let (a, b, e) = (
+            self.dir.square_length(),
+            self.dir.dot(other.dir),
+            other.dir.square_length(),
+        );
+        let (c, f) = (self.dir.dot(r), other.dir.dot(r));
+        let den = a * e - b * b;
+        if den <= T::epsilon() * a * e {
+            None
+        } else {
+            Some(((b * f - c * e) / den, (a * f - b * c) / den))
+        }
+    }
+    /// Closest points of two lines.
+    ///
+    /// For parallel lines the point of `self` is chosen arbitrarily.
+    pub fn closest_points(&self, other: &Self) -> (Vector<T, N>, Vector<T, N>) {
+        match self.closest_params(other) {
+            Some((s, t)) => (self.at(s), other.at(t)),
+            None => (self.point, other.closest_point(self.point)),
+        }
+    }
+    /// Distance between two lines.
+    pub fn line_distance(&self, other: &Self) -> T {
+        let (p, q) = self.closest_points(other);
+        (p - q).length()
+    }
+}
+
+impl<T: Float, const N: usize> Segment<T, N> {
+    /// Direction vector `b - a`, its length is equal to the length of the segment.
+    pub fn dir(&self) -> Vector<T, N> {
+        self.b - self.a
+    }
+    pub fn length(&self) -> T {
+        self.dir().length()
+    }
+    /// Line containing the segment, parameters of the line and the segment coincide.
+    pub fn to_line(&self) -> Line<T, N> {
+        Line::new(self.a, self.dir())
+    }
+
+    /// Parameters `(s, t)` of the closest points `self.at(s)` and `other.at(t)` of two segments.
+    pub fn closest_params(&self, other: &Self) -> (T, T) {
+        let (zero, one) = (T::zero(), T::one());
+        let clamp = |x: T| x.max(zero).min(one);
+        let (d1, d2) = (self.dir(), other.dir());
+        let r = self.a - other.a;
+        let (a, e, f) = (d1.square_length(), d2.square_length(), d2.dot(r));
+        if a <= T::epsilon() && e <= T::epsilon() {
+            return (zero, zero);
+        }
+        if a <= T::epsilon() {
+            return (zero, clamp(f / e));
+        }
+        let c = d1.dot(r);
+        if e <= T::epsilon() {
+            return (clamp(-c / a), zero);
+        }
+        let b = d1.dot(d2);
+        let den = a * e - b * b;
+        let mut s = if den > zero {
+            clamp((b * f - c * e) / den)
+        } else {
+            zero
+        };
+        let mut t = (b * s + f) / e;
+        if t < zero {
+            t = zero;
+            s = clamp(-c / a);
+        } else if t > one {
+            t = one;
+            s = clamp((b - c) / a);
+        }
+        (s, t)
+    }
+    /// Closest points of two segments.
+    pub fn closest_points(&self, other: &Self) -> (Vector<T, N>, Vector<T, N>) {
+        let (s, t) = self.closest_params(other);
+        (self.at(s), other.at(t))
+    }
+    /// Distance between two segments.
+    pub fn segment_distance(&self, other: &Self) -> T {
+        let (p, q) = self.closest_points(other);
+        (p - q).length()
+    }
+
+    /// Segment with transformed endpoints, exact for affine transformations.
+    pub fn transform<M: Map<Vector<T, N>>>(&self, map: &M) -> Self {
+        Self::new(map.apply(self.a), map.apply(self.b))
+    }
+}
+
+impl<T: Float, const N: usize> Ray<T, N> {
+    /// Ray starting at the point and passing through the other one.
+    pub fn from_points(origin: Vector<T, N>, target: Vector<T, N>) -> Self {
+        Self::new(origin, target - origin)
+    }
+    /// Line containing the ray, parameters of the line and the ray coincide.
+    pub fn to_line(&self) -> Line<T, N> {
+        Line::new(self.origin, self.dir)
+    }
+    /// Transform the ray using `apply` for its origin and `apply_dir` for its direction.
+    ///
+    /// Parameters are not preserved in general because `apply_dir` may normalize the direction.
+    pub fn transform<M: Directional<Vector<T, N>>>(&self, map: &M) -> Self {
+        Self::new(map.apply(self.origin), map.apply_dir(self.origin, self.dir))
+    }
+}
+
+impl<T: Float, const N: usize> Line<T, N> {
+    /// Transform the line using `apply` for its point and `apply_dir` for its direction.
+    ///
+    /// Parameters are not preserved in general because `apply_dir` may normalize the direction.
+    pub fn transform<M: Directional<Vector<T, N>>>(&self, map: &M) -> Self {
+        Self::new(map.apply(self.point), map.apply_dir(self.point, self.dir))
+    }
+}
+
+/// Intersection queries for the linear objects described by the origin, direction and parameter range.
+macro_rules! linear_queries {
+    ($Name:ident, $origin:ident, $dir:expr, $tmin:expr, $tmax:expr) => {
+        impl<T: Float, const N: usize> $Name<T, N> {
+            fn origin_dir(&self) -> (Vector<T, N>, Vector<T, N>) {
+                (self.$origin, $dir(self))
+            }
+            fn clip(&self, (t0, t1): (T, T)) -> Option<(T, T)> {
+                let (t0, t1) = (t0.max($tmin), t1.min($tmax));
+                if t0 <= t1 {
+                    Some((t0, t1))
+                } else {
+                    None
+                }
+            }
+            fn check(&self, t: T) -> Option<T> {
+                if t >= $tmin && t <= $tmax {
+                    Some(t)
+                } else {
+                    None
+                }
+            }
+
+            /// Point at the specified parameter.
+            pub fn at(&self, t: T) -> Vector<T, N> {
+                let (o, d) = self.origin_dir();
+                o + d * t
+            }
+            /// Parameter of the point closest to the specified one.
+            pub fn closest_param(&self, point: Vector<T, N>) -> T {
+                let (o, d) = self.origin_dir();
+                let t = (point - o).dot(d) / d.square_length();
+                t.max($tmin).min($tmax)
+            }
+            /// Point closest to the specified one.
+            pub fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+                self.at(self.closest_param(point))
+            }
+            /// Distance to the point.
+            pub fn distance(&self, point: Vector<T, N>) -> T {
+                (point - self.closest_point(point)).length()
+            }
+
+            /// Parameter of the intersection with the hyperplane `normal · x = offset`.
+            ///
+            /// Returns `None` if there is no intersection or the object is parallel to the plane.
+            pub fn intersect_plane(&self, normal: Vector<T, N>, offset: T) -> Option<T> {
+                let (o, d) = self.origin_dir();
+                let den = normal.dot(d);
+                if den == T::zero() {
+                    None
+                } else {
+                    self.check((offset - normal.dot(o)) / den)
+                }
+            }
+            /// Range of parameters of the points lying inside the ball.
+            pub fn intersect_sphere(&self, center: Vector<T, N>, radius: T) -> Option<(T, T)> {
+                let (o, d) = self.origin_dir();
+                let oc = o - center;
+                let (a, b, c) = (
+                    d.square_length(),
+                    oc.dot(d),
+                    oc.square_length() - radius * radius,
+                );
+                let disc = b * b - a * c;
+                if disc < T::zero() || a == T::zero() {
+                    return None;
+                }
+                // Numerically stable form of the quadratic formula.
+                let q = -(b + disc.sqrt().copysign(b));
+                let (t0, t1) = if q == T::zero() {
+                    (T::zero(), T::zero())
+                } else {
+                    (q / a, c / q)
+                };
+                self.clip((t0.min(t1), t0.max(t1)))
+            }
+            /// Range of parameters of the points lying inside the box (slab method).
+            pub fn intersect_aabb(&self, aabb: &Aabb<T, N>) -> Option<(T, T)> {
+                let (o, d) = self.origin_dir();
+                let (mut t0, mut t1) = (T::neg_infinity(), T::infinity());
+                for i in 0..N {
+                    let (lo, hi) = (aabb.min()[i], aabb.max()[i]);
+                    if d[i] == T::zero() {
+                        if o[i] < lo || o[i] > hi {
+                            return None;
+                        }
+                    } else {
+                        let (a, b) = ((lo - o[i]) / d[i], (hi - o[i]) / d[i]);
+                        t0 = t0.max(a.min(b));
+                        t1 = t1.min(a.max(b));
+                    }
+                }
+                self.clip((t0, t1))
+            }
+        }
+
+        impl<T: Float> $Name<T, 3> {
+            /// Parameter and barycentric coordinates `(t, u, v)` of the intersection with the triangle `abc`.
+            ///
+            /// The intersection point is `a + u * (b - a) + v * (c - a)`.
+            /// Möller–Trumbore algorithm is used, both sides of the triangle are hit.
+            pub fn intersect_triangle(
+                &self,
+                a: Vector<T, 3>,
+                b: Vector<T, 3>,
+                c: Vector<T, 3>,
+            ) -> Option<(T, T, T)> {
+                let (o, d) = self.origin_dir();
+                let (e1, e2) = (b - a, c - a);
+                let p = d.cross(e2);
+                let det = e1.dot(p);
+                if det.abs() <= T::epsilon() * e1.length() * e2.length() * d.length() {
+                    return None;
+                }
+                let s = o - a;
+                let u = s.dot(p) / det;
+                if u < T::zero() || u > T::one() {
+                    return None;
+                }
+                let q = s.cross(e1);
+                let v = d.dot(q) / det;
+                if v < T::zero() || u + v > T::one() {
+                    return None;
+                }
+                self.check(e2.dot(q) / det).map(|t| (t, u, v))
+            }
+        }
+    };
+}
+
+linear_queries!(Ray, origin, |r: &Self| r.dir, T::zero(), T::infinity());
+linear_queries!(
+    Line,
+    point,
+    |l: &Self| l.dir,
+    T::neg_infinity(),
+    T::infinity()
+);
+linear_queries!(Segment, a, |s: &Self| s.dir(), T::zero(), T::one());
+
+macro_rules! linear_approx {
+    ($Name:ident, $a:ident, $b:ident) => {
+        #[cfg(feature = "approx")]
+        impl<T, const N: usize> AbsDiffEq for $Name<T, N>
+        where
+            T: AbsDiffEq<Epsilon = T> + Copy,
+        {
+            type Epsilon = T;
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                abs_diff_eq!(self.$a, other.$a, epsilon = epsilon)
+                    && abs_diff_eq!(self.$b, other.$b, epsilon = epsilon)
+            }
+        }
+    };
+}
+
+linear_approx!(Ray, origin, dir);
+linear_approx!(Line, point, dir);
+linear_approx!(Segment, a, b);
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Invertible, Normal, Unit},
+        transform::{Affine3, Rotation3},
+        Quaternion,
+    };
+    use approx::assert_abs_diff_eq;
+    use num_traits::Zero;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    type V = Vector<f64, 3>;
+
+    fn v(x: f64, y: f64, z: f64) -> V {
+        Vector::from([x, y, z])
+    }
+    fn pair<P: Into<Option<(f64, f64)>>>(p: P) -> Vector<f64, 2> {
+        let (a, b) = p.into().unwrap();
+        Vector::from([a, b])
+    }
+
+    #[test]
+    fn primitives() {
+        let r = Ray::new(v(0.0, 0.0, -5.0), v(0.0, 0.0, 1.0));
+        assert_abs_diff_eq!(r.intersect_plane(v(0.0, 0.0, 1.0), 1.0).unwrap(), 6.0);
+        assert!(r.intersect_plane(v(0.0, 0.0, 1.0), -6.0).is_none());
+        assert!(r.intersect_plane(v(1.0, 0.0, 0.0), 1.0).is_none());
+
+        assert_abs_diff_eq!(pair(r.intersect_sphere(V::zero(), 2.0)), pair((3.0, 7.0)));
+        assert!(r.intersect_sphere(v(3.0, 0.0, 0.0), 2.0).is_none());
+        let inside = Ray::new(V::zero(), v(0.0, 0.0, 1.0));
+        assert_abs_diff_eq!(
+            pair(inside.intersect_sphere(V::zero(), 2.0)),
+            pair((0.0, 2.0))
+        );
+        let l = inside.to_line();
+        assert_abs_diff_eq!(pair(l.intersect_sphere(V::zero(), 2.0)), pair((-2.0, 2.0)));
+
+        let b = Aabb::new(v(-1.0, -1.0, 0.0), v(1.0, 1.0, 2.0));
+        assert_abs_diff_eq!(pair(r.intersect_aabb(&b)), pair((5.0, 7.0)));
+        assert!(Ray::new(v(2.0, 0.0, 0.0), v(0.0, 0.0, 1.0))
+            .intersect_aabb(&b)
+            .is_none());
+        let s = Segment::new(v(0.0, 0.0, -5.0), v(0.0, 0.0, -1.0));
+        assert!(s.intersect_aabb(&b).is_none());
+
+        let (a, bb, c) = (v(-1.0, -1.0, 1.0), v(1.0, -1.0, 1.0), v(0.0, 1.0, 1.0));
+        let (t, u, w) = r.intersect_triangle(a, bb, c).unwrap();
+        assert_abs_diff_eq!(t, 6.0);
+        assert_abs_diff_eq!(a + (bb - a) * u + (c - a) * w, r.at(t), epsilon = EPS);
+        assert!(Ray::new(v(2.0, 0.0, -5.0), v(0.0, 0.0, 1.0))
+            .intersect_triangle(a, bb, c)
+            .is_none());
+        assert!(Ray::new(v(0.0, 0.0, 5.0), v(0.0, 0.0, 1.0))
+            .intersect_triangle(a, bb, c)
+            .is_none());
+    }
+
+    #[test]
+    fn closest() {
+        let mut rng = XorShiftRng::seed_from_u64(0x11E);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let l = Line::<f64, 3>::new(rng.sample(&Normal), rng.sample(&Normal));
+            let m = Line::<f64, 3>::new(rng.sample(&Normal), rng.sample(&Normal));
+            let (p, q) = l.closest_points(&m);
+            // Connecting vector is orthogonal to both lines.
+            assert_abs_diff_eq!((p - q).dot(l.dir()), 0.0, epsilon = EPS);
+            assert_abs_diff_eq!((p - q).dot(m.dir()), 0.0, epsilon = EPS);
+
+            let x: V = rng.sample(&Normal);
+            assert_abs_diff_eq!((x - l.closest_point(x)).dot(l.dir()), 0.0, epsilon = EPS);
+
+            let s = Segment::<f64, 3>::new(rng.sample(&Normal), rng.sample(&Normal));
+            let r = Segment::<f64, 3>::new(rng.sample(&Normal), rng.sample(&Normal));
+            let d = s.segment_distance(&r);
+            for i in 0..=16 {
+                for j in 0..=16 {
+                    let (a, b) = (i as f64 / 16.0, j as f64 / 16.0);
+                    assert!(d <= (s.at(a) - r.at(b)).length() + EPS);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let mut rng = XorShiftRng::seed_from_u64(0x11F);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let r = Ray::<f64, 3>::new(rng.sample(&Normal), rng.sample(&Normal));
+            let rot = Rotation3::from_quaternion(rng.sample::<Quaternion<f64>, _>(&Unit));
+            let aff: Affine3<f64> = rng.sample(&Invertible);
+            for t in [0.0, 0.5, 2.0].iter() {
+                let p = rot.apply(r.at(*t));
+                let tr = r.transform(&rot);
+                assert_abs_diff_eq!(tr.distance(p), 0.0, epsilon = EPS);
+                let p = aff.apply(r.at(*t));
+                let tr = r.transform(&aff);
+                assert_abs_diff_eq!(tr.distance(p), 0.0, epsilon = 1e-6);
+            }
+            // Rotation preserves parameters.
+            assert_abs_diff_eq!(
+                r.transform(&rot).at(2.0),
+                rot.apply(r.at(2.0)),
+                epsilon = EPS
+            );
+            let s = Segment::<f64, 3>::new(rng.sample(&Normal), rng.sample(&Normal));
+            assert_abs_diff_eq!(
+                s.transform(&aff).at(0.3),
+                aff.apply(s.at(0.3)),
+                epsilon = EPS
+            );
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Geometric primitives and queries.
+
+mod aabb;
+mod capsule;
+mod line;
+mod obb;
+mod plane;
+pub mod polygon;
+pub mod predicates;
+mod sphere;
+
+pub use aabb::*;
+pub use capsule::*;
+pub use line::*;
+pub use obb::*;
+pub use plane::*;
+pub use sphere::*;
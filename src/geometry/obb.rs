@@ -0,0 +1,346 @@
+use crate::{
+    fit::eigen::symmetric_eigen,
+    geometry::{Aabb3, Sphere3},
+    traits::Dot,
+    transform::{Isometry3, Linear, Map, Rotation3, Transform},
+    Matrix, Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::Float;
+
+/// Oriented bounding box.
+///
+/// Box is obtained by rotating the box `[-half_extent, half_extent]` and then shifting it to the center.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Obb<T> {
+    center: Vector<T, 3>,
+    half_extent: Vector<T, 3>,
+    rotation: Rotation3<T>,
+}
+
+impl<T> Obb<T> {
+    pub const fn new(
+        center: Vector<T, 3>,
+        half_extent: Vector<T, 3>,
+        rotation: Rotation3<T>,
+    ) -> Self {
+        Self {
+            center,
+            half_extent,
+            rotation,
+        }
+    }
+    pub fn into_center_half_extent_rotation(self) -> (Vector<T, 3>, Vector<T, 3>, Rotation3<T>) {
+        (self.center, self.half_extent, self.rotation)
+    }
+}
+
+impl<T: Copy> Obb<T> {
+    pub fn center(&self) -> Vector<T, 3> {
+        self.center
+    }
+    pub fn half_extent(&self) -> Vector<T, 3> {
+        self.half_extent
+    }
+    pub fn rotation(&self) -> Rotation3<T> {
+        self.rotation
+    }
+}
+
+impl<T: Float> Obb<T> {
+    /// Box with the same shape as the axis-aligned one.
+    pub fn from_aabb(aabb: &Aabb3<T>) -> Self {
+        Self::new(aabb.center(), aabb.half_extent(), Rotation3::identity())
+    }
+
+    /// Matrix which columns are the local axes of the box.
+    pub fn axes(&self) -> Matrix<T, 3, 3> {
+        self.rotation.to_linear().into_matrix()
+    }
+    /// Coordinates of the point in the local frame of the box.
+    pub fn to_local(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        self.rotation.inv().apply(point - self.center)
+    }
+    /// Point with the specified coordinates in the local frame of the box.
+    pub fn from_local(&self, local: Vector<T, 3>) -> Vector<T, 3> {
+        self.center + self.rotation.apply(local)
+    }
+    pub fn volume(&self) -> T {
+        let e = self.half_extent * (T::one() + T::one());
+        e.x() * e.y() * e.z()
+    }
+
+    /// Check whether the point is inside the box or on its boundary.
+    pub fn contains(&self, point: Vector<T, 3>) -> bool {
+        self.to_local(point).abs().vle(self.half_extent).all()
+    }
+    /// Point of the box closest to the specified one.
+    pub fn closest_point(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        let h = self.half_extent;
+        self.from_local(self.to_local(point).clamp(-h, h))
+    }
+    /// Distance from the point to the box, zero if the point is inside.
+    pub fn distance(&self, point: Vector<T, 3>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Check whether boxes intersect using the separating axis theorem.
+    ///
+    /// Face normals of both boxes and their pairwise cross products are tested (15 axes in total).
+    pub fn intersects(&self, other: &Self) -> bool {
+        let (ha, hb) = (self.half_extent, other.half_extent);
+        let a = self.axes();
+        // Orientation and position of the other box in the local frame of this one.
+        let r = a.transpose().dot(other.axes());
+        let t = a.transpose().dot(other.center - self.center);
+        // Small term is added to handle nearly parallel edges, which cross product is close to zero.
+        let abs_r = r.abs() + Matrix::fill(T::epsilon() * T::from(16).unwrap());
+        for i in 0..3 {
+            if t[i].abs() > ha[i] + abs_r.row(i).dot(hb) {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            if t.dot(r.col(j)).abs() > abs_r.col(j).dot(ha) + hb[j] {
+                return false;
+            }
+        }
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ha[i1] * abs_r[(i2, j)] + ha[i2] * abs_r[(i1, j)];
+                let rb = hb[j1] * abs_r[(i, j2)] + hb[j2] * abs_r[(i, j1)];
+                if (t[i2] * r[(i1, j)] - t[i1] * r[(i2, j)]).abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+    /// Check whether the box intersects the axis-aligned one.
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        !aabb.is_empty() && self.intersects(&Self::from_aabb(aabb))
+    }
+    /// Check whether the box intersects the sphere.
+    pub fn intersects_sphere(&self, sphere: &Sphere3<T>) -> bool {
+        !sphere.is_empty() && self.distance(sphere.center()) <= sphere.radius()
+    }
+
+    /// Bounding box of the oriented box.
+    pub fn aabb(&self) -> Aabb3<T> {
+        Aabb3::from_center(self.center, self.axes().abs().dot(self.half_extent))
+    }
+
+    /// Fit the box to the points using principal component analysis.
+    ///
+    /// Axes of the box are the eigenvectors of the covariance matrix of the points,
+    /// so the result is not the minimal box in general, but it is cheap and usually good enough.
+    /// Returns `None` if there are no points.
+    pub fn fit(points: &[Vector<T, 3>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let n = T::from(points.len()).unwrap();
+        let zero = Vector::fill(T::zero());
+        let mean = points.iter().fold(zero, |a, p| a + *p) / n;
+        let cov = points.iter().fold(Matrix::fill(T::zero()), |a, p| {
+            let d = *p - mean;
+            a + Matrix::indices().map(|(i, j)| d[i] * d[j])
+        }) / n;
+        let mut axes = symmetric_eigen(cov).1;
+        if axes.det() < T::zero() {
+            for i in 0..3 {
+                axes[(i, 2)] = -axes[(i, 2)];
+            }
+        }
+        let rotation = Rotation3::from_linear(Linear::from_matrix(axes));
+        let (lo, hi) = points.iter().fold(
+            (Vector::fill(T::infinity()), Vector::fill(T::neg_infinity())),
+            |(lo, hi), p| {
+                let l = rotation.inv().apply(*p - mean);
+                (lo.vmin(l), hi.vmax(l))
+            },
+        );
+        let two = T::one() + T::one();
+        Some(Self::new(
+            mean + rotation.apply((lo + hi) / two),
+            (hi - lo) / two,
+            rotation,
+        ))
+    }
+
+    /// Image of the box under the rigid motion.
+    pub fn transform(&self, map: &Isometry3<T>) -> Self {
+        Self::new(
+            map.apply(self.center),
+            self.half_extent,
+            map.rotation().chain(self.rotation),
+        )
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Obb<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.center, other.center, epsilon = epsilon)
+            && abs_diff_eq!(self.half_extent, other.half_extent, epsilon = epsilon)
+            && abs_diff_eq!(self.rotation, other.rotation, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Normal, Uniform},
+        transform::Shift3,
+    };
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::FRAC_PI_4;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    type V = Vector<f64, 3>;
+
+    fn v(x: f64, y: f64, z: f64) -> V {
+        Vector::from([x, y, z])
+    }
+
+    fn sample_obb(rng: &mut XorShiftRng) -> Obb<f64> {
+        let h: V = rng.sample(&Normal);
+        Obb::new(
+            rng.sample(&Normal),
+            h.abs() + V::fill(0.1),
+            rng.sample(&Uniform),
+        )
+    }
+
+    fn corners(b: &Obb<f64>) -> [V; 8] {
+        let mut res = [V::fill(0.0); 8];
+        for (k, c) in res.iter_mut().enumerate() {
+            let s = Vector::indices().map(|i| if (k >> i) & 1 == 0 { -1.0 } else { 1.0 });
+            *c = b.from_local(b.half_extent() * s);
+        }
+        res
+    }
+
+    #[test]
+    fn queries() {
+        let mut rng = XorShiftRng::seed_from_u64(0x0BB);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let b = sample_obb(&mut rng);
+            let p: V = rng.sample::<V, _>(&Normal) * 2.0;
+            assert_abs_diff_eq!(b.to_local(b.from_local(p)), p, epsilon = EPS);
+            let c = b.closest_point(p);
+            assert!(b
+                .to_local(c)
+                .abs()
+                .vle(b.half_extent() + V::fill(EPS))
+                .all());
+            for q in corners(&b).iter() {
+                assert!(b.distance(p) <= (p - *q).length() + EPS);
+            }
+            assert_abs_diff_eq!(
+                b.aabb(),
+                Aabb3::bounding(corners(&b).iter().cloned()),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn separating_axes() {
+        let cube = Obb::from_aabb(&Aabb3::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0)));
+        // Cube rotated so that its edge points to the face of the first one.
+        let rot = Rotation3::new(v(0.0, 0.0, 1.0), FRAC_PI_4);
+        let edge = |x: f64| Obb::new(v(x, 0.0, 0.0), V::fill(1.0), rot);
+        assert!(cube.intersects(&edge(1.0 + 2f64.sqrt() - 1e-3)));
+        assert!(!cube.intersects(&edge(1.0 + 2f64.sqrt() + 1e-3)));
+        // Crossing edges, separated only by the cross product of edge directions.
+        let a = Obb::new(
+            V::fill(0.0),
+            V::fill(1.0),
+            Rotation3::new(v(1.0, 0.0, 0.0), FRAC_PI_4),
+        );
+        let b = |y: f64| {
+            Obb::new(
+                v(0.0, y, 0.0),
+                V::fill(1.0),
+                Rotation3::new(v(0.0, 0.0, 1.0), FRAC_PI_4),
+            )
+        };
+        assert!(a.intersects(&b(2.0f64.sqrt() * 2.0 - 1e-3)));
+        assert!(!a.intersects(&b(2.0f64.sqrt() * 2.0 + 1e-3)));
+
+        let mut rng = XorShiftRng::seed_from_u64(0x0BC);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (a, b) = (sample_obb(&mut rng), sample_obb(&mut rng));
+            let hit = a.intersects(&b);
+            assert_eq!(hit, b.intersects(&a));
+            if corners(&a).iter().any(|c| b.contains(*c)) || b.contains(a.center()) {
+                assert!(hit);
+            }
+            if !hit {
+                for _ in 0..64 {
+                    let s: V = rng.sample(&Normal);
+                    let p = a.from_local(a.half_extent() * s.clamp(-1.0, 1.0));
+                    assert!(!b.contains(p));
+                }
+            }
+            // Boxes moved apart beyond their bounding spheres can not intersect.
+            let d: V = rng.sample(&Normal);
+            let far = a.half_extent().length() + b.half_extent().length() + 1e-3;
+            let c = Obb::new(
+                a.center() + d.normalize() * far,
+                b.half_extent(),
+                b.rotation(),
+            );
+            assert!(!a.intersects(&c));
+        }
+    }
+
+    #[test]
+    fn fit() {
+        let mut rng = XorShiftRng::seed_from_u64(0x0BD);
+        assert!(Obb::<f64>::fit(&[]).is_none());
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let b = sample_obb(&mut rng);
+            let points = corners(&b);
+            let f = Obb::fit(&points).unwrap();
+            for p in points.iter() {
+                assert!(f.distance(*p) <= EPS);
+            }
+            // Covariance of the box corners is diagonal in its local frame, so the box is reproduced.
+            assert_abs_diff_eq!(f.volume(), b.volume(), epsilon = 1e-6);
+            assert_abs_diff_eq!(f.center(), b.center(), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let mut rng = XorShiftRng::seed_from_u64(0x0BE);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let b = sample_obb(&mut rng);
+            let iso = Isometry3::new(
+                Shift3::from_vector(rng.sample(&Normal)),
+                rng.sample(&Uniform),
+            );
+            let t = b.transform(&iso);
+            for (p, q) in corners(&b).iter().zip(corners(&t).iter()) {
+                assert_abs_diff_eq!(iso.apply(*p), *q, epsilon = EPS);
+            }
+        }
+    }
+}
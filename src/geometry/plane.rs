@@ -0,0 +1,234 @@
+use crate::{geometry::Line, traits::Dot, transform::Directional, Vector};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::Float;
+
+/// Hyperplane consisting of points `x` such that `normal · x = offset`.
+///
+/// Normal is not required to be of unit length, but some methods are cheaper if it is.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Plane<T, const N: usize> {
+    normal: Vector<T, N>,
+    offset: T,
+}
+
+pub type Plane2<T> = Plane<T, 2>;
+pub type Plane3<T> = Plane<T, 3>;
+
+impl<T, const N: usize> Plane<T, N> {
+    pub const fn new(normal: Vector<T, N>, offset: T) -> Self {
+        Self { normal, offset }
+    }
+    pub fn into_normal_offset(self) -> (Vector<T, N>, T) {
+        (self.normal, self.offset)
+    }
+}
+
+impl<T: Copy, const N: usize> Plane<T, N> {
+    pub fn normal(&self) -> Vector<T, N> {
+        self.normal
+    }
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+}
+
+impl<T: Float, const N: usize> Plane<T, N> {
+    /// Plane passing through the point and orthogonal to the normal.
+    pub fn from_point_normal(point: Vector<T, N>, normal: Vector<T, N>) -> Self {
+        Self::new(normal, normal.dot(point))
+    }
+    /// Equivalent plane with unit normal.
+    pub fn normalize(&self) -> Self {
+        let len = self.normal.length();
+        Self::new(self.normal / len, self.offset / len)
+    }
+    /// The same plane with the opposite orientation.
+    pub fn flip(&self) -> Self {
+        Self::new(-self.normal, -self.offset)
+    }
+    /// Point of the plane closest to the origin.
+    pub fn point(&self) -> Vector<T, N> {
+        self.normal * (self.offset / self.normal.square_length())
+    }
+
+    /// Signed distance from the plane to the point, positive on the side the normal points to.
+    pub fn signed_distance(&self, point: Vector<T, N>) -> T {
+        (self.normal.dot(point) - self.offset) / self.normal.length()
+    }
+    /// Distance from the plane to the point.
+    pub fn distance(&self, point: Vector<T, N>) -> T {
+        self.signed_distance(point).abs()
+    }
+    /// Orthogonal projection of the point onto the plane.
+    pub fn project(&self, point: Vector<T, N>) -> Vector<T, N> {
+        let k = (self.normal.dot(point) - self.offset) / self.normal.square_length();
+        point - self.normal * k
+    }
+    /// Mirror image of the point with respect to the plane.
+    pub fn reflect(&self, point: Vector<T, N>) -> Vector<T, N> {
+        let k = (self.normal.dot(point) - self.offset) / self.normal.square_length();
+        point - self.normal * (k + k)
+    }
+    /// Reflect the direction vector with respect to the plane.
+    pub fn reflect_dir(&self, dir: Vector<T, N>) -> Vector<T, N> {
+        let k = self.normal.dot(dir) / self.normal.square_length();
+        dir - self.normal * (k + k)
+    }
+
+    /// Point of intersection with the line, `None` if the line is parallel to the plane.
+    pub fn intersect_line(&self, line: &Line<T, N>) -> Option<Vector<T, N>> {
+        line.intersect_plane(self.normal, self.offset)
+            .map(|t| line.at(t))
+    }
+
+    /// Image of the plane under the transformation.
+    ///
+    /// Normal is transformed by `apply_normal` at the point of the plane closest to the origin,
+    /// so the result is exact for affine transformations.
+    pub fn transform<M: Directional<Vector<T, N>>>(&self, map: &M) -> Self {
+        let point = self.point();
+        Self::from_point_normal(map.apply(point), map.apply_normal(point, self.normal))
+    }
+}
+
+impl<T: Float> Plane<T, 3> {
+    /// Plane passing through three points, oriented counterclockwise.
+    pub fn from_points(a: Vector<T, 3>, b: Vector<T, 3>, c: Vector<T, 3>) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a))
+    }
+    /// Line of intersection of two planes, `None` if planes are parallel.
+    pub fn intersect_plane(&self, other: &Self) -> Option<Line<T, 3>> {
+        let dir = self.normal.cross(other.normal);
+        let den = dir.square_length();
+        if den == T::zero() {
+            return None;
+        }
+        let point = (other.normal * self.offset - self.normal * other.offset).cross(dir) / den;
+        Some(Line::new(point, dir))
+    }
+    /// Common point of three planes, `None` if there is no single such point.
+    pub fn intersect_three(a: &Self, b: &Self, c: &Self) -> Option<Vector<T, 3>> {
+        let bc = b.normal.cross(c.normal);
+        let det = a.normal.dot(bc);
+        if det == T::zero() {
+            return None;
+        }
+        let sum = bc * a.offset
+            + c.normal.cross(a.normal) * b.offset
+            + a.normal.cross(b.normal) * c.offset;
+        Some(sum / det)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T, const N: usize> AbsDiffEq for Plane<T, N>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.normal, other.normal, epsilon = epsilon)
+            && abs_diff_eq!(self.offset, other.offset, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Invertible, Normal},
+        transform::{Affine3, Linear3, Shift3},
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    type V = Vector<f64, 3>;
+
+    fn sample_plane(rng: &mut XorShiftRng) -> Plane3<f64> {
+        Plane::new(rng.sample(&Normal), rng.sample(&Normal))
+    }
+
+    #[test]
+    fn queries() {
+        let mut rng = XorShiftRng::seed_from_u64(0x9A1);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let p = sample_plane(&mut rng);
+            let x: V = rng.sample(&Normal);
+            let y = p.project(x);
+            assert_abs_diff_eq!(p.signed_distance(y), 0.0, epsilon = EPS);
+            assert_abs_diff_eq!((x - y).length(), p.distance(x), epsilon = EPS);
+            let z = p.reflect(x);
+            assert_abs_diff_eq!(p.signed_distance(z), -p.signed_distance(x), epsilon = EPS);
+            assert_abs_diff_eq!((z + x) / 2.0, y, epsilon = EPS);
+            assert_abs_diff_eq!(
+                p.normalize().signed_distance(x),
+                p.signed_distance(x),
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(
+                p.flip().signed_distance(x),
+                -p.signed_distance(x),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn intersection() {
+        let mut rng = XorShiftRng::seed_from_u64(0x9A2);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (a, b, c) = (
+                sample_plane(&mut rng),
+                sample_plane(&mut rng),
+                sample_plane(&mut rng),
+            );
+            let x = Plane::intersect_three(&a, &b, &c).unwrap();
+            for p in [a, b, c].iter() {
+                assert_abs_diff_eq!(p.signed_distance(x), 0.0, epsilon = 1e-6);
+            }
+            let l = a.intersect_plane(&b).unwrap();
+            for t in [-1.0, 0.0, 2.0].iter() {
+                assert_abs_diff_eq!(a.signed_distance(l.at(*t)), 0.0, epsilon = 1e-6);
+                assert_abs_diff_eq!(b.signed_distance(l.at(*t)), 0.0, epsilon = 1e-6);
+            }
+            let y = c.intersect_line(&l).unwrap();
+            assert_abs_diff_eq!(y, x, epsilon = 1e-4 * (1.0 + x.length()));
+        }
+        let p = Plane::new(Vector::from([0.0, 0.0, 1.0]), 1.0);
+        assert!(p.intersect_plane(&p.flip()).is_none());
+        assert!(Plane::intersect_three(&p, &p, &p).is_none());
+    }
+
+    fn check_transform<M: Directional<V>>(rng: &mut XorShiftRng, map: M) {
+        let p = sample_plane(rng);
+        let q = p.transform(&map);
+        for _ in 0..4 {
+            let x = p.project(rng.sample(&Normal));
+            assert_abs_diff_eq!(q.signed_distance(map.apply(x)), 0.0, epsilon = 1e-6);
+        }
+        // Orientation is preserved.
+        let x: V = p.point() + p.normal();
+        assert!(q.signed_distance(map.apply(x)) > 0.0);
+    }
+
+    #[test]
+    fn transform() {
+        let mut rng = XorShiftRng::seed_from_u64(0x9A3);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let shift = Shift3::<f64>::from_vector(rng.sample(&Normal));
+            check_transform(&mut rng, shift);
+            let lin: Linear3<f64> = rng.sample(&Invertible);
+            check_transform(&mut rng, lin);
+            let aff: Affine3<f64> = rng.sample(&Invertible);
+            check_transform(&mut rng, aff);
+        }
+    }
+}
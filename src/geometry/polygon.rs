@@ -0,0 +1,587 @@
+//! Two-dimensional polygon utilities.
+//!
+//! Polygon is represented by the slice of its vertices, the last vertex is implicitly connected to the first one.
+//! Counterclockwise polygons have positive area.
+//!
+//! Functions that produce a variable number of items write them into provided buffers,
+//! their allocating counterparts are available with the `alloc` feature.
+
+use crate::{
+    geometry::{predicates::orient2d, Segment},
+    Vector,
+};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use num_traits::Float;
+
+/// Orientation of a triangle or a polygon.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    Clockwise,
+    Collinear,
+    CounterClockwise,
+}
+
+impl Orientation {
+    fn from_value<T: Float>(value: T) -> Self {
+        if value > T::zero() {
+            Orientation::CounterClockwise
+        } else if value < T::zero() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `abc`.
+fn orient_value<T: Float>(a: Vector<T, 2>, b: Vector<T, 2>, c: Vector<T, 2>) -> T {
+    (b - a).cross(c - a)
+}
+
+/// Convert the point to `f64` coordinates.
+fn to_f64<T: Float>(p: Vector<T, 2>) -> Option<Vector<f64, 2>> {
+    Some(Vector::from([p.x().to_f64()?, p.y().to_f64()?]))
+}
+
+/// Orientation of the triangle `abc`.
+///
+/// Coordinates are converted to `f64` and passed to the robust [`orient2d`] predicate,
+/// so the result is exact for `f32` and `f64` points.
+/// All sign tests of this module are based on this function.
+pub fn orient<T: Float>(a: Vector<T, 2>, b: Vector<T, 2>, c: Vector<T, 2>) -> Orientation {
+    match (to_f64(a), to_f64(b), to_f64(c)) {
+        (Some(a), Some(b), Some(c)) => Orientation::from_value(orient2d(a, b, c)),
+        _ => Orientation::from_value(orient_value(a, b, c)),
+    }
+}
+
+/// Iterator over polygon edges as pairs of vertices.
+fn edges<T: Copy, const N: usize>(
+    poly: &[Vector<T, N>],
+) -> impl Iterator<Item = (Vector<T, N>, Vector<T, N>)> + '_ {
+    poly.iter()
+        .zip(poly.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/// Signed area of the polygon, positive if it is oriented counterclockwise.
+pub fn signed_area<T: Float>(poly: &[Vector<T, 2>]) -> T {
+    edges(poly).fold(T::zero(), |s, (a, b)| s + a.cross(b)) / (T::one() + T::one())
+}
+
+/// Area of the polygon.
+pub fn area<T: Float>(poly: &[Vector<T, 2>]) -> T {
+    signed_area(poly).abs()
+}
+
+/// Orientation of the polygon determined by the sign of its area.
+pub fn orientation<T: Float>(poly: &[Vector<T, 2>]) -> Orientation {
+    Orientation::from_value(signed_area(poly))
+}
+
+/// Center of mass of the polygon area, `None` if the area is zero.
+pub fn centroid<T: Float>(poly: &[Vector<T, 2>]) -> Option<Vector<T, 2>> {
+    let (sum, area2) = edges(poly).fold((Vector::fill(T::zero()), T::zero()), |(s, w), (a, b)| {
+        let c = a.cross(b);
+        (s + (a + b) * c, w + c)
+    });
+    if area2 == T::zero() {
+        None
+    } else {
+        Some(sum / (area2 * T::from(3).unwrap()))
+    }
+}
+
+/// Check whether the polygon is convex, collinear neighbouring edges are allowed.
+///
+/// Besides all turns having the same direction, edge directions must make exactly one revolution,
+/// so self-intersecting polygons like a pentagram are not convex.
+pub fn is_convex<T: Float>(poly: &[Vector<T, 2>]) -> bool {
+    let n = poly.len();
+    let mut sign = Orientation::Collinear;
+    for i in 0..n {
+        match orient(poly[i], poly[(i + 1) % n], poly[(i + 2) % n]) {
+            Orientation::Collinear => (),
+            o if sign == Orientation::Collinear => sign = o,
+            o if o != sign => return false,
+            _ => (),
+        }
+    }
+    // Edges of a convex polygon change their horizontal direction at most twice.
+    let mut dirs = edges(poly).filter_map(|(a, b)| match b.x().partial_cmp(&a.x()) {
+        Some(Ordering::Equal) | None => None,
+        o => o,
+    });
+    let first = match dirs.next() {
+        Some(d) => d,
+        None => return true,
+    };
+    let (last, changes) = dirs.fold((first, 0), |(prev, k), d| (d, k + (d != prev) as usize));
+    changes + (last != first) as usize <= 2
+}
+
+/// Number of times the polygon winds around the point counterclockwise.
+///
+/// The result is unspecified for points lying on the polygon boundary.
+pub fn winding_number<T: Float>(poly: &[Vector<T, 2>], point: Vector<T, 2>) -> i32 {
+    edges(poly).fold(0, |w, (a, b)| {
+        if a.y() <= point.y() {
+            if b.y() > point.y() && orient(a, b, point) == Orientation::CounterClockwise {
+                return w + 1;
+            }
+        } else if b.y() <= point.y() && orient(a, b, point) == Orientation::Clockwise {
+            return w - 1;
+        }
+        w
+    })
+}
+
+/// Check whether the point lies on the polygon boundary.
+pub fn on_boundary<T: Float>(poly: &[Vector<T, 2>], point: Vector<T, 2>) -> bool {
+    edges(poly).any(|(a, b)| on_segment(a, b, point))
+}
+
+/// Check whether the point is inside the polygon or on its boundary.
+///
+/// Non-zero winding rule is used for self-intersecting polygons.
+pub fn contains<T: Float>(poly: &[Vector<T, 2>], point: Vector<T, 2>) -> bool {
+    on_boundary(poly, point) || winding_number(poly, point) != 0
+}
+
+/// Check whether the point `p` lies on the segment `ab`.
+fn on_segment<T: Float>(a: Vector<T, 2>, b: Vector<T, 2>, p: Vector<T, 2>) -> bool {
+    orient(a, b, p) == Orientation::Collinear && in_box(a, b, p)
+}
+
+/// Check whether the point `p` lies in the bounding box of `a` and `b`.
+fn in_box<T: Float>(a: Vector<T, 2>, b: Vector<T, 2>, p: Vector<T, 2>) -> bool {
+    a.vmin(b).vle(p).all() && p.vle(a.vmax(b)).all()
+}
+
+/// Check whether two segments have a common point.
+///
+/// Only signs of orientations are used, so touching and collinear overlapping segments are handled consistently.
+pub fn segments_intersect<T: Float>(s: &Segment<T, 2>, t: &Segment<T, 2>) -> bool {
+    let (a, b, c, d) = (s.a(), s.b(), t.a(), t.b());
+    let (o1, o2) = (orient(a, b, c), orient(a, b, d));
+    let (o3, o4) = (orient(c, d, a), orient(c, d, b));
+    if o1 != o2
+        && o3 != o4
+        && [o1, o2, o3, o4]
+            .iter()
+            .all(|o| *o != Orientation::Collinear)
+    {
+        return true;
+    }
+    (o1 == Orientation::Collinear && in_box(a, b, c))
+        || (o2 == Orientation::Collinear && in_box(a, b, d))
+        || (o3 == Orientation::Collinear && in_box(c, d, a))
+        || (o4 == Orientation::Collinear && in_box(c, d, b))
+}
+
+/// Common point of two segments, `None` if they don't intersect.
+///
+/// If the segments are collinear and overlap then some point of the overlap is returned.
+pub fn segment_intersection<T: Float>(
+    s: &Segment<T, 2>,
+    t: &Segment<T, 2>,
+) -> Option<Vector<T, 2>> {
+    if !segments_intersect(s, t) {
+        return None;
+    }
+    let (a, b, c, d) = (s.a(), s.b(), t.a(), t.b());
+    let den = (b - a).cross(d - c);
+    if den != T::zero() {
+        let u = ((c - a).cross(d - c) / den).max(T::zero()).min(T::one());
+        return Some(s.at(u));
+    }
+    // Collinear segments, some endpoint lies on the other segment.
+    [c, d, a]
+        .iter()
+        .cloned()
+        .find(|p| in_box(a, b, *p) && in_box(c, d, *p))
+        .or(Some(b))
+}
+
+fn lexicographic<T: Float>(a: &Vector<T, 2>, b: &Vector<T, 2>) -> Ordering {
+    (a.x(), a.y())
+        .partial_cmp(&(b.x(), b.y()))
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Convex hull of the points using Andrew's monotone chain algorithm.
+///
+/// Points are sorted in place and the hull vertices are written to `hull` in counterclockwise order
+/// starting from the leftmost point, collinear points on the hull edges are omitted.
+/// Returns the number of hull vertices.
+///
+/// # Panics
+///
+/// Panics if `hull` is shorter than `points.len() + 1`.
+pub fn convex_hull_into<T: Float>(points: &mut [Vector<T, 2>], hull: &mut [Vector<T, 2>]) -> usize {
+    let n = points.len();
+    assert!(hull.len() > n, "hull buffer is too short");
+    points.sort_unstable_by(lexicographic);
+    let mut k = 0;
+    for p in points.iter() {
+        while k >= 2 && orient(hull[k - 2], hull[k - 1], *p) != Orientation::CounterClockwise {
+            k -= 1;
+        }
+        hull[k] = *p;
+        k += 1;
+    }
+    if k < 2 {
+        return k;
+    }
+    let lower = k + 1;
+    for p in points.iter().rev().skip(1) {
+        while k >= lower && orient(hull[k - 2], hull[k - 1], *p) != Orientation::CounterClockwise {
+            k -= 1;
+        }
+        hull[k] = *p;
+        k += 1;
+    }
+    // The last point is equal to the first one.
+    k -= 1;
+    if k == 2 && hull[0] == hull[1] {
+        1
+    } else {
+        k
+    }
+}
+
+/// Convex hull of the points in counterclockwise order, see [`convex_hull_into`].
+#[cfg(feature = "alloc")]
+pub fn convex_hull<T: Float>(points: &[Vector<T, 2>]) -> Vec<Vector<T, 2>> {
+    let mut points = points.to_vec();
+    let mut hull = vec![Vector::fill(T::zero()); points.len() + 1];
+    let k = convex_hull_into(&mut points, &mut hull);
+    hull.truncate(k);
+    hull
+}
+
+/// Triangulate simple polygon by ear clipping.
+///
+/// `work` is a scratch buffer of at least `poly.len()` indices.
+/// Triangles are written to `triangles` as triples of vertex indices with the same orientation as the polygon.
+/// Returns the number of triangles which is `poly.len() - 2` for simple polygons without collinear neighbouring edges
+/// (degenerate triangles at collinear vertices are skipped).
+/// For self-intersecting polygons the result is unspecified, but the function always terminates.
+///
+/// # Panics
+///
+/// Panics if the buffers are too short.
+pub fn triangulate_into<T: Float>(
+    poly: &[Vector<T, 2>],
+    work: &mut [usize],
+    triangles: &mut [[usize; 3]],
+) -> usize {
+    let n = poly.len();
+    if n < 3 {
+        return 0;
+    }
+    assert!(work.len() >= n, "work buffer is too short");
+    assert!(triangles.len() >= n - 2, "triangles buffer is too short");
+    let dir = orientation(poly);
+    if dir == Orientation::Collinear {
+        return 0;
+    }
+    let work = &mut work[..n];
+    for (i, w) in work.iter_mut().enumerate() {
+        *w = i;
+    }
+    let (mut m, mut count, mut i, mut idle) = (n, 0, 0, 0);
+    while m > 3 {
+        let (ip, ic, inx) = (work[(i + m - 1) % m], work[i], work[(i + 1) % m]);
+        let (a, b, c) = (poly[ip], poly[ic], poly[inx]);
+        let o = orient(a, b, c);
+        let is_ear = o == dir
+            && work[..m].iter().all(|&j| {
+                let p = poly[j];
+                j == ip
+                    || j == ic
+                    || j == inx
+                    || p == a
+                    || p == b
+                    || p == c
+                    || orient(a, b, p) != dir
+                    || orient(b, c, p) != dir
+                    || orient(c, a, p) != dir
+            });
+        // Degenerate vertices are removed when no ear can be found.
+        if is_ear || (idle >= m && o == Orientation::Collinear) || idle >= 2 * m {
+            if o != Orientation::Collinear {
+                triangles[count] = [ip, ic, inx];
+                count += 1;
+            }
+            work.copy_within((i + 1)..m, i);
+            m -= 1;
+            i %= m;
+            idle = 0;
+        } else {
+            i = (i + 1) % m;
+            idle += 1;
+        }
+    }
+    if orient(poly[work[0]], poly[work[1]], poly[work[2]]) != Orientation::Collinear {
+        triangles[count] = [work[0], work[1], work[2]];
+        count += 1;
+    }
+    count
+}
+
+/// Triangulate simple polygon by ear clipping, see [`triangulate_into`].
+#[cfg(feature = "alloc")]
+pub fn triangulate<T: Float>(poly: &[Vector<T, 2>]) -> Vec<[usize; 3]> {
+    let n = poly.len();
+    let mut work = vec![0; n];
+    let mut triangles = vec![[0; 3]; n.saturating_sub(2)];
+    let k = triangulate_into(poly, &mut work, &mut triangles);
+    triangles.truncate(k);
+    triangles
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::PI;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    type V = Vector<f64, 2>;
+
+    fn v(x: f64, y: f64) -> V {
+        Vector::from([x, y])
+    }
+
+    /// Random star-shaped polygon with `n` vertices.
+    fn star(rng: &mut XorShiftRng, poly: &mut [V]) {
+        let n = poly.len();
+        for (i, p) in poly.iter_mut().enumerate() {
+            let phi = 2.0 * PI * (i as f64 + 0.8 * rng.gen::<f64>()) / n as f64;
+            let r = 0.2 + rng.gen::<f64>();
+            *p = v(r * phi.cos(), r * phi.sin());
+        }
+    }
+
+    #[test]
+    fn measures() {
+        let square = [v(0.0, 0.0), v(2.0, 0.0), v(2.0, 2.0), v(0.0, 2.0)];
+        assert_abs_diff_eq!(signed_area(&square), 4.0);
+        assert_abs_diff_eq!(centroid(&square).unwrap(), v(1.0, 1.0));
+        assert_eq!(orientation(&square), Orientation::CounterClockwise);
+        assert!(is_convex(&square));
+        let mut rev = square;
+        rev.reverse();
+        assert_abs_diff_eq!(signed_area(&rev), -4.0);
+        assert_abs_diff_eq!(centroid(&rev).unwrap(), v(1.0, 1.0));
+        assert_eq!(orientation(&rev), Orientation::Clockwise);
+
+        let l = [
+            v(0.0, 0.0),
+            v(2.0, 0.0),
+            v(2.0, 1.0),
+            v(1.0, 1.0),
+            v(1.0, 2.0),
+            v(0.0, 2.0),
+        ];
+        assert_abs_diff_eq!(area(&l), 3.0);
+        assert_abs_diff_eq!(
+            centroid(&l).unwrap(),
+            v(5.0 / 6.0, 5.0 / 6.0),
+            epsilon = EPS
+        );
+        assert!(!is_convex(&l));
+        let pentagram = [0, 1, 2, 3, 4].map(|k| {
+            let phi = 4.0 * PI * k as f64 / 5.0;
+            v(phi.cos(), phi.sin())
+        });
+        assert!(!is_convex(&pentagram));
+        let pentagon = [0, 1, 2, 3, 4].map(|k| {
+            let phi = 2.0 * PI * k as f64 / 5.0;
+            v(phi.cos(), phi.sin())
+        });
+        assert!(is_convex(&pentagon));
+        assert!(centroid(&[v(0.0, 0.0), v(1.0, 1.0), v(2.0, 2.0)]).is_none());
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        let l = [
+            v(0.0, 0.0),
+            v(2.0, 0.0),
+            v(2.0, 1.0),
+            v(1.0, 1.0),
+            v(1.0, 2.0),
+            v(0.0, 2.0),
+        ];
+        assert_eq!(winding_number(&l, v(0.5, 0.5)), 1);
+        assert_eq!(winding_number(&l, v(1.5, 1.5)), 0);
+        let mut rev = l;
+        rev.reverse();
+        assert_eq!(winding_number(&rev, v(0.5, 1.5)), -1);
+        assert!(contains(&l, v(1.5, 0.5)));
+        assert!(!contains(&l, v(1.5, 1.5)));
+        assert!(contains(&l, v(1.5, 1.0)));
+        assert!(contains(&l, v(2.0, 0.0)));
+        assert!(on_boundary(&l, v(1.0, 1.5)));
+        // Doubly wound square.
+        let twice = [
+            v(0.0, 0.0),
+            v(1.0, 0.0),
+            v(1.0, 1.0),
+            v(0.0, 1.0),
+            v(0.0, 0.0),
+            v(1.0, 0.0),
+            v(1.0, 1.0),
+            v(0.0, 1.0),
+        ];
+        assert_eq!(winding_number(&twice, v(0.5, 0.5)), 2);
+    }
+
+    #[test]
+    fn segments() {
+        let s = |a: V, b: V| Segment::new(a, b);
+        let a = s(v(0.0, 0.0), v(2.0, 2.0));
+        assert!(segments_intersect(&a, &s(v(0.0, 2.0), v(2.0, 0.0))));
+        assert_abs_diff_eq!(
+            segment_intersection(&a, &s(v(0.0, 2.0), v(2.0, 0.0))).unwrap(),
+            v(1.0, 1.0)
+        );
+        // Touching at the endpoint.
+        assert!(segments_intersect(&a, &s(v(2.0, 2.0), v(3.0, 0.0))));
+        assert!(segments_intersect(&a, &s(v(1.0, 1.0), v(3.0, 0.0))));
+        assert!(!segments_intersect(&a, &s(v(1.0, 1.1), v(1.0, 3.0))));
+        // Parallel and collinear.
+        assert!(!segments_intersect(&a, &s(v(1.0, 0.0), v(3.0, 2.0))));
+        assert!(!segments_intersect(&a, &s(v(3.0, 3.0), v(4.0, 4.0))));
+        let p = segment_intersection(&a, &s(v(3.0, 3.0), v(1.0, 1.0))).unwrap();
+        assert!(a.distance(p) < EPS && p.x() >= 1.0);
+    }
+
+    #[test]
+    fn nearly_collinear_segments() {
+        // The point `b` lies on the segment `ac` or slightly to either side of it,
+        // the segment `bd` goes to the left of `ac` so it intersects `ac` only if `b` is not on the left.
+        let ulp = f64::EPSILON / 4.0;
+        let (b, c, d) = (v(12.0, 12.0), v(24.0, 24.0), v(11.0, 13.0));
+        let int = |x: f64| (x * 2.0f64.powi(54)) as i128;
+        for i in -16..16 {
+            for j in -16..16 {
+                let a = v(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                let exact = (int(a.x()) - int(c.x())) * (int(b.y()) - int(c.y()))
+                    - (int(a.y()) - int(c.y())) * (int(b.x()) - int(c.x()));
+                assert_eq!(
+                    segments_intersect(&Segment::new(a, c), &Segment::new(b, d)),
+                    exact >= 0
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hull() {
+        let mut rng = XorShiftRng::seed_from_u64(0x9011);
+        for k in 0..SAMPLE_ATTEMPTS / 4 {
+            let mut points = [v(0.0, 0.0); 64];
+            for p in points[..k].iter_mut() {
+                *p = v(rng.gen(), rng.gen());
+            }
+            let points = &mut points[..k];
+            let mut buf = [v(0.0, 0.0); 65];
+            let n = convex_hull_into(points, &mut buf);
+            let hull = &buf[..n];
+            if k >= 3 {
+                assert!(is_convex(hull));
+                assert_eq!(orientation(hull), Orientation::CounterClockwise);
+                for p in points.iter() {
+                    assert!(contains(hull, *p));
+                }
+            }
+            for h in hull.iter() {
+                assert!(points.contains(h));
+            }
+        }
+        let mut points = [
+            v(0.0, 0.0),
+            v(1.0, 0.0),
+            v(2.0, 0.0),
+            v(2.0, 2.0),
+            v(1.0, 1.0),
+            v(0.0, 2.0),
+            v(0.0, 1.0),
+            v(2.0, 0.0),
+        ];
+        let mut buf = [v(0.0, 0.0); 9];
+        let n = convex_hull_into(&mut points, &mut buf);
+        assert_eq!(
+            &buf[..n],
+            &[v(0.0, 0.0), v(2.0, 0.0), v(2.0, 2.0), v(0.0, 2.0)]
+        );
+        let mut same = [v(1.0, 1.0); 4];
+        assert_eq!(convex_hull_into(&mut same, &mut buf), 1);
+    }
+
+    fn check_triangulation(poly: &[V]) {
+        let mut work = [0; 64];
+        let mut tris = [[0; 3]; 64];
+        let n = triangulate_into(poly, &mut work, &mut tris);
+        assert_eq!(n, poly.len() - 2);
+        let dir = orientation(poly);
+        let mut sum = 0.0;
+        for t in tris[..n].iter() {
+            let tri = [poly[t[0]], poly[t[1]], poly[t[2]]];
+            assert_eq!(orientation(&tri), dir);
+            sum += signed_area(&tri);
+        }
+        assert_abs_diff_eq!(sum, signed_area(poly), epsilon = EPS);
+    }
+
+    #[test]
+    fn triangulation() {
+        let mut rng = XorShiftRng::seed_from_u64(0x9012);
+        for k in 3..SAMPLE_ATTEMPTS / 4 {
+            let mut poly = [v(0.0, 0.0); 64];
+            star(&mut rng, &mut poly[..k]);
+            check_triangulation(&poly[..k]);
+            poly[..k].reverse();
+            check_triangulation(&poly[..k]);
+        }
+        let comb = [
+            v(0.0, 0.0),
+            v(4.0, 0.0),
+            v(4.0, 2.0),
+            v(3.0, 0.5),
+            v(2.0, 2.0),
+            v(1.0, 0.5),
+            v(0.0, 2.0),
+        ];
+        check_triangulation(&comb);
+        assert_eq!(
+            triangulate_into(
+                &[v(0.0, 0.0), v(1.0, 1.0), v(2.0, 2.0)],
+                &mut [0; 3],
+                &mut [[0; 3]; 1]
+            ),
+            0
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn allocating() {
+        let mut rng = XorShiftRng::seed_from_u64(0x9013);
+        let mut poly = [v(0.0, 0.0); 16];
+        star(&mut rng, &mut poly);
+        assert_eq!(triangulate(&poly).len(), 14);
+        let hull = convex_hull(&poly);
+        assert!(is_convex(&hull));
+        assert!(poly.iter().all(|p| contains(&hull, *p)));
+    }
+}
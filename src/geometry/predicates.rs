@@ -0,0 +1,921 @@
+//! Robust geometric predicates.
+//!
+//! Adaptive-precision versions of the orientation and in-circle tests after J. R. Shewchuk,
+//! "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+//!
+//! Each predicate is first evaluated in ordinary floating-point arithmetic together with a forward error bound.
+//! If the result is too close to zero to trust its sign, it is refined in stages of increasing cost
+//! and the determinant is recomputed exactly only as the last resort.
+//! Floating-point expansions are stored on the stack, so no allocation is needed,
+//! and large buffers are only reserved by the refinement functions when they are actually called.
+//!
+//! The sign of the returned value is always exact, its magnitude is only an approximation of the determinant.
+//! The arithmetic must be IEEE 754 double precision with round-to-nearest-even, no overflow or underflow must occur.
+
+use crate::Vector;
+
+/// Half of the machine epsilon, the relative error of a single floating-point operation.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+/// `2^27 + 1`, used to split `f64` into two non-overlapping halves.
+const SPLITTER: f64 = 134_217_729.0;
+
+const RESULT_ERRBOUND: f64 = (3.0 + 8.0 * EPSILON) * EPSILON;
+const CCW_ERRBOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const O3D_ERRBOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const ICC_ERRBOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const ICC_ERRBOUND_B: f64 = (4.0 + 48.0 * EPSILON) * EPSILON;
+const ICC_ERRBOUND_C: f64 = (11.0 + 72.0 * EPSILON) * EPSILON;
+const ISP_ERRBOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+const ISP_ERRBOUND_B: f64 = (5.0 + 72.0 * EPSILON) * EPSILON;
+const ISP_ERRBOUND_C: f64 = (71.0 + 1408.0 * EPSILON) * EPSILON;
+
+/// Sum `a + b` as a two-component expansion `(hi, lo)`, requires `|a| >= |b|`.
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    (x, b - (x - a))
+}
+
+/// Sum `a + b` as a two-component expansion `(hi, lo)`.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    (x, (a - av) + (b - bv))
+}
+
+/// Difference `a - b` as a two-component expansion `(hi, lo)`.
+fn two_diff(a: f64, b: f64) -> (f64, f64) {
+    two_sum(a, -b)
+}
+
+/// Split the number into two halves with at most 26 significant bits each.
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+/// Product `a * b` as a two-component expansion `(hi, lo)`.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (ahi, alo) = split(a);
+    let (bhi, blo) = split(b);
+    let err = ((x - ahi * bhi) - alo * bhi) - ahi * blo;
+    (x, alo * blo - err)
+}
+
+/// Sum of two expansions with zero components eliminated, returns the length of `h`.
+///
+/// Expansions are stored in the order of increasing magnitude.
+fn expansion_sum(e: &[f64], f: &[f64], h: &mut [f64]) -> usize {
+    let (mut i, mut j, mut k) = (0, 0, 0);
+    let mut q = None;
+    while i < e.len() || j < f.len() {
+        let g = if j == f.len() || (i < e.len() && e[i].abs() < f[j].abs()) {
+            i += 1;
+            e[i - 1]
+        } else {
+            j += 1;
+            f[j - 1]
+        };
+        q = Some(match q {
+            None => g,
+            Some(q) => {
+                let (s, err) = two_sum(q, g);
+                if err != 0.0 {
+                    h[k] = err;
+                    k += 1;
+                }
+                s
+            }
+        });
+    }
+    if let Some(q) = q {
+        if q != 0.0 {
+            h[k] = q;
+            k += 1;
+        }
+    }
+    k
+}
+
+/// Product of the expansion and the number with zero components eliminated, returns the length of `h`.
+fn expansion_scale(e: &[f64], b: f64, h: &mut [f64]) -> usize {
+    let mut k = 0;
+    let mut push = |h: &mut [f64], x: f64| {
+        if x != 0.0 {
+            h[k] = x;
+            k += 1;
+        }
+    };
+    let (mut q, lo) = match e.first() {
+        Some(e0) => two_product(*e0, b),
+        None => return 0,
+    };
+    push(h, lo);
+    for ei in e[1..].iter() {
+        let (p1, p0) = two_product(*ei, b);
+        let (s, lo) = two_sum(q, p0);
+        push(h, lo);
+        let (s, lo) = fast_two_sum(p1, s);
+        push(h, lo);
+        q = s;
+    }
+    push(h, q);
+    k
+}
+
+/// Approximate value of the expansion, its sign is exact.
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().sum()
+}
+
+/// Exact `ax * by - bx * ay`.
+fn minor2(a: (f64, f64), b: (f64, f64), h: &mut [f64]) -> usize {
+    let (p1, p0) = two_product(a.0, b.1);
+    let (q1, q0) = two_product(b.0, a.1);
+    expansion_sum(&[p0, p1], &[-q0, -q1], h)
+}
+
+/// Exact determinant of `[[ax, ay, 1], [bx, by, 1], [cx, cy, 1]]`.
+fn det2_exact(a: (f64, f64), b: (f64, f64), c: (f64, f64), h: &mut [f64; 12]) -> usize {
+    let (mut ab, mut bc, mut ca) = ([0.0; 4], [0.0; 4], [0.0; 4]);
+    let ab_len = minor2(a, b, &mut ab);
+    let bc_len = minor2(b, c, &mut bc);
+    let ca_len = minor2(c, a, &mut ca);
+    let mut t = [0.0; 8];
+    let t_len = expansion_sum(&ab[..ab_len], &bc[..bc_len], &mut t);
+    expansion_sum(&t[..t_len], &ca[..ca_len], h)
+}
+
+type Point3 = (f64, f64, f64);
+
+/// Exact `e0 * s0 + e1 * s1 + e2 * s2` for expansions of at most 4 components.
+fn scale_sum3(terms: [(&[f64], f64); 3], h: &mut [f64; 24]) -> usize {
+    let mut scaled = [[0.0; 8]; 3];
+    let mut lens = [0; 3];
+    for (k, (e, s)) in terms.iter().enumerate() {
+        lens[k] = expansion_scale(e, *s, &mut scaled[k]);
+    }
+    let mut t = [0.0; 16];
+    let t_len = expansion_sum(&scaled[0][..lens[0]], &scaled[1][..lens[1]], &mut t);
+    expansion_sum(&t[..t_len], &scaled[2][..lens[2]], h)
+}
+
+/// Exact `s * det([[ax, ay, az], [bx, by, bz], [cx, cy, cz]])` where `s` is `1` or `-1`.
+fn det3_xyz_exact(a: Point3, b: Point3, c: Point3, s: f64, h: &mut [f64; 24]) -> usize {
+    let xy = |p: Point3| (p.0, p.1);
+    let (mut ab, mut bc, mut ac) = ([0.0; 4], [0.0; 4], [0.0; 4]);
+    let ab_len = minor2(xy(a), xy(b), &mut ab);
+    let bc_len = minor2(xy(b), xy(c), &mut bc);
+    let ac_len = minor2(xy(a), xy(c), &mut ac);
+    scale_sum3(
+        [
+            (&bc[..bc_len], s * a.2),
+            (&ac[..ac_len], -s * b.2),
+            (&ab[..ab_len], s * c.2),
+        ],
+        h,
+    )
+}
+
+/// Exact determinant of the 4x4 matrix which rows are `[x, y, z, 1]` for each point.
+fn det3_exact(a: Point3, b: Point3, c: Point3, d: Point3, h: &mut [f64; 96]) -> usize {
+    let mut terms = [[0.0; 24]; 4];
+    let l0 = det3_xyz_exact(b, c, d, -1.0, &mut terms[0]);
+    let l1 = det3_xyz_exact(a, c, d, 1.0, &mut terms[1]);
+    let l2 = det3_xyz_exact(a, b, d, -1.0, &mut terms[2]);
+    let l3 = det3_xyz_exact(a, b, c, 1.0, &mut terms[3]);
+    let (mut s, mut t) = ([0.0; 48], [0.0; 48]);
+    let s_len = expansion_sum(&terms[0][..l0], &terms[1][..l1], &mut s);
+    let t_len = expansion_sum(&terms[2][..l2], &terms[3][..l3], &mut t);
+    expansion_sum(&s[..s_len], &t[..t_len], h)
+}
+
+/// Multiply the expansion by `c * c` exactly.
+fn scale_square(e: &[f64], c: f64, tmp: &mut [f64], h: &mut [f64]) -> usize {
+    let len = expansion_scale(e, c, tmp);
+    expansion_scale(&tmp[..len], c, h)
+}
+
+/// Orientation of the triangle `abc`.
+///
+/// Returns a positive value if the points are in counterclockwise order,
+/// a negative value if they are in clockwise order and zero if they are collinear.
+/// The result is an approximation of twice the signed area of the triangle.
+pub fn orient2d(a: Vector<f64, 2>, b: Vector<f64, 2>, c: Vector<f64, 2>) -> f64 {
+    let detleft = (a.x() - c.x()) * (b.y() - c.y());
+    let detright = (a.y() - c.y()) * (b.x() - c.x());
+    let det = detleft - detright;
+    let detsum = if detleft > 0.0 && detright > 0.0 {
+        detleft + detright
+    } else if detleft < 0.0 && detright < 0.0 {
+        -detleft - detright
+    } else {
+        // Terms have different signs, so the result is computed with small relative error.
+        return det;
+    };
+    if det.abs() >= CCW_ERRBOUND * detsum {
+        return det;
+    }
+    orient2d_exact(a, b, c)
+}
+
+#[cold]
+#[inline(never)]
+fn orient2d_exact(a: Vector<f64, 2>, b: Vector<f64, 2>, c: Vector<f64, 2>) -> f64 {
+    let mut h = [0.0; 12];
+    let len = det2_exact((a.x(), a.y()), (b.x(), b.y()), (c.x(), c.y()), &mut h);
+    estimate(&h[..len])
+}
+
+/// Orientation of the tetrahedron `abcd`.
+///
+/// Returns a positive value if the point `d` lies below the plane passing through `a`, `b` and `c`,
+/// where "below" means that `a`, `b` and `c` appear in counterclockwise order when viewed from above the plane.
+/// Returns a negative value if `d` lies above the plane and zero if the points are coplanar.
+/// The result is an approximation of six times the signed volume of the tetrahedron.
+pub fn orient3d(a: Vector<f64, 3>, b: Vector<f64, 3>, c: Vector<f64, 3>, d: Vector<f64, 3>) -> f64 {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let bdxcdy = bd.x() * cd.y();
+    let cdxbdy = cd.x() * bd.y();
+    let cdxady = cd.x() * ad.y();
+    let adxcdy = ad.x() * cd.y();
+    let adxbdy = ad.x() * bd.y();
+    let bdxady = bd.x() * ad.y();
+    let det = ad.z() * (bdxcdy - cdxbdy) + bd.z() * (cdxady - adxcdy) + cd.z() * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * ad.z().abs()
+        + (cdxady.abs() + adxcdy.abs()) * bd.z().abs()
+        + (adxbdy.abs() + bdxady.abs()) * cd.z().abs();
+    if det.abs() >= O3D_ERRBOUND * permanent {
+        return det;
+    }
+    orient3d_exact(a, b, c, d)
+}
+
+#[cold]
+#[inline(never)]
+fn orient3d_exact(
+    a: Vector<f64, 3>,
+    b: Vector<f64, 3>,
+    c: Vector<f64, 3>,
+    d: Vector<f64, 3>,
+) -> f64 {
+    let p = |v: Vector<f64, 3>| (v.x(), v.y(), v.z());
+    let mut h = [0.0; 96];
+    let len = det3_exact(p(a), p(b), p(c), p(d), &mut h);
+    estimate(&h[..len])
+}
+
+/// Location of the point `d` relative to the circle passing through `a`, `b` and `c`.
+///
+/// Returns a positive value if `d` lies inside the circle, a negative value if it lies outside
+/// and zero if the four points are cocircular.
+/// The points `a`, `b` and `c` must be in counterclockwise order, otherwise the sign of the result is reversed.
+pub fn incircle(a: Vector<f64, 2>, b: Vector<f64, 2>, c: Vector<f64, 2>, d: Vector<f64, 2>) -> f64 {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let bdxcdy = bd.x() * cd.y();
+    let cdxbdy = cd.x() * bd.y();
+    let alift = ad.x() * ad.x() + ad.y() * ad.y();
+    let cdxady = cd.x() * ad.y();
+    let adxcdy = ad.x() * cd.y();
+    let blift = bd.x() * bd.x() + bd.y() * bd.y();
+    let adxbdy = ad.x() * bd.y();
+    let bdxady = bd.x() * ad.y();
+    let clift = cd.x() * cd.x() + cd.y() * cd.y();
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if det.abs() >= ICC_ERRBOUND * permanent {
+        return det;
+    }
+    incircle_adapt(a, b, c, d, permanent)
+}
+
+/// Refinement of `incircle` for nearly cocircular points.
+///
+/// First the determinant of the rounded differences is computed exactly,
+/// then the first-order correction for the rounding errors of the differences is added.
+/// Only if both stages fail the exact determinant of the original coordinates is computed.
+#[cold]
+#[inline(never)]
+fn incircle_adapt(
+    a: Vector<f64, 2>,
+    b: Vector<f64, 2>,
+    c: Vector<f64, 2>,
+    d: Vector<f64, 2>,
+    permanent: f64,
+) -> f64 {
+    let (adx, adxtail) = two_diff(a.x(), d.x());
+    let (ady, adytail) = two_diff(a.y(), d.y());
+    let (bdx, bdxtail) = two_diff(b.x(), d.x());
+    let (bdy, bdytail) = two_diff(b.y(), d.y());
+    let (cdx, cdxtail) = two_diff(c.x(), d.x());
+    let (cdy, cdytail) = two_diff(c.y(), d.y());
+
+    let mut dets = [[0.0; 32]; 3];
+    let mut lens = [0; 3];
+    let rows = [
+        ((adx, ady), (bdx, bdy), (cdx, cdy)),
+        ((bdx, bdy), (cdx, cdy), (adx, ady)),
+        ((cdx, cdy), (adx, ady), (bdx, bdy)),
+    ];
+    for (k, &((x, y), p, q)) in rows.iter().enumerate() {
+        let mut m = [0.0; 4];
+        let m_len = minor2(p, q, &mut m);
+        let (mut tmp, mut mx, mut my) = ([0.0; 8], [0.0; 16], [0.0; 16]);
+        let mx_len = scale_square(&m[..m_len], x, &mut tmp, &mut mx);
+        let my_len = scale_square(&m[..m_len], y, &mut tmp, &mut my);
+        lens[k] = expansion_sum(&mx[..mx_len], &my[..my_len], &mut dets[k]);
+    }
+    let mut ab = [0.0; 64];
+    let ab_len = expansion_sum(&dets[0][..lens[0]], &dets[1][..lens[1]], &mut ab);
+    let mut fin = [0.0; 96];
+    let fin_len = expansion_sum(&ab[..ab_len], &dets[2][..lens[2]], &mut fin);
+    let mut det = estimate(&fin[..fin_len]);
+    if det.abs() >= ICC_ERRBOUND_B * permanent {
+        return det;
+    }
+    let tails = [adxtail, adytail, bdxtail, bdytail, cdxtail, cdytail];
+    if tails.iter().all(|t| *t == 0.0) {
+        // Differences are exact, so is the determinant.
+        return det;
+    }
+
+    let errbound = ICC_ERRBOUND_C * permanent + RESULT_ERRBOUND * det.abs();
+    det += ((adx * adx + ady * ady)
+        * ((bdx * cdytail + cdy * bdxtail) - (bdy * cdxtail + cdx * bdytail))
+        + 2.0 * (adx * adxtail + ady * adytail) * (bdx * cdy - bdy * cdx))
+        + ((bdx * bdx + bdy * bdy)
+            * ((cdx * adytail + ady * cdxtail) - (cdy * adxtail + adx * cdytail))
+            + 2.0 * (bdx * bdxtail + bdy * bdytail) * (cdx * ady - cdy * adx))
+        + ((cdx * cdx + cdy * cdy)
+            * ((adx * bdytail + bdy * adxtail) - (ady * bdxtail + bdx * adytail))
+            + 2.0 * (cdx * cdxtail + cdy * cdytail) * (adx * bdy - ady * bdx));
+    if det.abs() >= errbound {
+        return det;
+    }
+    incircle_exact(a, b, c, d)
+}
+
+#[cold]
+#[inline(never)]
+fn incircle_exact(
+    a: Vector<f64, 2>,
+    b: Vector<f64, 2>,
+    c: Vector<f64, 2>,
+    d: Vector<f64, 2>,
+) -> f64 {
+    // Exact determinant of the matrix with rows `[x, y, x^2 + y^2, 1]`, expanded along the lifted column.
+    let pts = [
+        (a.x(), a.y()),
+        (b.x(), b.y()),
+        (c.x(), c.y()),
+        (d.x(), d.y()),
+    ];
+    let (mut buf0, mut buf1) = ([0.0; 384], [0.0; 384]);
+    let (mut acc, mut next) = (&mut buf0, &mut buf1);
+    let mut acc_len = 0;
+    for (i, &(x, y)) in pts.iter().enumerate() {
+        let mut others = pts
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, p)| *p);
+        let (p, q, r) = (
+            others.next().unwrap(),
+            others.next().unwrap(),
+            others.next().unwrap(),
+        );
+        let mut m = [0.0; 12];
+        let m_len = det2_exact(p, q, r, &mut m);
+        if i % 2 == 1 {
+            m[..m_len].iter_mut().for_each(|v| *v = -*v);
+        }
+        let (mut tmp, mut mx, mut my) = ([0.0; 24], [0.0; 48], [0.0; 48]);
+        let mx_len = scale_square(&m[..m_len], x, &mut tmp, &mut mx);
+        let my_len = scale_square(&m[..m_len], y, &mut tmp, &mut my);
+        let mut term = [0.0; 96];
+        let term_len = expansion_sum(&mx[..mx_len], &my[..my_len], &mut term);
+        acc_len = expansion_sum(&acc[..acc_len], &term[..term_len], next);
+        core::mem::swap(&mut acc, &mut next);
+    }
+    estimate(&acc[..acc_len])
+}
+
+/// Location of the point `e` relative to the sphere passing through `a`, `b`, `c` and `d`.
+///
+/// Returns a positive value if `e` lies inside the sphere, a negative value if it lies outside
+/// and zero if the five points are cospherical.
+/// The points `a`, `b`, `c` and `d` must have positive orientation in terms of [`orient3d`],
+/// otherwise the sign of the result is reversed.
+pub fn insphere(
+    a: Vector<f64, 3>,
+    b: Vector<f64, 3>,
+    c: Vector<f64, 3>,
+    d: Vector<f64, 3>,
+    e: Vector<f64, 3>,
+) -> f64 {
+    let (ae, be, ce, de) = (a - e, b - e, c - e, d - e);
+    let aexbey = ae.x() * be.y();
+    let bexaey = be.x() * ae.y();
+    let ab = aexbey - bexaey;
+    let bexcey = be.x() * ce.y();
+    let cexbey = ce.x() * be.y();
+    let bc = bexcey - cexbey;
+    let cexdey = ce.x() * de.y();
+    let dexcey = de.x() * ce.y();
+    let cd = cexdey - dexcey;
+    let dexaey = de.x() * ae.y();
+    let aexdey = ae.x() * de.y();
+    let da = dexaey - aexdey;
+    let aexcey = ae.x() * ce.y();
+    let cexaey = ce.x() * ae.y();
+    let ac = aexcey - cexaey;
+    let bexdey = be.x() * de.y();
+    let dexbey = de.x() * be.y();
+    let bd = bexdey - dexbey;
+
+    let abc = ae.z() * bc - be.z() * ac + ce.z() * ab;
+    let bcd = be.z() * cd - ce.z() * bd + de.z() * bc;
+    let cda = ce.z() * da + de.z() * ac + ae.z() * cd;
+    let dab = de.z() * ab + ae.z() * bd + be.z() * da;
+
+    let alift = ae.square_length();
+    let blift = be.square_length();
+    let clift = ce.square_length();
+    let dlift = de.square_length();
+
+    let det = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+
+    let (aez, bez, cez, dez) = (ae.z().abs(), be.z().abs(), ce.z().abs(), de.z().abs());
+    let ab_plus = aexbey.abs() + bexaey.abs();
+    let bc_plus = bexcey.abs() + cexbey.abs();
+    let cd_plus = cexdey.abs() + dexcey.abs();
+    let da_plus = dexaey.abs() + aexdey.abs();
+    let ac_plus = aexcey.abs() + cexaey.abs();
+    let bd_plus = bexdey.abs() + dexbey.abs();
+    let permanent = (cd_plus * bez + bd_plus * cez + bc_plus * dez) * alift
+        + (da_plus * cez + ac_plus * dez + cd_plus * aez) * blift
+        + (ab_plus * dez + bd_plus * aez + da_plus * bez) * clift
+        + (bc_plus * aez + ac_plus * bez + ab_plus * cez) * dlift;
+    if det.abs() >= ISP_ERRBOUND * permanent {
+        return det;
+    }
+    insphere_adapt(a, b, c, d, e, permanent)
+}
+
+/// Exact `sign * (x^2 + y^2 + z^2) * e` for the expansion of at most 24 components.
+fn lift_scale(e: &[f64], (x, y, z): Point3, sign: f64, h: &mut [f64; 288]) -> usize {
+    let mut tmp = [0.0; 48];
+    let mut sq = [[0.0; 96]; 3];
+    let mut lens = [0; 3];
+    for (k, c) in [x, y, z].iter().enumerate() {
+        let len = expansion_scale(e, sign * c, &mut tmp);
+        lens[k] = expansion_scale(&tmp[..len], *c, &mut sq[k]);
+    }
+    let mut xy = [0.0; 192];
+    let xy_len = expansion_sum(&sq[0][..lens[0]], &sq[1][..lens[1]], &mut xy);
+    expansion_sum(&xy[..xy_len], &sq[2][..lens[2]], h)
+}
+
+/// Refinement of `insphere` for nearly cospherical points, see `incircle_adapt`.
+#[cold]
+#[inline(never)]
+fn insphere_adapt(
+    a: Vector<f64, 3>,
+    b: Vector<f64, 3>,
+    c: Vector<f64, 3>,
+    d: Vector<f64, 3>,
+    e: Vector<f64, 3>,
+    permanent: f64,
+) -> f64 {
+    let (aex, aextail) = two_diff(a.x(), e.x());
+    let (aey, aeytail) = two_diff(a.y(), e.y());
+    let (aez, aeztail) = two_diff(a.z(), e.z());
+    let (bex, bextail) = two_diff(b.x(), e.x());
+    let (bey, beytail) = two_diff(b.y(), e.y());
+    let (bez, beztail) = two_diff(b.z(), e.z());
+    let (cex, cextail) = two_diff(c.x(), e.x());
+    let (cey, ceytail) = two_diff(c.y(), e.y());
+    let (cez, ceztail) = two_diff(c.z(), e.z());
+    let (dex, dextail) = two_diff(d.x(), e.x());
+    let (dey, deytail) = two_diff(d.y(), e.y());
+    let (dez, deztail) = two_diff(d.z(), e.z());
+
+    let mut minors = [[0.0; 4]; 6];
+    let mut lens = [0; 6];
+    let pairs = [
+        ((aex, aey), (bex, bey)),
+        ((bex, bey), (cex, cey)),
+        ((cex, cey), (dex, dey)),
+        ((dex, dey), (aex, aey)),
+        ((aex, aey), (cex, cey)),
+        ((bex, bey), (dex, dey)),
+    ];
+    for (k, &(p, q)) in pairs.iter().enumerate() {
+        lens[k] = minor2(p, q, &mut minors[k]);
+    }
+    let [ab, bc, cd, da, ac, bd] = [0, 1, 2, 3, 4, 5].map(|k| &minors[k][..lens[k]]);
+
+    let (mut abc, mut bcd, mut cda, mut dab) = ([0.0; 24], [0.0; 24], [0.0; 24], [0.0; 24]);
+    let abc_len = scale_sum3([(bc, aez), (ac, -bez), (ab, cez)], &mut abc);
+    let bcd_len = scale_sum3([(cd, bez), (bd, -cez), (bc, dez)], &mut bcd);
+    let cda_len = scale_sum3([(da, cez), (ac, dez), (cd, aez)], &mut cda);
+    let dab_len = scale_sum3([(ab, dez), (bd, aez), (da, bez)], &mut dab);
+
+    let (mut buf0, mut buf1) = ([0.0; 1152], [0.0; 1152]);
+    let (mut acc, mut next) = (&mut buf0, &mut buf1);
+    let mut acc_len = 0;
+    let terms = [
+        (&abc[..abc_len], (dex, dey, dez), 1.0),
+        (&dab[..dab_len], (cex, cey, cez), -1.0),
+        (&cda[..cda_len], (bex, bey, bez), 1.0),
+        (&bcd[..bcd_len], (aex, aey, aez), -1.0),
+    ];
+    for &(m, p, sign) in terms.iter() {
+        let mut term = [0.0; 288];
+        let term_len = lift_scale(m, p, sign, &mut term);
+        acc_len = expansion_sum(&acc[..acc_len], &term[..term_len], next);
+        core::mem::swap(&mut acc, &mut next);
+    }
+    let mut det = estimate(&acc[..acc_len]);
+    if det.abs() >= ISP_ERRBOUND_B * permanent {
+        return det;
+    }
+    let tails = [
+        aextail, aeytail, aeztail, bextail, beytail, beztail, cextail, ceytail, ceztail, dextail,
+        deytail, deztail,
+    ];
+    if tails.iter().all(|t| *t == 0.0) {
+        // Differences are exact, so is the determinant.
+        return det;
+    }
+
+    let [ab3, bc3, cd3, da3, ac3, bd3] = [ab, bc, cd, da, ac, bd].map(estimate);
+    let abeps = (aex * beytail + bey * aextail) - (aey * bextail + bex * aeytail);
+    let bceps = (bex * ceytail + cey * bextail) - (bey * cextail + cex * beytail);
+    let cdeps = (cex * deytail + dey * cextail) - (cey * dextail + dex * ceytail);
+    let daeps = (dex * aeytail + aey * dextail) - (dey * aextail + aex * deytail);
+    let aceps = (aex * ceytail + cey * aextail) - (aey * cextail + cex * aeytail);
+    let bdeps = (bex * deytail + dey * bextail) - (bey * dextail + dex * beytail);
+    let errbound = ISP_ERRBOUND_C * permanent + RESULT_ERRBOUND * det.abs();
+    det += (((bex * bex + bey * bey + bez * bez)
+        * ((cez * daeps + dez * aceps + aez * cdeps)
+            + (ceztail * da3 + deztail * ac3 + aeztail * cd3))
+        + (dex * dex + dey * dey + dez * dez)
+            * ((aez * bceps - bez * aceps + cez * abeps)
+                + (aeztail * bc3 - beztail * ac3 + ceztail * ab3)))
+        - ((aex * aex + aey * aey + aez * aez)
+            * ((bez * cdeps - cez * bdeps + dez * bceps)
+                + (beztail * cd3 - ceztail * bd3 + deztail * bc3))
+            + (cex * cex + cey * cey + cez * cez)
+                * ((dez * abeps + aez * bdeps + bez * daeps)
+                    + (deztail * ab3 + aeztail * bd3 + beztail * da3))))
+        + 2.0
+            * (((bex * bextail + bey * beytail + bez * beztail)
+                * (cez * da3 + dez * ac3 + aez * cd3)
+                + (dex * dextail + dey * deytail + dez * deztail)
+                    * (aez * bc3 - bez * ac3 + cez * ab3))
+                - ((aex * aextail + aey * aeytail + aez * aeztail)
+                    * (bez * cd3 - cez * bd3 + dez * bc3)
+                    + (cex * cextail + cey * ceytail + cez * ceztail)
+                        * (dez * ab3 + aez * bd3 + bez * da3)));
+    if det.abs() >= errbound {
+        return det;
+    }
+    insphere_exact(a, b, c, d, e)
+}
+
+#[cold]
+#[inline(never)]
+fn insphere_exact(
+    a: Vector<f64, 3>,
+    b: Vector<f64, 3>,
+    c: Vector<f64, 3>,
+    d: Vector<f64, 3>,
+    e: Vector<f64, 3>,
+) -> f64 {
+    // Exact determinant of the matrix with rows `[x, y, z, x^2 + y^2 + z^2, 1]`, expanded along the lifted column.
+    let p = |v: Vector<f64, 3>| (v.x(), v.y(), v.z());
+    let pts = [p(a), p(b), p(c), p(d), p(e)];
+    let (mut buf0, mut buf1) = ([0.0; 5760], [0.0; 5760]);
+    let (mut acc, mut next) = (&mut buf0, &mut buf1);
+    let mut acc_len = 0;
+    for (i, &(x, y, z)) in pts.iter().enumerate() {
+        let mut others = pts
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, p)| *p);
+        let (p, q, r, s) = (
+            others.next().unwrap(),
+            others.next().unwrap(),
+            others.next().unwrap(),
+            others.next().unwrap(),
+        );
+        let mut m = [0.0; 96];
+        let m_len = det3_exact(p, q, r, s, &mut m);
+        if i % 2 == 0 {
+            m[..m_len].iter_mut().for_each(|v| *v = -*v);
+        }
+        let mut tmp = [0.0; 192];
+        let (mut mx, mut my, mut mz) = ([0.0; 384], [0.0; 384], [0.0; 384]);
+        let mx_len = scale_square(&m[..m_len], x, &mut tmp, &mut mx);
+        let my_len = scale_square(&m[..m_len], y, &mut tmp, &mut my);
+        let mz_len = scale_square(&m[..m_len], z, &mut tmp, &mut mz);
+        let mut mxy = [0.0; 768];
+        let mxy_len = expansion_sum(&mx[..mx_len], &my[..my_len], &mut mxy);
+        let mut term = [0.0; 1152];
+        let term_len = expansion_sum(&mxy[..mxy_len], &mz[..mz_len], &mut term);
+        acc_len = expansion_sum(&acc[..acc_len], &term[..term_len], next);
+        core::mem::swap(&mut acc, &mut next);
+    }
+    estimate(&acc[..acc_len])
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::Unit;
+    use core::f64::consts::PI;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    fn v2(x: f64, y: f64) -> Vector<f64, 2> {
+        Vector::from([x, y])
+    }
+    fn v3(x: f64, y: f64, z: f64) -> Vector<f64, 3> {
+        Vector::from([x, y, z])
+    }
+    fn sign(x: f64) -> i128 {
+        if x > 0.0 {
+            1
+        } else if x < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    fn det3(m: [[i128; 3]; 3]) -> i128 {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+    fn det4(m: [[i128; 4]; 4]) -> i128 {
+        (0..4)
+            .map(|j| {
+                let mut minor = [[0; 3]; 3];
+                for i in 0..3 {
+                    for (k, c) in (0..4).filter(|c| *c != j).enumerate() {
+                        minor[i][k] = m[i + 1][c];
+                    }
+                }
+                let s = if j % 2 == 0 { 1 } else { -1 };
+                s * m[0][j] * det3(minor)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn signs() {
+        let (a, b, c) = (v2(0.0, 0.0), v2(1.0, 0.0), v2(0.0, 1.0));
+        assert!(orient2d(a, b, c) > 0.0);
+        assert!(orient2d(a, c, b) < 0.0);
+        assert_eq!(orient2d(a, b, v2(3.0, 0.0)), 0.0);
+        assert!(incircle(a, b, c, v2(0.5, 0.5)) > 0.0);
+        assert!(incircle(a, b, c, v2(2.0, 2.0)) < 0.0);
+        assert_eq!(incircle(a, b, c, v2(1.0, 1.0)), 0.0);
+
+        let (a, b, c, d) = (
+            v3(0.0, 0.0, 0.0),
+            v3(1.0, 0.0, 0.0),
+            v3(0.0, 1.0, 0.0),
+            v3(0.0, 0.0, -1.0),
+        );
+        assert!(orient3d(a, b, c, d) > 0.0);
+        assert!(orient3d(a, b, c, -d) < 0.0);
+        assert_eq!(orient3d(a, b, c, v3(2.0, 3.0, 0.0)), 0.0);
+        assert!(insphere(a, b, c, d, v3(0.25, 0.25, -0.25)) > 0.0);
+        assert!(insphere(a, b, c, d, v3(2.0, 2.0, 2.0)) < 0.0);
+        assert_eq!(insphere(a, b, c, d, v3(1.0, 1.0, -1.0)), 0.0);
+    }
+
+    #[test]
+    fn orient2d_near_collinear() {
+        // Points on a grid near the line `y = x` with steps at the limit of `f64` precision.
+        let ulp = f64::EPSILON / 4.0;
+        let scale = 2.0f64.powi(54);
+        let (b, c) = (v2(12.0, 12.0), v2(24.0, 24.0));
+        for i in -32..32 {
+            for j in -32..32 {
+                let a = v2(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                let int = |x: f64| (x * scale) as i128;
+                let exact = (int(a.x()) - int(c.x())) * (int(b.y()) - int(c.y()))
+                    - (int(a.y()) - int(c.y())) * (int(b.x()) - int(c.x()));
+                assert_eq!(sign(orient2d(a, b, c)), exact.signum());
+            }
+        }
+    }
+
+    #[test]
+    fn orient3d_near_coplanar() {
+        let mut rng = XorShiftRng::seed_from_u64(0x0D3);
+        let int3 = |rng: &mut XorShiftRng, r: i64| {
+            [
+                rng.gen_range(-r..=r),
+                rng.gen_range(-r..=r),
+                rng.gen_range(-r..=r),
+            ]
+        };
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let base = int3(&mut rng, 1 << 29);
+            let (u, w) = (int3(&mut rng, 1 << 14), int3(&mut rng, 1 << 14));
+            let mut pts = [[0i64; 3]; 4];
+            for p in pts.iter_mut() {
+                let (s, t) = (
+                    rng.gen_range(-(1 << 14)..=(1 << 14)),
+                    rng.gen_range(-(1 << 14)..=(1 << 14)),
+                );
+                for k in 0..3 {
+                    p[k] = base[k] + s * u[k] + t * w[k];
+                }
+            }
+            pts[3][rng.gen_range(0..3)] += rng.gen_range(-1..=1);
+
+            let f = |p: [i64; 3]| v3(p[0] as f64, p[1] as f64, p[2] as f64);
+            let mut m = [[0; 3]; 3];
+            for i in 0..3 {
+                for k in 0..3 {
+                    m[i][k] = (pts[i][k] - pts[3][k]) as i128;
+                }
+            }
+            let (a, b, c, d) = (f(pts[0]), f(pts[1]), f(pts[2]), f(pts[3]));
+            assert_eq!(sign(orient3d(a, b, c, d)), det3(m).signum());
+            assert_eq!(sign(orient3d_exact(a, b, c, d)), det3(m).signum());
+        }
+    }
+
+    #[test]
+    fn incircle_near_cocircular() {
+        // Lattice points on the circle of radius `sqrt(5 * 13 * 17 * 29 * 37 * 41)`
+        // obtained as products of Gaussian integers and their conjugates.
+        let factors = [(2, 1), (3, 2), (4, 1), (5, 2), (6, 1), (5, 4)];
+        let mut circle = [[0i64; 2]; 64];
+        for (mask, p) in circle.iter_mut().enumerate() {
+            let (mut x, mut y) = (1i64, 0i64);
+            for (k, &(a, b)) in factors.iter().enumerate() {
+                let b = if mask & (1 << k) != 0 { -b } else { b };
+                let (nx, ny) = (x * a - y * b, x * b + y * a);
+                x = nx;
+                y = ny;
+            }
+            *p = [x, y];
+        }
+
+        let mut rng = XorShiftRng::seed_from_u64(0x1CC);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let offset = [
+                rng.gen_range(-(1 << 20)..=(1 << 20)),
+                rng.gen_range(-(1 << 20)..=(1 << 20)),
+            ];
+            let mut pts = [[0i64; 2]; 4];
+            for p in pts.iter_mut() {
+                let q = circle[rng.gen_range(0..circle.len())];
+                *p = [q[0] + offset[0], q[1] + offset[1]];
+            }
+            pts[3][rng.gen_range(0..2)] += rng.gen_range(-1..=1);
+
+            let f = |p: [i64; 2]| v2(p[0] as f64, p[1] as f64);
+            let mut m = [[0; 3]; 3];
+            for i in 0..3 {
+                let (dx, dy) = (
+                    (pts[i][0] - pts[3][0]) as i128,
+                    (pts[i][1] - pts[3][1]) as i128,
+                );
+                m[i] = [dx, dy, dx * dx + dy * dy];
+            }
+            let (a, b, c, d) = (f(pts[0]), f(pts[1]), f(pts[2]), f(pts[3]));
+            assert_eq!(sign(incircle(a, b, c, d)), det3(m).signum());
+            assert_eq!(sign(incircle_exact(a, b, c, d)), det3(m).signum());
+        }
+    }
+
+    #[test]
+    fn insphere_near_cospherical() {
+        // Lattice points on the sphere `x^2 + y^2 + z^2 = 2450`.
+        const R2: i64 = 2450;
+        let mut sphere = [[0i64; 3]; 1024];
+        let mut n = 0;
+        for x in -50..=50i64 {
+            for y in -50..=50i64 {
+                let z2 = R2 - x * x - y * y;
+                if z2 < 0 {
+                    continue;
+                }
+                let z = (z2 as f64).sqrt() as i64;
+                if z * z == z2 {
+                    sphere[n] = [x, y, z];
+                    n += 1;
+                    if z != 0 {
+                        sphere[n] = [x, y, -z];
+                        n += 1;
+                    }
+                }
+            }
+        }
+        let sphere = &sphere[..n];
+
+        let mut rng = XorShiftRng::seed_from_u64(0x15F);
+        let scale = (1 << 15) + 1;
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let mut pts = [[0i64; 3]; 5];
+            for p in pts.iter_mut() {
+                let q = sphere[rng.gen_range(0..sphere.len())];
+                *p = [q[0] * scale, q[1] * scale, q[2] * scale];
+            }
+            pts[4][rng.gen_range(0..3)] += rng.gen_range(-1..=1);
+
+            let f = |p: [i64; 3]| v3(p[0] as f64, p[1] as f64, p[2] as f64);
+            let mut m = [[0; 4]; 4];
+            for i in 0..4 {
+                let mut d = [0; 3];
+                for k in 0..3 {
+                    d[k] = (pts[i][k] - pts[4][k]) as i128;
+                }
+                m[i] = [d[0], d[1], d[2], d[0] * d[0] + d[1] * d[1] + d[2] * d[2]];
+            }
+            let (a, b, c, d, e) = (f(pts[0]), f(pts[1]), f(pts[2]), f(pts[3]), f(pts[4]));
+            assert_eq!(sign(insphere(a, b, c, d, e)), det4(m).signum());
+            assert_eq!(sign(insphere_exact(a, b, c, d, e)), det4(m).signum());
+        }
+    }
+
+    #[test]
+    fn incircle_rounded_differences() {
+        // Nearly cocircular points with coordinates of different magnitudes, so that their differences are rounded.
+        let mut rng = XorShiftRng::seed_from_u64(0x1CD);
+        for k in 0..SAMPLE_ATTEMPTS {
+            let spread = 10.0f64.powi(-(k as i32 % 4) - 12);
+            let center = v2(rng.gen::<f64>() * 1e3, rng.gen::<f64>() * 1e-3);
+            let r = 10.0f64.powf(rng.gen_range(-3.0..3.0));
+            let mut pts = [center; 4];
+            for p in pts.iter_mut() {
+                let phi = 2.0 * PI * rng.gen::<f64>();
+                let dr = spread * rng.gen_range(-1.0..1.0);
+                *p = center + v2(phi.cos(), phi.sin()) * (r * (1.0 + dr));
+            }
+            let (a, b, c, d) = (pts[0], pts[1], pts[2], pts[3]);
+            assert_eq!(sign(incircle(a, b, c, d)), sign(incircle_exact(a, b, c, d)));
+        }
+    }
+
+    #[test]
+    fn insphere_rounded_differences() {
+        let mut rng = XorShiftRng::seed_from_u64(0x15E);
+        for k in 0..SAMPLE_ATTEMPTS {
+            let spread = 10.0f64.powi(-(k as i32 % 4) - 12);
+            let center = v3(
+                rng.gen::<f64>() * 1e3,
+                rng.gen::<f64>() * 1e-3,
+                rng.gen::<f64>(),
+            );
+            let r = 10.0f64.powf(rng.gen_range(-3.0..3.0));
+            let mut pts = [center; 5];
+            for p in pts.iter_mut() {
+                let dr = spread * rng.gen_range(-1.0..1.0);
+                *p = center + rng.sample::<Vector<f64, 3>, _>(&Unit) * (r * (1.0 + dr));
+            }
+            let (a, b, c, d, e) = (pts[0], pts[1], pts[2], pts[3], pts[4]);
+            assert_eq!(
+                sign(insphere(a, b, c, d, e)),
+                sign(insphere_exact(a, b, c, d, e))
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn small_stack() {
+        // Exact arithmetic buffers must not be reserved when the floating-point filter succeeds.
+        std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let (a, b, c, d) = (
+                    v3(0.0, 0.0, 0.0),
+                    v3(1.0, 0.0, 0.0),
+                    v3(0.0, 1.0, 0.0),
+                    v3(0.0, 0.0, -1.0),
+                );
+                assert!(insphere(a, b, c, d, v3(0.25, 0.25, -0.25)) > 0.0);
+                assert!(incircle(v2(0.0, 0.0), v2(1.0, 0.0), v2(0.0, 1.0), v2(0.5, 0.5)) > 0.0);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+}
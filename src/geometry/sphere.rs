@@ -0,0 +1,410 @@
+use crate::{geometry::Aabb, traits::Dot, transform::Jacobian, Matrix, Vector};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::Float;
+
+/// Ball of the specified radius around the center.
+///
+/// Sphere is considered to be empty if its radius is negative.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sphere<T, const N: usize> {
+    center: Vector<T, N>,
+    radius: T,
+}
+
+pub type Sphere2<T> = Sphere<T, 2>;
+pub type Sphere3<T> = Sphere<T, 3>;
+
+impl<T, const N: usize> Sphere<T, N> {
+    pub const fn new(center: Vector<T, N>, radius: T) -> Self {
+        Self { center, radius }
+    }
+    pub fn into_center_radius(self) -> (Vector<T, N>, T) {
+        (self.center, self.radius)
+    }
+}
+
+impl<T: Copy, const N: usize> Sphere<T, N> {
+    pub fn center(&self) -> Vector<T, N> {
+        self.center
+    }
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+}
+
+impl<T: Float, const N: usize> Sphere<T, N> {
+    /// Empty sphere, identity element for `union`.
+    pub fn empty() -> Self {
+        Self::new(Vector::fill(T::zero()), T::neg_infinity())
+    }
+    /// Degenerate sphere containing the only point.
+    pub fn from_point(point: Vector<T, N>) -> Self {
+        Self::new(point, T::zero())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.radius < T::zero()
+    }
+
+    /// Check whether the point is inside the sphere or on its boundary.
+    pub fn contains(&self, point: Vector<T, N>) -> bool {
+        !self.is_empty() && (point - self.center).square_length() <= self.radius * self.radius
+    }
+    /// Check whether other sphere is completely inside this one.
+    pub fn contains_sphere(&self, other: &Self) -> bool {
+        other.is_empty()
+            || (!self.is_empty()
+                && (other.center - self.center).length() + other.radius <= self.radius)
+    }
+    /// Check whether spheres intersect (touching counts as intersection).
+    pub fn intersects(&self, other: &Self) -> bool {
+        let r = self.radius + other.radius;
+        !self.is_empty()
+            && !other.is_empty()
+            && (other.center - self.center).square_length() <= r * r
+    }
+    /// Check whether the sphere intersects the box.
+    pub fn intersects_aabb(&self, aabb: &Aabb<T, N>) -> bool {
+        !self.is_empty()
+            && !aabb.is_empty()
+            && aabb.square_distance(self.center) <= self.radius * self.radius
+    }
+
+    /// Point of the sphere closest to the specified one.
+    pub fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+        let d = point - self.center;
+        let len = d.length();
+        if len <= self.radius {
+            point
+        } else {
+            self.center + d * (self.radius / len)
+        }
+    }
+    /// Distance from the point to the sphere, zero if the point is inside.
+    pub fn distance(&self, point: Vector<T, N>) -> T {
+        ((point - self.center).length() - self.radius).max(T::zero())
+    }
+
+    /// Bounding box of the sphere.
+    pub fn aabb(&self) -> Aabb<T, N> {
+        if self.is_empty() {
+            Aabb::empty()
+        } else {
+            Aabb::from_center(self.center, Vector::fill(self.radius))
+        }
+    }
+    /// Smallest sphere containing both spheres.
+    pub fn union(&self, other: &Self) -> Self {
+        if self.contains_sphere(other) {
+            return *self;
+        }
+        if other.contains_sphere(self) {
+            return *other;
+        }
+        let d = other.center - self.center;
+        let len = d.length();
+        let radius = (len + self.radius + other.radius) / (T::one() + T::one());
+        Self::new(self.center + d * ((radius - self.radius) / len), radius)
+    }
+
+    /// Minimal bounding sphere of the points, empty if there are no points.
+    ///
+    /// Welzl's algorithm in its move-to-front form is used,
+    /// so the points are reordered in place and no allocation is needed.
+    /// Expected running time is linear if the points are in random order.
+    pub fn bounding(points: &mut [Vector<T, N>]) -> Self {
+        let mut support = Support::new();
+        welzl(points, points.len(), &mut support)
+    }
+
+    /// Sphere containing the transformed sphere.
+    ///
+    /// Radius is scaled by an upper bound of the stretch factor of the Jacobian at the center,
+    /// so the result contains the image for affine transformations and is exact for similarities
+    /// (shifts, rotations, uniform scales and their compositions).
+    /// For other transformations it is a linear approximation at the center of the sphere.
+    pub fn transform<M>(&self, map: &M) -> Self
+    where
+        M: Jacobian<Vector<T, N>, Output = Matrix<T, N, N>>,
+    {
+        if self.is_empty() {
+            return *self;
+        }
+        let scale = max_stretch(map.jacobian(self.center));
+        Self::new(map.apply(self.center), self.radius * scale)
+    }
+}
+
+/// Upper bound of `|j x| / |x|`, i.e. of the spectral norm of `j`.
+///
+/// The largest eigenvalue of `jᵀ j` is bounded by Gershgorin circles,
+/// so the bound is exact when `j` is a scaled orthogonal matrix.
+pub(crate) fn max_stretch<T: Float, const N: usize>(j: Matrix<T, N, N>) -> T {
+    let gram = j.transpose().dot(j);
+    gram.abs()
+        .into_vector_of_vectors()
+        .map(|row| row.sum())
+        .max()
+        .sqrt()
+}
+
+/// Up to `N + 1` points lying on the boundary of the sphere.
+struct Support<T, const N: usize> {
+    origin: Vector<T, N>,
+    /// Offsets of the remaining points relative to `origin`.
+    diffs: Vector<Vector<T, N>, N>,
+    len: usize,
+}
+
+impl<T: Float, const N: usize> Support<T, N> {
+    fn new() -> Self {
+        Self {
+            origin: Vector::fill(T::zero()),
+            diffs: Vector::fill(Vector::fill(T::zero())),
+            len: 0,
+        }
+    }
+    fn is_full(&self) -> bool {
+        self.len > N
+    }
+    fn push(&mut self, point: Vector<T, N>) {
+        if self.len == 0 {
+            self.origin = point;
+        } else {
+            self.diffs[self.len - 1] = point - self.origin;
+        }
+        self.len += 1;
+    }
+    fn pop(&mut self) {
+        self.len -= 1;
+    }
+
+    /// Smallest sphere passing through all the support points.
+    ///
+    /// Its center `origin + sum(x_i * d_i)` lies in the affine hull of the points,
+    /// so `x` is the solution of the Gram system `G x = b`, where `G_ij = d_i · d_j` and `b_i = |d_i|^2 / 2`.
+    /// The system is solved by `LDLᵀ` decomposition, degenerate directions are skipped.
+    fn sphere(&self) -> Sphere<T, N> {
+        if self.len == 0 {
+            return Sphere::empty();
+        }
+        let k = self.len - 1;
+        let two = T::one() + T::one();
+        let tol = T::epsilon() * T::from(16).unwrap();
+        let d = &self.diffs;
+        let mut l = Matrix::<T, N, N>::fill(T::zero());
+        let mut diag = Vector::<T, N>::fill(T::zero());
+        for j in 0..k {
+            let gjj = d[j].square_length();
+            let mut dj = gjj;
+            for m in 0..j {
+                dj = dj - l[(j, m)] * l[(j, m)] * diag[m];
+            }
+            if dj <= tol * gjj {
+                continue;
+            }
+            diag[j] = dj;
+            for i in (j + 1)..k {
+                let mut lij = d[i].dot(d[j]);
+                for m in 0..j {
+                    lij = lij - l[(i, m)] * l[(j, m)] * diag[m];
+                }
+                l[(i, j)] = lij / dj;
+            }
+        }
+        let mut x = Vector::<T, N>::fill(T::zero());
+        for i in 0..k {
+            let mut y = d[i].square_length() / two;
+            for m in 0..i {
+                y = y - l[(i, m)] * x[m];
+            }
+            x[i] = y;
+        }
+        for i in 0..k {
+            x[i] = if diag[i] > T::zero() {
+                x[i] / diag[i]
+            } else {
+                T::zero()
+            };
+        }
+        for i in (0..k).rev() {
+            let mut y = x[i];
+            for m in (i + 1)..k {
+                y = y - l[(m, i)] * x[m];
+            }
+            x[i] = y;
+        }
+        let offset = (0..k).fold(Vector::fill(T::zero()), |a, i| a + d[i] * x[i]);
+        Sphere::new(self.origin + offset, offset.length())
+    }
+}
+
+fn welzl<T: Float, const N: usize>(
+    points: &mut [Vector<T, N>],
+    end: usize,
+    support: &mut Support<T, N>,
+) -> Sphere<T, N> {
+    let mut sphere = support.sphere();
+    if support.is_full() {
+        return sphere;
+    }
+    // Points lying on the boundary may fail exact containment check because of rounding errors.
+    let tol = T::one() + T::epsilon() * T::from(64).unwrap();
+    for i in 0..end {
+        let p = points[i];
+        let r = sphere.radius * tol;
+        if sphere.is_empty() || (p - sphere.center).square_length() > r * r {
+            support.push(p);
+            sphere = welzl(points, i, support);
+            support.pop();
+            points[..=i].rotate_right(1);
+        }
+    }
+    sphere
+}
+
+#[cfg(feature = "approx")]
+impl<T, const N: usize> AbsDiffEq for Sphere<T, N>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.center, other.center, epsilon = epsilon)
+            && abs_diff_eq!(self.radius, other.radius, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Invertible, Normal, Unit},
+        transform::{Affine3, Chain, Linear3, Map, Rotation3, Scale, Shift3},
+        Quaternion,
+    };
+    use approx::assert_abs_diff_eq;
+    use num_traits::Zero;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 64;
+    const EPS: f64 = 1e-9;
+
+    type V = Vector<f64, 3>;
+
+    #[test]
+    fn queries() {
+        let a = Sphere::new(Vector::from([0.0, 0.0]), 1.0);
+        let b = Sphere::new(Vector::from([3.0, 0.0]), 1.5);
+        assert!(a.contains(Vector::from([0.6, 0.8])));
+        assert!(!a.contains(Vector::from([0.7, 0.8])));
+        assert!(!a.intersects(&b));
+        assert!(a.intersects(&Sphere::new(Vector::from([3.0, 0.0]), 2.0)));
+        assert_abs_diff_eq!(a.distance(Vector::from([0.0, 3.0])), 2.0);
+        assert_abs_diff_eq!(
+            a.union(&b),
+            Sphere::new(Vector::from([1.75, 0.0]), 2.75),
+            epsilon = EPS
+        );
+        assert_abs_diff_eq!(Sphere2::empty().union(&a), a);
+        assert!(a.intersects_aabb(&Aabb::new(
+            Vector::from([0.5, 0.5]),
+            Vector::from([2.0, 2.0])
+        )));
+        assert!(!a.intersects_aabb(&Aabb::new(
+            Vector::from([0.8, 0.8]),
+            Vector::from([2.0, 2.0])
+        )));
+        assert!(Sphere2::<f64>::bounding(&mut []).is_empty());
+    }
+
+    #[test]
+    fn bounding() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5FE);
+        for k in 1..SAMPLE_ATTEMPTS {
+            let mut points = [V::fill(0.0); 64];
+            for p in points[..k].iter_mut() {
+                *p = rng.sample(&Normal);
+            }
+            let points = &mut points[..k];
+            let s = Sphere::bounding(points);
+            for p in points.iter() {
+                assert!((*p - s.center()).length() <= s.radius() + EPS);
+            }
+            // The problem is convex, so it is enough to check that the sphere is locally minimal.
+            for _ in 0..16 {
+                let d: V = rng.sample::<V, _>(&Unit) * 1e-3;
+                let r = points
+                    .iter()
+                    .map(|p| (*p - s.center() - d).length())
+                    .fold(0.0, f64::max);
+                assert!(r >= s.radius() - EPS);
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_degenerate() {
+        let mut points = [
+            Vector::from([0.0, 0.0]),
+            Vector::from([1.0, 0.0]),
+            Vector::from([2.0, 0.0]),
+            Vector::from([3.0, 0.0]),
+            Vector::from([2.0, 0.0]),
+        ];
+        assert_abs_diff_eq!(
+            Sphere::bounding(&mut points),
+            Sphere::new(Vector::from([1.5, 0.0]), 1.5),
+            epsilon = EPS
+        );
+    }
+
+    #[test]
+    fn transform() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5FF);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let s = Sphere::<f64, 3>::new(rng.sample(&Normal), 1.5);
+            let sim = Chain::new(
+                Shift3::from_vector(rng.sample(&Normal)),
+                Chain::new(
+                    Rotation3::from_quaternion(rng.sample::<Quaternion<f64>, _>(&Unit)),
+                    Scale::from_mag(2.5),
+                ),
+            );
+            let t = s.transform(&sim);
+            assert_abs_diff_eq!(t.radius(), 3.75, epsilon = EPS);
+            let p = s.center() + rng.sample::<V, _>(&Unit) * 1.5;
+            assert_abs_diff_eq!(
+                (sim.apply(p) - t.center()).length(),
+                t.radius(),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn transform_non_uniform() {
+        let s = Sphere::<f64, 3>::new(V::zero(), 1.0);
+        let lin = Linear3::from(Matrix::diagonal(Vector::from([4.0, 1.0, 1.0])));
+        assert_abs_diff_eq!(
+            s.transform(&lin),
+            Sphere::new(V::zero(), 4.0),
+            epsilon = EPS
+        );
+
+        let mut rng = XorShiftRng::seed_from_u64(0x600);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let s = Sphere::<f64, 3>::new(rng.sample(&Normal), 1.5);
+            let aff: Affine3<f64> = rng.sample(&Invertible);
+            let t = s.transform(&aff);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let p = s.center() + rng.sample::<V, _>(&Unit) * 1.5;
+                assert!((aff.apply(p) - t.center()).length() <= t.radius() + EPS);
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use super::{clamp_to_disk, clamp_to_half_plane, disk_to_half_plane, HalfPlane};
+use crate::complex::{Complex, Moebius};
+use num_traits::Float;
+
+/// Point of the hyperbolic plane in the Poincaré disk model.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PoincareDisk<T> {
+    pos: Complex<T>,
+}
+
+impl<T> PoincareDisk<T> {
+    /// Complex coordinate of the point.
+    pub fn into_complex(self) -> Complex<T> {
+        self.pos
+    }
+    /// Get complex coordinate reference.
+    pub fn as_complex(&self) -> &Complex<T> {
+        &self.pos
+    }
+}
+
+impl<T> PoincareDisk<T>
+where
+    T: Float,
+{
+    /// Construct point from complex coordinate if it lies inside the unit disk.
+    pub fn try_new(pos: Complex<T>) -> Option<Self> {
+        if pos.norm_sqr() < T::one() {
+            Some(Self { pos })
+        } else {
+            None
+        }
+    }
+    /// Construct point from complex coordinate.
+    ///
+    /// Panics if the coordinate is outside of the unit disk.
+    pub fn new(pos: Complex<T>) -> Self {
+        assert!(
+            pos.norm_sqr() < T::one(),
+            "Point is outside of the unit disk"
+        );
+        Self { pos }
+    }
+    /// Center of the disk.
+    pub fn origin() -> Self {
+        Self::new(Complex::new(T::zero(), T::zero()))
+    }
+
+    /// Hyperbolic distance between points.
+    pub fn distance(self, other: Self) -> T {
+        let (z, w) = (self.pos, other.pos);
+        let two = T::one() + T::one();
+        let den = ((T::one() - z.norm_sqr()) * (T::one() - w.norm_sqr())).sqrt();
+        two * ((z - w).norm() / den).asinh()
+    }
+
+    /// Point on the geodesic segment between `self` (`t = 0`) and `other` (`t = 1`).
+    ///
+    /// The distance from `self` to the resulting point is `t` times the distance between the endpoints.
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        let m = self.translation();
+        let a = m.inv().apply(other.pos);
+        let r = a.norm();
+        if r == T::zero() {
+            return self;
+        }
+        let u = a * ((r.atanh() * t).tanh() / r);
+        Self::new(clamp_to_disk(m.apply(u)))
+    }
+
+    /// Apply isometry of the disk to the point.
+    ///
+    /// Points pushed onto the boundary by rounding errors are moved back inside the disk.
+    pub fn transform(self, m: &Moebius<Complex<T>>) -> Self {
+        Self::new(clamp_to_disk(m.apply(self.pos)))
+    }
+
+    /// Hyperbolic translation that moves the origin to this point along the geodesic.
+    pub fn translation(self) -> Moebius<Complex<T>> {
+        let one = Complex::new(T::one(), T::zero());
+        Moebius::new(one, self.pos, self.pos.conj(), one).normalize_sl2()
+    }
+
+    /// Hyperbolic translation that moves this point to `other` along the geodesic.
+    pub fn translation_to(self, other: Self) -> Moebius<Complex<T>> {
+        let m = self.translation();
+        let a = Self::new(clamp_to_disk(m.inv().apply(other.pos)));
+        m.chain(a.translation()).chain(m.inv())
+    }
+
+    /// Rotation about this point by `angle`.
+    pub fn rotation(self, angle: T) -> Moebius<Complex<T>> {
+        let two = T::one() + T::one();
+        let (s, c) = (angle / two).sin_cos();
+        let r = Moebius::new(
+            Complex::new(c, s),
+            Complex::new(T::zero(), T::zero()),
+            Complex::new(T::zero(), T::zero()),
+            Complex::new(c, -s),
+        );
+        let m = self.translation();
+        m.chain(r).chain(m.inv())
+    }
+
+    /// Convert to the upper half-plane model.
+    pub fn to_half_plane(self) -> HalfPlane<T> {
+        HalfPlane::new(clamp_to_half_plane(disk_to_half_plane().apply(self.pos)))
+    }
+}
+
+impl<T> From<HalfPlane<T>> for PoincareDisk<T>
+where
+    T: Float,
+{
+    fn from(p: HalfPlane<T>) -> Self {
+        p.to_disk()
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::Normal;
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    fn point(rng: &mut XorShiftRng) -> PoincareDisk<f64> {
+        let z: Complex<f64> = rng.sample(&Normal);
+        PoincareDisk::new(z / (1.0 + z.norm()))
+    }
+
+    #[test]
+    fn distance() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B0);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q, c) = (point(&mut rng), point(&mut rng), point(&mut rng));
+            let d = p.distance(q);
+            assert_abs_diff_eq!(d, q.distance(p), epsilon = EPS);
+            assert_abs_diff_eq!(
+                PoincareDisk::origin().distance(p),
+                2.0 * p.into_complex().norm().atanh(),
+                epsilon = EPS
+            );
+
+            let m = c.translation().chain(c.rotation(rng.gen::<f64>() * 6.0));
+            assert_abs_diff_eq!(m.det(), Complex::new(1.0, 0.0), epsilon = EPS);
+            assert_abs_diff_eq!(p.transform(&m).distance(q.transform(&m)), d, epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn isometries() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B1);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q) = (point(&mut rng), point(&mut rng));
+            let o = PoincareDisk::origin();
+            assert_abs_diff_eq!(
+                o.transform(&p.translation()).into_complex(),
+                p.into_complex(),
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(
+                p.transform(&p.translation_to(q)).into_complex(),
+                q.into_complex(),
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(
+                p.transform(&q.rotation(1.0)).distance(q),
+                p.distance(q),
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(
+                q.transform(&q.rotation(1.0)).into_complex(),
+                q.into_complex(),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn lerp() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B2);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q) = (point(&mut rng), point(&mut rng));
+            let t = rng.gen::<f64>();
+            let d = p.distance(q);
+            let x = p.lerp(q, t);
+            assert_abs_diff_eq!(p.distance(x), t * d, epsilon = EPS);
+            assert_abs_diff_eq!(x.distance(q), (1.0 - t) * d, epsilon = EPS);
+            assert_abs_diff_eq!(
+                p.lerp(q, 1.0).into_complex(),
+                q.into_complex(),
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(p.lerp(p, t).into_complex(), p.into_complex(), epsilon = EPS);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use super::{clamp_to_disk, clamp_to_half_plane, half_plane_to_disk, HalfSpace, PoincareDisk};
+use crate::complex::{Complex, Moebius};
+use num_traits::Float;
+
+/// Point of the hyperbolic plane in the Poincaré upper half-plane model.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct HalfPlane<T> {
+    pos: Complex<T>,
+}
+
+impl<T> HalfPlane<T> {
+    /// Complex coordinate of the point.
+    pub fn into_complex(self) -> Complex<T> {
+        self.pos
+    }
+    /// Get complex coordinate reference.
+    pub fn as_complex(&self) -> &Complex<T> {
+        &self.pos
+    }
+}
+
+impl<T> HalfPlane<T>
+where
+    T: Float,
+{
+    /// Construct point from complex coordinate if it lies in the upper half-plane.
+    pub fn try_new(pos: Complex<T>) -> Option<Self> {
+        if pos.im() > T::zero() {
+            Some(Self { pos })
+        } else {
+            None
+        }
+    }
+    /// Construct point from complex coordinate.
+    ///
+    /// Panics if the imaginary part of the coordinate is not positive.
+    pub fn new(pos: Complex<T>) -> Self {
+        assert!(
+            pos.im() > T::zero(),
+            "Point is outside of the upper half-plane"
+        );
+        Self { pos }
+    }
+
+    /// Hyperbolic distance between points.
+    pub fn distance(self, other: Self) -> T {
+        let (z, w) = (self.pos, other.pos);
+        let two = T::one() + T::one();
+        two * ((z - w).norm() / (two * (z.im() * w.im()).sqrt())).asinh()
+    }
+
+    /// Point on the geodesic segment between `self` (`t = 0`) and `other` (`t = 1`).
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self.to_disk().lerp(other.to_disk(), t).to_half_plane()
+    }
+
+    /// Apply isometry of the half-plane (transformation with real coefficients) to the point.
+    ///
+    /// Points pushed onto the real axis by rounding errors are moved back into the half-plane.
+    pub fn transform(self, m: &Moebius<Complex<T>>) -> Self {
+        Self::new(clamp_to_half_plane(m.apply(self.pos)))
+    }
+
+    /// Hyperbolic translation that moves this point to `other` along the geodesic.
+    pub fn translation_to(self, other: Self) -> Moebius<Complex<T>> {
+        let c = half_plane_to_disk();
+        c.inv()
+            .chain(self.to_disk().translation_to(other.to_disk()))
+            .chain(c)
+    }
+
+    /// Rotation about this point by `angle`.
+    pub fn rotation(self, angle: T) -> Moebius<Complex<T>> {
+        let c = half_plane_to_disk();
+        c.inv().chain(self.to_disk().rotation(angle)).chain(c)
+    }
+
+    /// Convert to the Poincaré disk model.
+    ///
+    /// Points far from the origin that are rounded onto the unit circle are moved back inside the disk.
+    pub fn to_disk(self) -> PoincareDisk<T> {
+        PoincareDisk::new(clamp_to_disk(half_plane_to_disk().apply(self.pos)))
+    }
+
+    /// Embed into the upper half-space as its vertical section over the real axis.
+    pub fn to_half_space(self) -> HalfSpace<T> {
+        HalfSpace::new(Complex::new(self.pos.re(), T::zero()), self.pos.im())
+    }
+}
+
+impl<T> From<PoincareDisk<T>> for HalfPlane<T>
+where
+    T: Float,
+{
+    fn from(p: PoincareDisk<T>) -> Self {
+        p.to_half_plane()
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::Normal;
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    fn point(rng: &mut XorShiftRng) -> HalfPlane<f64> {
+        let z: Complex<f64> = rng.sample(&Normal);
+        HalfPlane::new(Complex::new(z.re(), z.im().exp()))
+    }
+
+    #[test]
+    fn distance() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B3);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q) = (point(&mut rng), point(&mut rng));
+            let d = p.distance(q);
+            assert_abs_diff_eq!(p.to_disk().distance(q.to_disk()), d, epsilon = EPS);
+            assert_abs_diff_eq!(
+                p.to_disk().to_half_plane().into_complex(),
+                p.into_complex(),
+                epsilon = EPS
+            );
+
+            let v = HalfPlane::new(Complex::new(p.into_complex().re(), q.into_complex().im()));
+            assert_abs_diff_eq!(
+                p.distance(v),
+                (p.into_complex().im() / v.into_complex().im()).ln().abs(),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn isometries() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B4);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q, c) = (point(&mut rng), point(&mut rng), point(&mut rng));
+            let t = p.translation_to(q);
+            assert_abs_diff_eq!(t.det(), Complex::new(1.0, 0.0), epsilon = EPS);
+            for x in t.into_array().iter().flatten() {
+                assert_abs_diff_eq!(x.im(), 0.0, epsilon = EPS);
+            }
+            assert_abs_diff_eq!(
+                p.transform(&t).into_complex(),
+                q.into_complex(),
+                epsilon = EPS
+            );
+            let r = c.rotation(rng.gen::<f64>() * 6.0);
+            assert_abs_diff_eq!(
+                p.transform(&r).distance(q.transform(&r)),
+                p.distance(q),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn lerp() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B5);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q) = (point(&mut rng), point(&mut rng));
+            let t = rng.gen::<f64>();
+            let x = p.lerp(q, t);
+            assert_abs_diff_eq!(p.distance(x), t * p.distance(q), epsilon = EPS);
+            assert_abs_diff_eq!(
+                x.to_disk().into_complex(),
+                p.to_disk().lerp(q.to_disk(), t).into_complex(),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn boundary() {
+        let far = [
+            Complex::new(1e9, 1.0),
+            Complex::new(0.0, 1e-20),
+            Complex::new(0.0, 1e20),
+            Complex::new(-1e12, 1e-12),
+        ];
+        let o = HalfPlane::new(Complex::new(0.0, 1.0));
+        for z in far.iter() {
+            let p = HalfPlane::new(*z);
+            let d = p.to_disk();
+            assert!(d.as_complex().norm_sqr() < 1.0);
+            assert!(d.to_half_plane().as_complex().im() > 0.0);
+            assert!(p.distance(o).is_finite());
+
+            let x = p.lerp(o, 0.5);
+            assert!(x.as_complex().im() > 0.0);
+            assert!(d.lerp(PoincareDisk::origin(), 0.0).as_complex().norm_sqr() < 1.0);
+            assert!(
+                d.transform(&PoincareDisk::origin().rotation(1.0))
+                    .as_complex()
+                    .norm_sqr()
+                    < 1.0
+            );
+            assert!(d.transform(&d.translation()).as_complex().norm_sqr() < 1.0);
+            assert!(p.transform(&o.rotation(1.0)).as_complex().im() > 0.0);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use super::HalfPlane;
+use crate::complex::{Complex, Moebius, Quaternion};
+use num_traits::Float;
+
+/// Point of the three-dimensional hyperbolic space in the Poincaré upper half-space model.
+///
+/// Represented by quaternion `x + y i + h j` where `h > 0` is the height above the boundary plane,
+/// so that `Moebius<Complex<T>>` acts on it as an isometry.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct HalfSpace<T> {
+    pos: Quaternion<T>,
+}
+
+impl<T> HalfSpace<T> {
+    /// Quaternion representation of the point.
+    pub fn into_quaternion(self) -> Quaternion<T> {
+        self.pos
+    }
+    /// Get quaternion representation reference.
+    pub fn as_quaternion(&self) -> &Quaternion<T> {
+        &self.pos
+    }
+}
+
+impl<T> HalfSpace<T>
+where
+    T: Copy,
+{
+    /// Projection of the point onto the boundary plane.
+    pub fn base(&self) -> Complex<T> {
+        Complex::new(self.pos.w(), self.pos.x())
+    }
+    /// Height of the point above the boundary plane.
+    pub fn height(&self) -> T {
+        self.pos.y()
+    }
+}
+
+impl<T> HalfSpace<T>
+where
+    T: Float,
+{
+    /// Construct point from its projection onto the boundary plane and height if the height is positive.
+    pub fn try_new(base: Complex<T>, height: T) -> Option<Self> {
+        if height > T::zero() {
+            Some(Self::new(base, height))
+        } else {
+            None
+        }
+    }
+    /// Construct point from its projection onto the boundary plane and height.
+    ///
+    /// Panics if the height is not positive.
+    pub fn new(base: Complex<T>, height: T) -> Self {
+        assert!(
+            height > T::zero(),
+            "Point is outside of the upper half-space"
+        );
+        Self {
+            pos: Quaternion::new(base.re(), base.im(), height, T::zero()),
+        }
+    }
+
+    /// Hyperbolic distance between points.
+    pub fn distance(self, other: Self) -> T {
+        let two = T::one() + T::one();
+        let den = two * (self.height() * other.height()).sqrt();
+        two * ((self.pos - other.pos).norm() / den).asinh()
+    }
+
+    /// Point on the geodesic segment between `self` (`t = 0`) and `other` (`t = 1`).
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        // Geodesic lies in the vertical plane containing both points,
+        // this plane is isometric to the upper half-plane.
+        let dir = other.base() - self.base();
+        let len = dir.norm();
+        if len == T::zero() {
+            let h = self.height().powf(T::one() - t) * other.height().powf(t);
+            return Self::new(self.base(), h);
+        }
+        let a = HalfPlane::new(Complex::new(T::zero(), self.height()));
+        let b = HalfPlane::new(Complex::new(len, other.height()));
+        let x = a.lerp(b, t).into_complex();
+        Self::new(self.base() + dir * (x.re() / len), x.im())
+    }
+
+    /// Apply isometry to the point.
+    ///
+    /// Points pushed onto the boundary plane by rounding errors are moved back into the half-space.
+    pub fn transform(self, m: &Moebius<Complex<T>>) -> Self {
+        let q = m.apply(self.pos);
+        // The `k` component vanishes analytically, drop rounding errors.
+        Self::new(
+            Complex::new(q.w(), q.x()),
+            q.y().max(T::min_positive_value()),
+        )
+    }
+}
+
+impl<T> From<HalfPlane<T>> for HalfSpace<T>
+where
+    T: Float,
+{
+    fn from(p: HalfPlane<T>) -> Self {
+        p.to_half_space()
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::{Invertible, Normal};
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-9;
+
+    fn point(rng: &mut XorShiftRng) -> HalfSpace<f64> {
+        let h: f64 = rng.sample(&Normal);
+        HalfSpace::new(rng.sample(&Normal), h.exp())
+    }
+
+    #[test]
+    fn distance() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B6);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q) = (point(&mut rng), point(&mut rng));
+            let d = p.distance(q);
+            let m: Moebius<Complex<f64>> = rng.sample(&Invertible);
+            let m = m.normalize_sl2();
+            assert_abs_diff_eq!(p.transform(&m).distance(q.transform(&m)), d, epsilon = 1e-6);
+
+            let (a, b): (Complex<f64>, Complex<f64>) = (rng.sample(&Normal), rng.sample(&Normal));
+            let (a, b) = (
+                HalfPlane::new(Complex::new(a.re(), a.im().exp())),
+                HalfPlane::new(Complex::new(b.re(), b.im().exp())),
+            );
+            assert_abs_diff_eq!(
+                a.to_half_space().distance(b.into()),
+                a.distance(b),
+                epsilon = EPS
+            );
+            let t = a.translation_to(b);
+            assert_abs_diff_eq!(
+                a.to_half_space().transform(&t).into_quaternion(),
+                b.to_half_space().into_quaternion(),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn lerp() {
+        let mut rng = XorShiftRng::seed_from_u64(0x4B7);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (p, q) = (point(&mut rng), point(&mut rng));
+            let t = rng.gen::<f64>();
+            let d = p.distance(q);
+            let x = p.lerp(q, t);
+            assert_abs_diff_eq!(p.distance(x), t * d, epsilon = EPS);
+            assert_abs_diff_eq!(x.distance(q), (1.0 - t) * d, epsilon = EPS);
+
+            let v = HalfSpace::new(p.base(), q.height());
+            let y = p.lerp(v, t);
+            assert_abs_diff_eq!(p.distance(y), t * p.distance(v), epsilon = EPS);
+            assert_abs_diff_eq!(y.base(), p.base(), epsilon = EPS);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Models of hyperbolic geometry.
+//!
+//! + `PoincareDisk` and `HalfPlane` - two-dimensional hyperbolic plane.
+//! + `HalfSpace` - three-dimensional hyperbolic space.
+//!
+//! Isometries of all models are represented by `Moebius<Complex<T>>` with unit determinant.
+//! Isometries of the disk have form `[[a, b], [conj(b), conj(a)]]`,
+//! isometries of the half-plane have real coefficients (and also act on the half-space),
+//! any transformation acts as an isometry of the half-space.
+//! Isometries could be moved between the disk and the half-plane by conjugation with `half_plane_to_disk`.
+
+mod disk;
+mod half_plane;
+mod half_space;
+
+pub use disk::*;
+pub use half_plane::*;
+pub use half_space::*;
+
+use crate::complex::{Complex, Moebius};
+use num_traits::Float;
+
+/// Cayley transform `(z - i) / (z + i)` mapping the upper half-plane onto the unit disk.
+pub fn half_plane_to_disk<T: Float>() -> Moebius<Complex<T>> {
+    let (one, i) = (
+        Complex::new(T::one(), T::zero()),
+        Complex::new(T::zero(), T::one()),
+    );
+    Moebius::new(one, -i, one, i).normalize_sl2()
+}
+
+/// Inverse Cayley transform `i (1 + z) / (1 - z)` mapping the unit disk onto the upper half-plane.
+pub fn disk_to_half_plane<T: Float>() -> Moebius<Complex<T>> {
+    let (one, i) = (
+        Complex::new(T::one(), T::zero()),
+        Complex::new(T::zero(), T::one()),
+    );
+    Moebius::new(i, i, -one, one).normalize_sl2()
+}
+
+/// Move the point pushed onto or outside of the unit circle by rounding errors back inside the disk.
+fn clamp_to_disk<T: Float>(z: Complex<T>) -> Complex<T> {
+    if z.norm_sqr() < T::one() {
+        z
+    } else {
+        let two = T::one() + T::one();
+        z * ((T::one() - two * two * T::epsilon()) / z.norm())
+    }
+}
+
+/// Move the point pushed onto or below the real axis by rounding errors back into the upper half-plane.
+fn clamp_to_half_plane<T: Float>(z: Complex<T>) -> Complex<T> {
+    Complex::new(z.re(), z.im().max(T::min_positive_value()))
+}
@@ -60,22 +60,31 @@
 #![no_std]
 #[cfg(feature = "std")]
 extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+pub mod autodiff;
 pub mod complex;
 #[cfg(feature = "rand")]
 pub mod distr;
+pub mod fit;
+pub mod ga;
+pub mod geometry;
+pub mod hyperbolic;
 pub mod matrix;
+#[cfg(feature = "simd")]
+pub mod simd;
 pub mod traits;
 pub mod transform;
 pub mod vector;
 
 pub use complex::{Complex, Quaternion};
 pub use matrix::Matrix;
-pub use transform::Transform;
+pub use transform::{Map, Transform};
 pub use vector::Vector;
 
 pub mod prelude {
-    pub use crate::{traits::*, Transform};
+    pub use crate::{traits::*, Map, Transform};
     pub use core::convert::TryFrom;
     pub use num_traits::{One, Zero};
 }
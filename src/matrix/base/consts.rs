@@ -0,0 +1,26 @@
+use crate::{Matrix, Vector};
+use num_traits::{ConstOne, ConstZero};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: ConstZero + Copy,
+{
+    /// Matrix with all elements equal to zero.
+    pub const ZERO: Self = Self::from_vector_of_vectors(Vector::from_array([Vector::ZERO; M]));
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: ConstZero + ConstOne + Copy,
+{
+    /// Identity matrix.
+    pub const IDENTITY: Self = {
+        let mut rows = [Vector::ZERO; N];
+        let mut i = 0;
+        while i < N {
+            rows[i] = Vector::unit(i);
+            i += 1;
+        }
+        Self::from_array_of_vectors(rows)
+    };
+}
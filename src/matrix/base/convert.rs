@@ -1,15 +1,19 @@
 use crate::{vector::GroupIter, Matrix, Vector};
-use core::convert::TryFrom;
+use core::{convert::TryFrom, mem, ptr};
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
-    pub fn from_vector_of_vectors(a: Vector<Vector<T, N>, M>) -> Self {
+    pub const fn from_vector_of_vectors(a: Vector<Vector<T, N>, M>) -> Self {
         Self { data: a }
     }
-    pub fn from_array_of_vectors(a: [Vector<T, N>; M]) -> Self {
+    pub const fn from_array_of_vectors(a: [Vector<T, N>; M]) -> Self {
         Self::from_vector_of_vectors(Vector::from_array(a))
     }
-    pub fn from_array_of_arrays(a: [[T; N]; M]) -> Self {
-        Self::from_vector_of_vectors(Vector::from_array(a).map(Vector::from_array))
+    pub const fn from_array_of_arrays(a: [[T; N]; M]) -> Self {
+        // Matrix and vector are transparent wrappers over arrays, so layouts are the same.
+        // TODO: Use `mem::transmute` when it will be possible.
+        let m = unsafe { ptr::read(&a as *const _ as *const Self) };
+        mem::forget(a);
+        m
     }
     pub fn into_vector_of_vectors(self) -> Vector<Vector<T, N>, M> {
         self.data
@@ -1,3 +1,4 @@
+mod consts;
 mod convert;
 mod init;
 mod iter;
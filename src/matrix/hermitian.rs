@@ -0,0 +1,118 @@
+use crate::{
+    traits::{Conj, NormL2},
+    Matrix,
+};
+use core::ops::{Add, Mul, Sub};
+use num_traits::{One, Zero};
+
+impl<T, const M: usize, const N: usize> Conj for Matrix<T, M, N>
+where
+    T: Conj,
+{
+    /// Conjugate each element of the matrix.
+    fn conj(self) -> Self {
+        self.map(T::conj)
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Conj,
+{
+    /// Conjugate transpose (Hermitian adjoint) of the matrix.
+    ///
+    /// Not to be confused with the adjugate matrix `adj`.
+    pub fn adjoint(self) -> Matrix<T, N, M> {
+        self.transpose().conj()
+    }
+    /// Conjugate transpose, the same as `adjoint`.
+    pub fn conj_transpose(self) -> Matrix<T, N, M> {
+        self.adjoint()
+    }
+}
+
+impl<T, R, const N: usize> Matrix<T, N, N>
+where
+    T: Conj + Sub<Output = T> + NormL2<Output = R> + Copy,
+    R: PartialOrd,
+{
+    /// Check that the matrix is equal to its conjugate transpose up to `eps` elementwise.
+    pub fn is_hermitian(&self, eps: R) -> bool {
+        (*self - self.adjoint())
+            .into_iter()
+            .all(|x| x.norm_l2() <= eps)
+    }
+}
+
+impl<T, R, const N: usize> Matrix<T, N, N>
+where
+    T: Conj + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    T: NormL2<Output = R> + Copy,
+    R: PartialOrd,
+{
+    /// Check that the product of the matrix and its conjugate transpose is identity up to `eps` elementwise.
+    pub fn is_unitary(&self, eps: R) -> bool {
+        let p: Self = Matrix::indices().map(|(i, j)| self.row(i).vdot(self.row(j)));
+        (p - Matrix::one()).into_iter().all(|x| x.norm_l2() <= eps)
+    }
+}
+
+#[cfg(all(test, feature = "approx"))]
+mod tests {
+    use crate::{prelude::*, Complex, Matrix, Vector};
+    use approx::assert_abs_diff_eq;
+
+    type C = Complex<f64>;
+
+    fn c(re: f64, im: f64) -> C {
+        C::new(re, im)
+    }
+
+    #[test]
+    fn adjoint() {
+        let m = Matrix::from([[c(1.0, 2.0), c(3.0, -1.0)], [c(0.0, 1.0), c(-2.0, 0.5)]]);
+        let a = m.adjoint();
+        assert_abs_diff_eq!(a[(0, 0)], c(1.0, -2.0));
+        assert_abs_diff_eq!(a[(0, 1)], c(0.0, -1.0));
+        assert_abs_diff_eq!(a[(1, 0)], c(3.0, 1.0));
+        assert_abs_diff_eq!(a[(1, 1)], c(-2.0, -0.5));
+        assert_abs_diff_eq!(m.conj_transpose().adjoint(), m);
+        assert!(!m.is_hermitian(1e-12));
+        assert!((m + a).is_hermitian(1e-12));
+    }
+
+    #[test]
+    fn unitary() {
+        let h = 0.5f64.sqrt();
+        // Hadamard gate.
+        let hd = Matrix::from([[c(h, 0.0), c(h, 0.0)], [c(h, 0.0), c(-h, 0.0)]]);
+        assert!(hd.is_unitary(1e-12));
+        assert!(hd.is_hermitian(1e-12));
+        // Phase gate.
+        let s = Matrix::from([[c(1.0, 0.0), c(0.0, 0.0)], [c(0.0, 0.0), c(0.0, 1.0)]]);
+        assert!(s.is_unitary(1e-12));
+        assert!(!s.is_hermitian(1e-12));
+        assert!(hd.dot(s).is_unitary(1e-12));
+        assert_abs_diff_eq!(
+            hd.dot(s).inv(),
+            s.adjoint().dot(hd.adjoint()),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(s.det(), c(0.0, 1.0), epsilon = 1e-12);
+        assert!(!(hd * c(2.0, 0.0)).is_unitary(1e-12));
+
+        let r = Matrix::from([[0.6, -0.8], [0.8, 0.6]]);
+        assert!(r.is_unitary(1e-12));
+        assert_abs_diff_eq!(r.adjoint(), r.transpose());
+    }
+
+    #[test]
+    fn vdot() {
+        let a = Vector::from([c(1.0, 2.0), c(0.0, -1.0)]);
+        let b = Vector::from([c(3.0, 0.0), c(1.0, 1.0)]);
+        assert_abs_diff_eq!(a.vdot(b), c(2.0, -5.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(a.vdot(b), b.vdot(a).conj(), epsilon = 1e-12);
+        assert_abs_diff_eq!(a.vdot(a), c(6.0, 0.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(a.dot(b), c(4.0, 5.0), epsilon = 1e-12);
+    }
+}
@@ -4,8 +4,10 @@ mod base;
 #[cfg(feature = "rand")]
 mod distr;
 mod format;
+mod hermitian;
 mod ops;
 mod product;
+mod spectral;
 mod square;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,175 @@
+use crate::{complex::roots, traits::Dot, Complex, Matrix, Vector};
+use core::ops::Neg;
+use num_traits::{Float, FloatConst, Num, NumCast, One, Zero};
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Zero + Copy,
+{
+    /// Sum of the diagonal elements.
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |a, i| a + self[(i, i)])
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Num + Copy,
+{
+    /// Coefficients of the characteristic polynomial `det(x I - A)`.
+    ///
+    /// The polynomial is monic, so only lower coefficients are returned in ascending order,
+    /// i.e. `x^N + c[N-1] x^(N-1) + ... + c[1] x + c[0]`, the same convention as in `complex::roots`.
+    ///
+    /// Faddeev-LeVerrier algorithm is used, it contains only exact divisions for integer matrices.
+    pub fn char_poly(&self) -> Vector<T, N> {
+        let mut coef = Vector::<T, N>::zero();
+        let mut m = Self::zero();
+        let mut c = T::one();
+        let mut k = T::zero();
+        for i in (0..N).rev() {
+            m = self.dot(m) + Self::one() * c;
+            k = k + T::one();
+            c = T::zero() - self.dot(m).trace() / k;
+            coef[i] = c;
+        }
+        coef
+    }
+
+    /// Raise the matrix to non-negative integer power by repeated squaring.
+    ///
+    /// Not to be confused with elementwise `powi`.
+    pub fn matrix_powu(&self, mut exp: u32) -> Self {
+        let mut res = Self::one();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 != 0 {
+                res = res.dot(base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.dot(base);
+            }
+        }
+        res
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Raise the matrix to integer power, negative powers are powers of the inverse matrix.
+    ///
+    /// Not to be confused with elementwise `powi`.
+    pub fn matrix_powi(&self, exp: i32) -> Self {
+        if exp < 0 {
+            self.inv().matrix_powu(exp.unsigned_abs())
+        } else {
+            self.matrix_powu(exp as u32)
+        }
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + FloatConst + NumCast,
+{
+    /// Eigenvalues of the matrix, the roots of its characteristic polynomial.
+    ///
+    /// Matrix may be non-symmetric, so eigenvalues are complex in general.
+    /// Characteristic polynomial becomes ill-conditioned for large `N`, so it is mostly suitable for small matrices.
+    pub fn eigenvalues(&self) -> Vector<Complex<T>, N> {
+        roots::real_roots(self.char_poly())
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use crate::{distr::Normal, prelude::*, Complex, Matrix, Vector};
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    #[test]
+    fn trace() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(m.trace(), 15);
+        assert_eq!(Matrix::<i32, 4, 4>::one().trace(), 4);
+    }
+
+    #[test]
+    fn char_poly_int() {
+        let m = Matrix::from([[2, 1], [-1, 3]]);
+        assert_eq!(m.char_poly(), Vector::from([7, -5]));
+        let m = Matrix::from([[1, 2, 0], [3, -1, 4], [0, 5, 2]]);
+        let c = m.char_poly();
+        assert_eq!(c[2], -m.trace());
+        assert_eq!(c[0], -m.det());
+        // Cayley-Hamilton theorem.
+        let p = m.matrix_powu(3) + m.matrix_powu(2) * c[2] + m * c[1] + Matrix::one() * c[0];
+        assert_eq!(p, Matrix::zero());
+    }
+
+    #[test]
+    fn powers() {
+        let f = Matrix::from([[1, 1], [1, 0]]);
+        assert_eq!(f.matrix_powu(0), Matrix::one());
+        assert_eq!(f.matrix_powu(10), Matrix::from([[89, 55], [55, 34]]));
+        assert_eq!(f.matrix_powi(-3), Matrix::from([[-1, 2], [2, -3]]));
+
+        let mut rng = XorShiftRng::seed_from_u64(0x4040);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m: Matrix<f64, 3, 3> = rng.sample(&Normal);
+            let m = m + Matrix::one() * 4.0;
+            assert_abs_diff_eq!(
+                m.matrix_powi(5),
+                m.dot(m).dot(m).dot(m).dot(m),
+                epsilon = 1e-6 * m.matrix_powi(5).abs().fold(0.0, f64::max)
+            );
+            assert_abs_diff_eq!(
+                m.matrix_powi(-2).dot(m.matrix_powi(2)),
+                Matrix::one(),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn eigenvalues() {
+        let r = Matrix::from([[0.0, -1.0], [1.0, 0.0]]);
+        let e = r.eigenvalues();
+        let e = if e[0].im() < 0.0 {
+            Vector::from([e[1], e[0]])
+        } else {
+            e
+        };
+        assert_abs_diff_eq!(
+            e,
+            Vector::from([Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)]),
+            epsilon = 1e-12
+        );
+
+        let mut rng = XorShiftRng::seed_from_u64(0x4041);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m: Matrix<f64, 4, 4> = rng.sample(&Normal);
+            let e = m.eigenvalues();
+            let cm = m.map(|x| Complex::new(x, 0.0));
+            for l in e.into_iter() {
+                assert_abs_diff_eq!((cm - Matrix::one() * l).det().norm(), 0.0, epsilon = 1e-6);
+            }
+            assert_abs_diff_eq!(
+                e.into_iter().fold(Complex::<f64>::zero(), |a, l| a + l).re(),
+                m.trace(),
+                epsilon = 1e-8
+            );
+            assert_abs_diff_eq!(
+                e.into_iter().fold(Complex::<f64>::one(), |a, l| a * l).re(),
+                m.det(),
+                epsilon = 1e-8
+            );
+        }
+    }
+}
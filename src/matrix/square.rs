@@ -151,19 +151,141 @@ where
         Determinator::new(self).cofactor((i, i), (j, j))
     }
 
+    /// Determinant of the matrix computed by recursive Laplace (cofactor) expansion.
+    ///
+    /// Works for any `N`, but `det` is much faster for `N <= 4`.
+    pub fn det_laplace(&self) -> T {
+        Determinator::new(self).det()
+    }
+
+    /// Adjugate matrix computed by recursive Laplace (cofactor) expansion.
+    ///
+    /// Works for any `N`, but `adj` is much faster for `N <= 4`.
+    pub fn adj_laplace(&self) -> Self {
+        Matrix::indices().map(|(i, j)| self.cofactor(j, i))
+    }
+
     /// Determinant of the matrix.
+    ///
+    /// Closed-form expressions are used for `N <= 4`.
     pub fn det(&self) -> T {
-        Determinator::new(self).det()
+        let m = |i: usize, j: usize| self[(i, j)];
+        match N {
+            0 => T::one(),
+            1 => m(0, 0),
+            2 => m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0),
+            3 => {
+                m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+                    + m(0, 1) * (m(1, 2) * m(2, 0) - m(1, 0) * m(2, 2))
+                    + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0))
+            }
+            4 => {
+                let Minors4 { s, c, .. } = Minors4::new(self);
+                s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+            }
+            _ => self.det_laplace(),
+        }
     }
 
     /// Adjugate matrix.
+    ///
+    /// Closed-form expressions are used for `N <= 4`.
     pub fn adj(&self) -> Self {
-        Matrix::indices().map(|(i, j)| self.cofactor(j, i))
+        let m = |i: usize, j: usize| self[(i, j)];
+        let a: [T; 16] = match N {
+            2 => {
+                let mut a = [T::zero(); 16];
+                a[..4].copy_from_slice(&[m(1, 1), -m(0, 1), -m(1, 0), m(0, 0)]);
+                a
+            }
+            3 => {
+                let mut a = [T::zero(); 16];
+                a[..9].copy_from_slice(&[
+                    m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1),
+                    m(0, 2) * m(2, 1) - m(0, 1) * m(2, 2),
+                    m(0, 1) * m(1, 2) - m(0, 2) * m(1, 1),
+                    m(1, 2) * m(2, 0) - m(1, 0) * m(2, 2),
+                    m(0, 0) * m(2, 2) - m(0, 2) * m(2, 0),
+                    m(0, 2) * m(1, 0) - m(0, 0) * m(1, 2),
+                    m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0),
+                    m(0, 1) * m(2, 0) - m(0, 0) * m(2, 1),
+                    m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0),
+                ]);
+                a
+            }
+            4 => Minors4::new(self).adj(),
+            _ => return self.adj_laplace(),
+        };
+        Matrix::indices().map(|(i, j)| a[i * N + j])
     }
 
     /// Inverse matrix.
     pub fn inv(&self) -> Self {
-        self.adj() / self.det()
+        let adj = self.adj();
+        // Determinant is obtained from the adjugate matrix to avoid repeated computation.
+        let det = (0..N).fold(T::zero(), |d, j| d + self[(0, j)] * adj[(j, 0)]);
+        adj / det
+    }
+}
+
+/// Precomputed 2x2 minors of the top and bottom halves of 4x4 matrix.
+struct Minors4<T> {
+    m: [[T; 4]; 4],
+    s: [T; 6],
+    c: [T; 6],
+}
+
+impl<T> Minors4<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn new<const N: usize>(matrix: &Matrix<T, N, N>) -> Self {
+        let mut m = [[T::zero(); 4]; 4];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = matrix[(i, j)];
+            }
+        }
+        let s = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+        let c = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+        Self { m, s, c }
+    }
+
+    /// Adjugate matrix in row-major order.
+    fn adj(&self) -> [T; 16] {
+        let (m, s, c) = (&self.m, &self.s, &self.c);
+        [
+            m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+            -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+            m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+            -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+            -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+            m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+            -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+            m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+            m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+            -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+            m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+            -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+            -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+            m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+            -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+            m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+        ]
     }
 }
 
@@ -174,6 +296,44 @@ where
     type Output = Self;
 
     fn inv(self) -> Self::Output {
-        self.adj() / self.det()
+        Matrix::inv(&self)
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use crate::{prelude::*, Matrix};
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    fn check<const N: usize>(rng: &mut XorShiftRng) {
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m = Matrix::<i64, N, N>::init(|| rng.gen_range(-9..10));
+            assert_eq!(m.det(), m.det_laplace());
+            assert_eq!(m.adj(), m.adj_laplace());
+            assert_eq!(m.dot(m.adj()), Matrix::one() * m.det());
+        }
+    }
+
+    #[test]
+    fn closed_form() {
+        let mut rng = XorShiftRng::seed_from_u64(0xDE7);
+        check::<1>(&mut rng);
+        check::<2>(&mut rng);
+        check::<3>(&mut rng);
+        check::<4>(&mut rng);
+        check::<5>(&mut rng);
+    }
+
+    #[test]
+    fn inv() {
+        let mut rng = XorShiftRng::seed_from_u64(0xDE8);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m = Matrix::<f64, 4, 4>::init(|| rng.gen_range(-1.0..1.0)) + Matrix::one() * 4.0;
+            let p = m.dot(m.inv()) - Matrix::one();
+            assert!(p.into_iter().all(|x| x.abs() < 1e-12));
+        }
     }
 }
@@ -752,3 +752,12 @@ fn inv() {
     let dm = m - im;
     assert!(dm[(0, 0)].abs() + dm[(0, 1)].abs() + dm[(1, 0)].abs() + dm[(1, 1)].abs() < 1e-8);
 }
+
+#[test]
+fn consts() {
+    const M: Matrix2x3<i32> = Matrix::from_array_of_arrays([[1, 2, 3], [4, 5, 6]]);
+    const I: Matrix3x3<f64> = Matrix::IDENTITY;
+    assert_eq!(M, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+    assert_eq!(I, Matrix3x3::one());
+    assert_eq!(Matrix3x4::<u32>::ZERO, Matrix3x4::zero());
+}
@@ -0,0 +1,189 @@
+//! Minimal portable wrappers over SIMD registers.
+//!
+//! On `x86_64` SSE2 (which is always available there) and AVX (if enabled at compile time) are used,
+//! on other platforms the operations are performed lane by lane.
+//!
+//! Operations are `unsafe` because of the underlying intrinsics. The implementation is selected
+//! by the target features enabled at compile time, so it is always sound to call them.
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+use core::arch::x86_64::*;
+
+#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+macro_rules! lanes_fallback {
+    ($Name:ident, $T:ty, $N:expr) => {
+        #[derive(Clone, Copy)]
+        pub struct $Name([$T; $N]);
+
+        impl $Name {
+            #[inline]
+            pub unsafe fn load(a: [$T; $N]) -> Self {
+                Self(a)
+            }
+            #[inline]
+            pub unsafe fn store(self) -> [$T; $N] {
+                self.0
+            }
+            #[inline]
+            pub unsafe fn splat(x: $T) -> Self {
+                Self([x; $N])
+            }
+            #[inline]
+            fn zip(self, other: Self, f: impl Fn($T, $T) -> $T) -> Self {
+                let mut res = self.0;
+                for (r, o) in res.iter_mut().zip(other.0.iter()) {
+                    *r = f(*r, *o);
+                }
+                Self(res)
+            }
+            #[inline]
+            pub unsafe fn add(self, other: Self) -> Self {
+                self.zip(other, |a, b| a + b)
+            }
+            #[inline]
+            pub unsafe fn sub(self, other: Self) -> Self {
+                self.zip(other, |a, b| a - b)
+            }
+            #[inline]
+            pub unsafe fn mul(self, other: Self) -> Self {
+                self.zip(other, |a, b| a * b)
+            }
+            #[inline]
+            pub unsafe fn div(self, other: Self) -> Self {
+                self.zip(other, |a, b| a / b)
+            }
+        }
+    };
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+macro_rules! lanes_x86 {
+    (
+        $Name:ident, $T:ty, $N:expr, $Reg:ty,
+        $load:ident, $store:ident, $set1:ident,
+        $add:ident, $sub:ident, $mul:ident, $div:ident
+    ) => {
+        #[derive(Clone, Copy)]
+        pub struct $Name($Reg);
+
+        impl $Name {
+            #[inline]
+            pub unsafe fn load(a: [$T; $N]) -> Self {
+                Self($load(a.as_ptr()))
+            }
+            #[inline]
+            pub unsafe fn store(self) -> [$T; $N] {
+                let mut res = [0.0; $N];
+                $store(res.as_mut_ptr(), self.0);
+                res
+            }
+            #[inline]
+            pub unsafe fn splat(x: $T) -> Self {
+                Self($set1(x))
+            }
+            #[inline]
+            pub unsafe fn add(self, other: Self) -> Self {
+                Self($add(self.0, other.0))
+            }
+            #[inline]
+            pub unsafe fn sub(self, other: Self) -> Self {
+                Self($sub(self.0, other.0))
+            }
+            #[inline]
+            pub unsafe fn mul(self, other: Self) -> Self {
+                Self($mul(self.0, other.0))
+            }
+            #[inline]
+            pub unsafe fn div(self, other: Self) -> Self {
+                Self($div(self.0, other.0))
+            }
+        }
+    };
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+lanes_x86!(
+    F32x4,
+    f32,
+    4,
+    __m128,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_set1_ps,
+    _mm_add_ps,
+    _mm_sub_ps,
+    _mm_mul_ps,
+    _mm_div_ps
+);
+#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+lanes_fallback!(F32x4, f32, 4);
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+lanes_x86!(
+    F64x2,
+    f64,
+    2,
+    __m128d,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_set1_pd,
+    _mm_add_pd,
+    _mm_sub_pd,
+    _mm_mul_pd,
+    _mm_div_pd
+);
+#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+lanes_fallback!(F64x2, f64, 2);
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx"))]
+lanes_x86!(
+    F64x4,
+    f64,
+    4,
+    __m256d,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_set1_pd,
+    _mm256_add_pd,
+    _mm256_sub_pd,
+    _mm256_mul_pd,
+    _mm256_div_pd
+);
+
+/// Pair of two-lane registers when AVX is not available.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx")))]
+#[derive(Clone, Copy)]
+pub struct F64x4(F64x2, F64x2);
+
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx")))]
+impl F64x4 {
+    #[inline]
+    pub unsafe fn load(a: [f64; 4]) -> Self {
+        Self(F64x2::load([a[0], a[1]]), F64x2::load([a[2], a[3]]))
+    }
+    #[inline]
+    pub unsafe fn store(self) -> [f64; 4] {
+        let ([a, b], [c, d]) = (self.0.store(), self.1.store());
+        [a, b, c, d]
+    }
+    #[inline]
+    pub unsafe fn splat(x: f64) -> Self {
+        Self(F64x2::splat(x), F64x2::splat(x))
+    }
+    #[inline]
+    pub unsafe fn add(self, other: Self) -> Self {
+        Self(self.0.add(other.0), self.1.add(other.1))
+    }
+    #[inline]
+    pub unsafe fn sub(self, other: Self) -> Self {
+        Self(self.0.sub(other.0), self.1.sub(other.1))
+    }
+    #[inline]
+    pub unsafe fn mul(self, other: Self) -> Self {
+        Self(self.0.mul(other.0), self.1.mul(other.1))
+    }
+    #[inline]
+    pub unsafe fn div(self, other: Self) -> Self {
+        Self(self.0.div(other.0), self.1.div(other.1))
+    }
+}
@@ -0,0 +1,186 @@
+//! SIMD-accelerated operations for the most common `f32` and `f64` types.
+//!
+//! Generic operators can't be specialized for concrete types on stable Rust,
+//! so accelerated versions are provided as separate `simd_*` methods.
+//! Results are bitwise equal to the ones of the corresponding generic operations
+//! because the order of floating-point operations is preserved.
+//!
+//! Speed of these methods compared to the generic ones can be measured with `benches/simd.rs`.
+
+mod lanes;
+
+use crate::{matrix::Matrix4x4, Quaternion, Vector};
+use lanes::{F32x4, F64x2, F64x4};
+
+macro_rules! vector_simd {
+    ($T:ty, $N:expr, $Lanes:ident) => {
+        impl Vector<$T, $N> {
+            #[inline]
+            unsafe fn into_lanes(self) -> $Lanes {
+                $Lanes::load(self.into_array())
+            }
+            #[inline]
+            unsafe fn from_lanes(lanes: $Lanes) -> Self {
+                Self::from_array(lanes.store())
+            }
+
+            /// Component-wise sum, the same as `self + other`.
+            pub fn simd_add(self, other: Self) -> Self {
+                unsafe { Self::from_lanes(self.into_lanes().add(other.into_lanes())) }
+            }
+            /// Component-wise difference, the same as `self - other`.
+            pub fn simd_sub(self, other: Self) -> Self {
+                unsafe { Self::from_lanes(self.into_lanes().sub(other.into_lanes())) }
+            }
+            /// Component-wise product, the same as `self * other`.
+            pub fn simd_mul(self, other: Self) -> Self {
+                unsafe { Self::from_lanes(self.into_lanes().mul(other.into_lanes())) }
+            }
+            /// Component-wise quotient, the same as `self / other`.
+            pub fn simd_div(self, other: Self) -> Self {
+                unsafe { Self::from_lanes(self.into_lanes().div(other.into_lanes())) }
+            }
+            /// Product by scalar, the same as `self * a`.
+            pub fn simd_scale(self, a: $T) -> Self {
+                unsafe { Self::from_lanes(self.into_lanes().mul($Lanes::splat(a))) }
+            }
+            /// Dot product, the same as `self.dot(other)`.
+            pub fn simd_dot(self, other: Self) -> $T {
+                let p = unsafe { self.into_lanes().mul(other.into_lanes()).store() };
+                p[1..].iter().fold(p[0], |a, x| a + x)
+            }
+        }
+    };
+}
+
+vector_simd!(f32, 4, F32x4);
+vector_simd!(f64, 2, F64x2);
+vector_simd!(f64, 4, F64x4);
+
+impl Matrix4x4<f32> {
+    /// Matrix-matrix product, the same as `self.dot(other)`.
+    pub fn simd_dot(self, other: Self) -> Self {
+        unsafe {
+            let b = other.into_vector_of_vectors().map(|row| row.into_lanes());
+            Matrix4x4::from_vector_of_vectors(self.into_vector_of_vectors().map(|a| {
+                Vector::<f32, 4>::from_lanes((1..4).fold(F32x4::splat(a[0]).mul(b[0]), |acc, k| {
+                    acc.add(F32x4::splat(a[k]).mul(b[k]))
+                }))
+            }))
+        }
+    }
+    /// Matrix-vector product, the same as `self.dot(vec)`.
+    pub fn simd_dot_vector(self, vec: Vector<f32, 4>) -> Vector<f32, 4> {
+        unsafe {
+            let c = self
+                .transpose()
+                .into_vector_of_vectors()
+                .map(|col| col.into_lanes());
+            Vector::<f32, 4>::from_lanes((1..4).fold(c[0].mul(F32x4::splat(vec[0])), |acc, k| {
+                acc.add(c[k].mul(F32x4::splat(vec[k])))
+            }))
+        }
+    }
+}
+
+macro_rules! quaternion_simd {
+    ($T:ty, $Lanes:ident) => {
+        impl Quaternion<$T> {
+            /// Hamilton product, the same as `self * other`.
+            pub fn simd_mul(self, other: Self) -> Self {
+                let [aw, ax, ay, az] = self.into_array();
+                let [bw, bx, by, bz] = other.into_array();
+                // Subtraction is replaced by addition of negated product, that is exact.
+                let terms = [
+                    (aw, [bw, bx, by, bz], [1.0, 1.0, 1.0, 1.0]),
+                    (ax, [bx, bw, bz, by], [-1.0, 1.0, -1.0, 1.0]),
+                    (ay, [by, bz, bw, bx], [-1.0, 1.0, 1.0, -1.0]),
+                    (az, [bz, by, bx, bw], [-1.0, -1.0, 1.0, 1.0]),
+                ];
+                unsafe {
+                    let term = |(a, b, s): ($T, [$T; 4], [$T; 4])| {
+                        $Lanes::splat(a).mul($Lanes::load(b)).mul($Lanes::load(s))
+                    };
+                    let acc = terms[1..]
+                        .iter()
+                        .fold(term(terms[0]), |acc, t| acc.add(term(*t)));
+                    Self::from_array(acc.store())
+                }
+            }
+        }
+    };
+}
+
+quaternion_simd!(f32, F32x4);
+quaternion_simd!(f64, F64x4);
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use crate::{distr::Normal, matrix::Matrix4x4, prelude::*, Quaternion, Vector};
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    fn bits32<const N: usize>(v: Vector<f32, N>) -> Vector<u32, N> {
+        v.map(f32::to_bits)
+    }
+    fn bits64<const N: usize>(v: Vector<f64, N>) -> Vector<u64, N> {
+        v.map(f64::to_bits)
+    }
+
+    macro_rules! vector_test {
+        ($name:ident, $T:ty, $N:expr, $bits:ident) => {
+            #[test]
+            fn $name() {
+                let mut rng = XorShiftRng::seed_from_u64(0x51D0 + $N);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: Vector<$T, $N> = rng.sample(&Normal);
+                    let b: Vector<$T, $N> = rng.sample(&Normal);
+                    let c: $T = rng.sample(rand_::distributions::Standard);
+                    assert_eq!($bits(a.simd_add(b)), $bits(a + b));
+                    assert_eq!($bits(a.simd_sub(b)), $bits(a - b));
+                    assert_eq!($bits(a.simd_mul(b)), $bits(a * b));
+                    assert_eq!($bits(a.simd_div(b)), $bits(a / b));
+                    assert_eq!($bits(a.simd_scale(c)), $bits(a * c));
+                    assert_eq!(a.simd_dot(b).to_bits(), a.dot(b).to_bits());
+                }
+            }
+        };
+    }
+
+    vector_test!(vector_f32x4, f32, 4, bits32);
+    vector_test!(vector_f64x2, f64, 2, bits64);
+    vector_test!(vector_f64x4, f64, 4, bits64);
+
+    #[test]
+    fn matrix_f32x4x4() {
+        let mut rng = XorShiftRng::seed_from_u64(0x51D1);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Matrix4x4<f32> = rng.sample(&Normal);
+            let b: Matrix4x4<f32> = rng.sample(&Normal);
+            let v: Vector<f32, 4> = rng.sample(&Normal);
+            assert_eq!(a.simd_dot(b).map(f32::to_bits), a.dot(b).map(f32::to_bits));
+            assert_eq!(bits32(a.simd_dot_vector(v)), bits32(a.dot(v)));
+        }
+    }
+
+    #[test]
+    fn quaternion() {
+        let mut rng = XorShiftRng::seed_from_u64(0x51D2);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Quaternion<f32> = rng.sample(&Normal);
+            let b: Quaternion<f32> = rng.sample(&Normal);
+            assert_eq!(
+                bits32(a.simd_mul(b).into_vector()),
+                bits32((a * b).into_vector())
+            );
+            let a: Quaternion<f64> = rng.sample(&Normal);
+            let b: Quaternion<f64> = rng.sample(&Normal);
+            assert_eq!(
+                bits64(a.simd_mul(b).into_vector()),
+                bits64((a * b).into_vector())
+            );
+        }
+    }
+}
@@ -50,7 +50,7 @@ where
 mod tests {
     mod base {
         use super::super::*;
-        use crate::{matrix::*, vector::*, Transform};
+        use crate::{matrix::*, vector::*, Map, Transform};
         use approx::*;
         use num_traits::{One, Zero};
 
@@ -109,7 +109,7 @@ mod tests {
     #[cfg(feature = "rand")]
     mod random {
         use super::super::*;
-        use crate::{vector::*, Transform};
+        use crate::{traits::Dot, transform::Jacobian, vector::*, Map, Transform};
         use approx::assert_abs_diff_eq;
         use num_traits::Zero;
         use rand_::prelude::*;
@@ -153,5 +153,24 @@ mod tests {
                 assert_abs_diff_eq!(a.deriv(z, a.inv().deriv(z, x)), x, epsilon = EPS);
             }
         }
+
+        #[test]
+        fn jacobian() {
+            const EPS: f64 = 1e-12;
+            let mut rng = XorShiftRng::seed_from_u64(0xFEE);
+
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Affine3<f64> = rng.sample(&Normal);
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let d: Vector3<f64> = rng.sample(&Normal);
+
+                assert_abs_diff_eq!(a.jacobian(x).dot(d), a.deriv(x, d), epsilon = EPS);
+                assert_abs_diff_eq!(
+                    a.jacobian_det(x),
+                    a.linear().into_matrix().det(),
+                    epsilon = EPS
+                );
+            }
+        }
     }
 }
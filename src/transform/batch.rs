@@ -0,0 +1,98 @@
+use crate::{
+    traits::Dot,
+    transform::{Affine, Linear, Map, Rotation3, Shift},
+    vector::VectorBatch,
+};
+use core::ops::Neg;
+use num_traits::{Float, Num, NumCast};
+
+impl<T, const N: usize, const L: usize> Map<VectorBatch<T, N, L>> for Shift<T, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: VectorBatch<T, N, L>) -> VectorBatch<T, N, L> {
+        pos + self.into_vector()
+    }
+    fn deriv(&self, _pos: VectorBatch<T, N, L>, dir: VectorBatch<T, N, L>) -> VectorBatch<T, N, L> {
+        dir
+    }
+}
+
+impl<T, const N: usize, const L: usize> Map<VectorBatch<T, N, L>> for Linear<T, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: VectorBatch<T, N, L>) -> VectorBatch<T, N, L> {
+        self.into_matrix().dot(pos)
+    }
+    fn deriv(&self, _pos: VectorBatch<T, N, L>, dir: VectorBatch<T, N, L>) -> VectorBatch<T, N, L> {
+        self.apply(dir)
+    }
+}
+
+impl<T, const N: usize, const L: usize> Map<VectorBatch<T, N, L>> for Affine<T, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: VectorBatch<T, N, L>) -> VectorBatch<T, N, L> {
+        self.shift().apply(self.linear().apply(pos))
+    }
+    fn deriv(&self, _pos: VectorBatch<T, N, L>, dir: VectorBatch<T, N, L>) -> VectorBatch<T, N, L> {
+        self.linear().apply(dir)
+    }
+}
+
+impl<T, const L: usize> Map<VectorBatch<T, 3, L>> for Rotation3<T>
+where
+    T: Float + NumCast,
+{
+    /// Rotation matrix is computed once and then applied to all vectors of the batch.
+    fn apply(&self, pos: VectorBatch<T, 3, L>) -> VectorBatch<T, 3, L> {
+        self.to_linear().apply(pos)
+    }
+    fn deriv(&self, _pos: VectorBatch<T, 3, L>, dir: VectorBatch<T, 3, L>) -> VectorBatch<T, 3, L> {
+        self.apply(dir)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Invertible, Normal, Unit},
+        Quaternion, Vector,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 64;
+    const EPS: f64 = 1e-12;
+
+    fn check<M>(m: M, xs: [Vector<f64, 3>; 4])
+    where
+        M: Map<Vector<f64, 3>> + Map<VectorBatch<f64, 3, 4>>,
+    {
+        let b = VectorBatch::from_vectors(xs);
+        let (mb, db) = (m.apply(b), m.deriv(b, b));
+        for (j, x) in xs.iter().enumerate() {
+            assert_abs_diff_eq!(mb.get(j), m.apply(*x), epsilon = EPS);
+            assert_abs_diff_eq!(db.get(j), m.deriv(*x, *x), epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn transforms() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBA7F);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let xs = Vector::<Vector<f64, 3>, 4>::init(|| rng.sample(&Normal)).into_array();
+            check(Shift::<f64, 3>::from_vector(rng.sample(&Normal)), xs);
+            check(rng.sample::<Linear<f64, 3>, _>(&Invertible), xs);
+            check(rng.sample::<Affine<f64, 3>, _>(&Invertible), xs);
+            check(
+                Rotation3::from_quaternion(rng.sample::<Quaternion<f64>, _>(&Unit)),
+                xs,
+            );
+        }
+    }
+}
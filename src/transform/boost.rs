@@ -0,0 +1,213 @@
+use crate::{
+    complex::SplitComplex,
+    transform::{Directional, Jacobian, Linear, Map},
+    Matrix, Transform, Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use core::ops::Neg;
+use num_traits::{Float, Num, One};
+
+/// Lorentz boost in 1+1 dimensions, i.e. hyperbolic rotation.
+///
+/// Acts on vectors `(t, x)` (with the speed of light equal to one) by multiplication
+/// by a unit split-complex number `cosh(φ) + sinh(φ) j`, where `φ` is the rapidity.
+/// It preserves the Minkowski interval `t² - x²`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Boost2<T> {
+    comp: SplitComplex<T>,
+}
+
+impl<T> Boost2<T> {
+    pub fn from_split_complex(comp: SplitComplex<T>) -> Self {
+        Self { comp }
+    }
+    pub fn into_split_complex(self) -> SplitComplex<T> {
+        self.comp
+    }
+}
+
+impl<T> From<SplitComplex<T>> for Boost2<T> {
+    fn from(comp: SplitComplex<T>) -> Self {
+        Self::from_split_complex(comp)
+    }
+}
+impl<T> From<Boost2<T>> for SplitComplex<T> {
+    fn from(boost: Boost2<T>) -> Self {
+        boost.into_split_complex()
+    }
+}
+
+impl<T> Boost2<T>
+where
+    T: Float,
+{
+    /// Boost with specified rapidity.
+    pub fn new(rapidity: T) -> Self {
+        Self {
+            comp: SplitComplex::from_polar(T::one(), rapidity),
+        }
+    }
+    /// Boost with specified velocity, must be less than one by absolute value.
+    pub fn from_velocity(velocity: T) -> Self {
+        Self::new(velocity.atanh())
+    }
+    pub fn rapidity(&self) -> T {
+        self.comp.arg()
+    }
+    pub fn velocity(&self) -> T {
+        self.comp.im() / self.comp.re()
+    }
+    /// Lorentz factor `γ = cosh(φ)`.
+    pub fn gamma(&self) -> T {
+        self.comp.re()
+    }
+}
+
+impl<T> Map<Vector<T, 2>> for Boost2<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: Vector<T, 2>) -> Vector<T, 2> {
+        (SplitComplex::from(pos) * self.comp).into()
+    }
+    fn deriv(&self, _pos: Vector<T, 2>, dir: Vector<T, 2>) -> Vector<T, 2> {
+        self.apply(dir)
+    }
+}
+
+impl<T> Transform<Vector<T, 2>> for Boost2<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn identity() -> Self {
+        Self {
+            comp: SplitComplex::one(),
+        }
+    }
+    fn inv(self) -> Self {
+        Self {
+            comp: self.comp.conj(),
+        }
+    }
+    fn chain(self, other: Self) -> Self {
+        Self {
+            comp: self.comp * other.comp,
+        }
+    }
+}
+
+impl<T> Jacobian<Vector<T, 2>> for Boost2<T>
+where
+    Self: Map<Vector<T, 2>>,
+    T: One + Copy,
+{
+    type Output = Matrix<T, 2, 2>;
+    type Scalar = T;
+    fn jacobian(&self, _: Vector<T, 2>) -> Matrix<T, 2, 2> {
+        self.to_linear().into_matrix()
+    }
+    fn jacobian_det(&self, _: Vector<T, 2>) -> T {
+        T::one()
+    }
+}
+
+impl<T> Directional<Vector<T, 2>> for Boost2<T>
+where
+    T: Float,
+{
+    fn apply_dir(&self, pos: Vector<T, 2>, dir: Vector<T, 2>) -> Vector<T, 2> {
+        self.to_linear().apply_dir(pos, dir)
+    }
+    fn apply_normal(&self, pos: Vector<T, 2>, normal: Vector<T, 2>) -> Vector<T, 2> {
+        self.to_linear().apply_normal(pos, normal)
+    }
+}
+
+impl<T> Boost2<T>
+where
+    T: Copy,
+{
+    pub fn to_linear(self) -> Linear<T, 2> {
+        Linear::from(self.comp.into_matrix())
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Boost2<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.comp, other.comp, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{distr::Normal, traits::Dot};
+    use approx::assert_abs_diff_eq;
+    use rand_::{distributions::Uniform, prelude::*};
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-12;
+
+    fn interval(v: Vector<f64, 2>) -> f64 {
+        v.x() * v.x() - v.y() * v.y()
+    }
+
+    #[test]
+    fn interval_preserved() {
+        let mut rng = XorShiftRng::seed_from_u64(0xB0057);
+        let range = Uniform::new(-2.0, 2.0);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let b = Boost2::new(rng.sample(range));
+            let v: Vector<f64, 2> = rng.sample(&Normal);
+            assert_abs_diff_eq!(interval(b.apply(v)), interval(v), epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn velocity_addition() {
+        let mut rng = XorShiftRng::seed_from_u64(0xB0058);
+        let range = Uniform::new(-0.99, 0.99);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (u, v): (f64, f64) = (rng.sample(range), rng.sample(range));
+            let (a, b) = (Boost2::from_velocity(u), Boost2::from_velocity(v));
+            let c = a.chain(b);
+            assert_abs_diff_eq!(c.velocity(), (u + v) / (1.0 + u * v), epsilon = EPS);
+            assert_abs_diff_eq!(c.rapidity(), a.rapidity() + b.rapidity(), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn chaining() {
+        let mut rng = XorShiftRng::seed_from_u64(0xB0059);
+        let range = Uniform::new(-2.0, 2.0);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a = Boost2::new(rng.sample(range));
+            let b = Boost2::new(rng.sample(range));
+            let v: Vector<f64, 2> = rng.sample(&Normal);
+            assert_abs_diff_eq!(a.chain(b).apply(v), a.apply(b.apply(v)), epsilon = 1e-10);
+            assert_abs_diff_eq!(a.chain(a.inv()), Boost2::identity(), epsilon = 1e-10);
+            assert_abs_diff_eq!(a.jacobian(v).dot(v), a.apply(v), epsilon = 1e-10);
+            assert_abs_diff_eq!(a.jacobian(v).det(), a.jacobian_det(v), epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn light_cone() {
+        let b = Boost2::from_velocity(0.6);
+        assert_abs_diff_eq!(b.gamma(), 1.25, epsilon = EPS);
+        let v = Vector::from([1.0, 1.0]);
+        assert_abs_diff_eq!(b.apply(v), v * 2.0, epsilon = EPS);
+        let w = Vector::from([1.0, -1.0]);
+        assert_abs_diff_eq!(b.apply(w), w * 0.5, epsilon = EPS);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{Transform, transform::Directional, traits::Normalize};
+use crate::{Transform, transform::{Directional, Jacobian, Map}, traits::{Dot, Normalize}};
 #[cfg(feature = "approx")]
 use approx::{abs_diff_eq, AbsDiffEq};
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::Mul};
 
 /// Transformation obtained by combining two other ones.
 ///
@@ -65,6 +65,21 @@ pub trait Reorder<B: Transform<T>, T>: Transform<T> {
     fn reorder(self, other: B) -> (B, Self);
 }
 
+impl<A, B, T> Map<T> for Chain<A, B, T>
+where
+    A: Map<T>,
+    B: Map<T>,
+    T: Copy,
+{
+    fn apply(&self, pos: T) -> T {
+        self.outer.apply(self.inner.apply(pos))
+    }
+    fn deriv(&self, pos: T, dir: T) -> T {
+        self.outer
+            .deriv(self.inner.apply(pos), self.inner.deriv(pos, dir))
+    }
+}
+
 impl<A, B, T> Transform<T> for Chain<A, B, T>
 where
     A: Transform<T> + Reorder<B, T>,
@@ -77,19 +92,32 @@ where
     fn inv(self) -> Self {
         self.inner.inv().reorder(self.outer.inv()).into()
     }
-    fn apply(&self, pos: T) -> T {
-        self.outer.apply(self.inner.apply(pos))
-    }
-    fn deriv(&self, pos: T, dir: T) -> T {
-        self.outer
-            .deriv(self.inner.apply(pos), self.inner.deriv(pos, dir))
-    }
     fn chain(self, other: Self) -> Self {
         let (roa, rsb) = self.inner.reorder(other.outer);
         Self::new(self.outer.chain(roa), rsb.chain(other.inner))
     }
 }
 
+impl<A, B, T> Jacobian<T> for Chain<A, B, T>
+where
+    A: Jacobian<T>,
+    B: Jacobian<T, Output = A::Output, Scalar = A::Scalar>,
+    A::Output: Dot<Output = A::Output>,
+    A::Scalar: Mul<Output = A::Scalar>,
+    T: Copy,
+{
+    type Output = A::Output;
+    type Scalar = A::Scalar;
+    fn jacobian(&self, pos: T) -> A::Output {
+        self.outer
+            .jacobian(self.inner.apply(pos))
+            .dot(self.inner.jacobian(pos))
+    }
+    fn jacobian_det(&self, pos: T) -> A::Scalar {
+        self.outer.jacobian_det(self.inner.apply(pos)) * self.inner.jacobian_det(pos)
+    }
+}
+
 impl<A, B, T> Directional<T> for Chain<A, B, T>
 where
     A: Directional<T>,
@@ -108,13 +136,14 @@ where
 #[cfg(feature = "approx")]
 impl<A, B, T> AbsDiffEq for Chain<A, B, T>
 where
-    A: AbsDiffEq<Epsilon = T>,
-    B: AbsDiffEq<Epsilon = T>,
-    T: AbsDiffEq<Epsilon = T> + Copy,
+    A: AbsDiffEq,
+    B: AbsDiffEq<Epsilon = A::Epsilon>,
+    A::Epsilon: Copy,
+    T: PartialEq,
 {
-    type Epsilon = T;
+    type Epsilon = A::Epsilon;
     fn default_epsilon() -> Self::Epsilon {
-        T::default_epsilon()
+        A::default_epsilon()
     }
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
         abs_diff_eq!(self.outer, other.outer, epsilon = epsilon)
@@ -0,0 +1,249 @@
+use crate::{
+    traits::Dot,
+    transform::{Directional, Jacobian, Map},
+    Transform,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use core::{marker::PhantomData, ops::Mul};
+
+/// Lazy composition of two mappings of possibly different types.
+///
+/// Mappings are applied in the following order: `A(B(x))`.
+///
+/// In contrast to `Chain` the components are never merged together,
+/// so any sequence of mappings could be composed, e.g. `Scale`, then `Rotation3`, then `Shift`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Compose<A, B, T> {
+    outer: A,
+    inner: B,
+    phantom: PhantomData<T>,
+}
+
+impl<A, B, T> Compose<A, B, T> {
+    /// Construct composition from outer and inner mappings.
+    pub fn new(outer: A, inner: B) -> Self {
+        Self {
+            outer,
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Split into two components.
+    pub fn split(self) -> (A, B) {
+        (self.outer, self.inner)
+    }
+
+    /// Get outer mapping reference.
+    pub fn outer(&self) -> &A {
+        &self.outer
+    }
+    /// Get outer mapping mutable reference.
+    pub fn outer_mut(&mut self) -> &mut A {
+        &mut self.outer
+    }
+
+    /// Get inner mapping reference.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+    /// Get inner mapping mutable reference.
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+}
+
+impl<A, B, T> From<(A, B)> for Compose<A, B, T> {
+    fn from((a, b): (A, B)) -> Self {
+        Self::new(a, b)
+    }
+}
+impl<A, B, T> From<Compose<A, B, T>> for (A, B) {
+    fn from(c: Compose<A, B, T>) -> Self {
+        c.split()
+    }
+}
+
+impl<A, B, T> Map<T> for Compose<A, B, T>
+where
+    A: Map<T>,
+    B: Map<T>,
+    T: Copy,
+{
+    fn apply(&self, pos: T) -> T {
+        self.outer.apply(self.inner.apply(pos))
+    }
+    fn deriv(&self, pos: T, dir: T) -> T {
+        self.outer
+            .deriv(self.inner.apply(pos), self.inner.deriv(pos, dir))
+    }
+}
+
+impl<A, B, T> Jacobian<T> for Compose<A, B, T>
+where
+    A: Jacobian<T>,
+    B: Jacobian<T, Output = A::Output, Scalar = A::Scalar>,
+    A::Output: Dot<Output = A::Output>,
+    A::Scalar: Mul<Output = A::Scalar>,
+    T: Copy,
+{
+    type Output = A::Output;
+    type Scalar = A::Scalar;
+    fn jacobian(&self, pos: T) -> A::Output {
+        self.outer
+            .jacobian(self.inner.apply(pos))
+            .dot(self.inner.jacobian(pos))
+    }
+    fn jacobian_det(&self, pos: T) -> A::Scalar {
+        self.outer.jacobian_det(self.inner.apply(pos)) * self.inner.jacobian_det(pos)
+    }
+}
+
+impl<A, B, T> Directional<T> for Compose<A, B, T>
+where
+    A: Directional<T>,
+    B: Directional<T>,
+    T: Copy,
+{
+    fn apply_dir(&self, pos: T, dir: T) -> T {
+        self.outer
+            .apply_dir(self.inner.apply(pos), self.inner.apply_dir(pos, dir))
+    }
+    fn apply_normal(&self, pos: T, normal: T) -> T {
+        self.outer
+            .apply_normal(self.inner.apply(pos), self.inner.apply_normal(pos, normal))
+    }
+}
+
+impl<A, B, T> Compose<A, B, T>
+where
+    A: Transform<T>,
+    B: Transform<T>,
+{
+    /// Inverse composition, components are inverted and applied in reverse order.
+    ///
+    /// Available only when both components are invertible transformations.
+    pub fn inv(self) -> Compose<B, A, T> {
+        Compose::new(self.inner.inv(), self.outer.inv())
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<A, B, T> AbsDiffEq for Compose<A, B, T>
+where
+    A: AbsDiffEq,
+    B: AbsDiffEq<Epsilon = A::Epsilon>,
+    A::Epsilon: Copy,
+    T: PartialEq,
+{
+    type Epsilon = A::Epsilon;
+    fn default_epsilon() -> Self::Epsilon {
+        A::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.outer, other.outer, epsilon = epsilon)
+            && abs_diff_eq!(self.inner, other.inner, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Invertible, Normal, Uniform, Unit},
+        transform::{Linear, Rotation2, Rotation3, Scale, Shift},
+        vector::Vector2,
+        vector::Vector3,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-12;
+
+    #[test]
+    fn pipeline() {
+        let mut rng = XorShiftRng::seed_from_u64(0xC0A);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let s: Scale<f64> = rng.sample(&Normal);
+            let r: Rotation3<f64> = rng.sample(&Uniform);
+            let t: Shift<f64, 3> = rng.sample(&Normal);
+            let l: Linear<f64, 3> = rng.sample(&Invertible);
+            let x: Vector3<f64> = rng.sample(&Normal);
+            let d: Vector3<f64> = rng.sample(&Normal);
+
+            let c = s.then(r).then(t).then(l);
+            assert_abs_diff_eq!(
+                c.apply(x),
+                l.apply(t.apply(r.apply(s.apply(x)))),
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(
+                c.deriv(x, d),
+                l.deriv(t.apply(r.apply(s.apply(x))), r.deriv(x, s.deriv(x, d))),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    fn inversion() {
+        let mut rng = XorShiftRng::seed_from_u64(0xC0B);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let t: Shift<f64, 3> = rng.sample(&Normal);
+            let l: Linear<f64, 3> = rng.sample(&Invertible);
+            let x: Vector3<f64> = rng.sample(&Normal);
+
+            let c = t.then(l);
+            assert_abs_diff_eq!(c.inv().apply(c.apply(x)), x, epsilon = EPS);
+            assert_abs_diff_eq!(c.apply(c.inv().apply(x)), x, epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn directional() {
+        let mut rng = XorShiftRng::seed_from_u64(0xC0C);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let l: Linear<f64, 3> = rng.sample(&Invertible);
+            let r: Rotation3<f64> = rng.sample(&Uniform);
+            let t: Shift<f64, 3> = rng.sample(&Normal);
+            let x: Vector3<f64> = rng.sample(&Normal);
+            let d: Vector3<f64> = rng.sample(&Unit);
+
+            let c = l.then(r).then(t);
+            let m = r.to_linear().chain(l);
+            assert_abs_diff_eq!(c.apply_dir(x, d), m.apply_dir(x, d), epsilon = EPS);
+            assert_abs_diff_eq!(c.apply_normal(x, d), m.apply_normal(x, d), epsilon = EPS);
+        }
+    }
+
+    #[test]
+    fn jacobian() {
+        let mut rng = XorShiftRng::seed_from_u64(0xC0D);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let s: Scale<f64> = rng.sample(&Normal);
+            let r: Rotation3<f64> = rng.sample(&Uniform);
+            let t: Shift<f64, 3> = rng.sample(&Normal);
+            let l: Linear<f64, 3> = rng.sample(&Invertible);
+            let x: Vector3<f64> = rng.sample(&Normal);
+            let d: Vector3<f64> = rng.sample(&Normal);
+
+            let c = s.then(r).then(t).then(l);
+            assert_abs_diff_eq!(c.jacobian(x).dot(d), c.deriv(x, d), epsilon = EPS);
+            assert_abs_diff_eq!(c.jacobian(x).det(), c.jacobian_det(x), epsilon = EPS);
+            assert_abs_diff_eq!(
+                c.jacobian_det(x),
+                s.into_mag().powi(3) * l.into_matrix().det(),
+                epsilon = EPS
+            );
+
+            let r2: Rotation2<f64> = rng.sample(&Uniform);
+            let y: Vector2<f64> = rng.sample(&Normal);
+            let e: Vector2<f64> = rng.sample(&Normal);
+            assert_abs_diff_eq!(r2.jacobian(y).dot(e), r2.deriv(y, e), epsilon = EPS);
+            assert_abs_diff_eq!(r2.jacobian(y).det(), r2.jacobian_det(y), epsilon = EPS);
+        }
+    }
+}
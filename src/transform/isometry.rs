@@ -0,0 +1,214 @@
+#[cfg(feature = "rand")]
+use crate::distr::{Normal, Uniform};
+use crate::{
+    transform::{Affine, Chain, Compose, Rotation2, Rotation3, Scale, Shift},
+    Map, Transform, Vector,
+};
+use core::ops::Neg;
+use num_traits::{Float, Num, NumCast};
+#[cfg(feature = "rand")]
+use rand_::{distributions::Distribution, Rng};
+
+/// Two-dimensional rigid motion (rotation followed by shift).
+pub type Isometry2<T> = Chain<Shift<T, 2>, Rotation2<T>, Vector<T, 2>>;
+/// Three-dimensional rigid motion (rotation followed by shift).
+pub type Isometry3<T> = Chain<Shift<T, 3>, Rotation3<T>, Vector<T, 3>>;
+
+/// Two-dimensional similarity (uniform scale followed by rigid motion).
+pub type Similarity2<T> = Compose<Isometry2<T>, Scale<T>, Vector<T, 2>>;
+/// Three-dimensional similarity (uniform scale followed by rigid motion).
+pub type Similarity3<T> = Compose<Isometry3<T>, Scale<T>, Vector<T, 3>>;
+
+impl<T> Isometry2<T>
+where
+    T: Copy,
+{
+    /// Rotation component of the transformation.
+    pub fn rotation(&self) -> Rotation2<T> {
+        *self.inner()
+    }
+
+    /// Translation component of the transformation.
+    pub fn translation(&self) -> Shift<T, 2> {
+        *self.outer()
+    }
+}
+
+impl<T> Isometry2<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Apply inverse transformation without computing the inverse itself.
+    pub fn inverse_apply(&self, pos: Vector<T, 2>) -> Vector<T, 2> {
+        self.rotation()
+            .inv()
+            .apply(pos - self.translation().into_vector())
+    }
+}
+
+impl<T> Isometry2<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    /// Convert to general affine transformation.
+    pub fn to_affine(self) -> Affine<T, 2> {
+        let (shift, rot) = self.split();
+        Affine::new(shift, rot.to_linear())
+    }
+}
+
+impl<T> Isometry3<T>
+where
+    T: Copy,
+{
+    /// Rotation component of the transformation.
+    pub fn rotation(&self) -> Rotation3<T> {
+        *self.inner()
+    }
+
+    /// Translation component of the transformation.
+    pub fn translation(&self) -> Shift<T, 3> {
+        *self.outer()
+    }
+}
+
+impl<T> Isometry3<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Apply inverse transformation without computing the inverse itself.
+    pub fn inverse_apply(&self, pos: Vector<T, 3>) -> Vector<T, 3> {
+        self.rotation()
+            .inv()
+            .apply(pos - self.translation().into_vector())
+    }
+}
+
+impl<T> Isometry3<T>
+where
+    T: Float + NumCast,
+{
+    /// Convert to general affine transformation.
+    pub fn to_affine(self) -> Affine<T, 3> {
+        let (shift, rot) = self.split();
+        Affine::new(shift, rot.to_linear())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> Distribution<Isometry2<T>> for Normal
+where
+    Normal: Distribution<Shift<T, 2>>,
+    Uniform: Distribution<Rotation2<T>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Isometry2<T> {
+        Isometry2::new(self.sample(rng), rng.sample(&Uniform))
+    }
+}
+#[cfg(feature = "rand")]
+impl<T> Distribution<Isometry3<T>> for Normal
+where
+    Normal: Distribution<Shift<T, 3>>,
+    Uniform: Distribution<Rotation3<T>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Isometry3<T> {
+        Isometry3::new(self.sample(rng), rng.sample(&Uniform))
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::Unit,
+        transform::Directional,
+        vector::{Vector2, Vector3},
+    };
+    use approx::assert_abs_diff_eq;
+    use num_traits::Zero;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-12;
+
+    macro_rules! isometry_tests {
+        ($name:ident, $X:ident, $V:ident, $seed:expr) => {
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn mapping() {
+                    let mut rng = XorShiftRng::seed_from_u64($seed);
+                    for _ in 0..SAMPLE_ATTEMPTS {
+                        let m: $X<f64> = rng.sample(&Normal);
+                        let a: $V<f64> = rng.sample(&Normal);
+                        let b: $V<f64> = rng.sample(&Normal);
+                        assert_abs_diff_eq!(
+                            (m.apply(a) - m.apply(b)).length(),
+                            (a - b).length(),
+                            epsilon = EPS
+                        );
+                        assert_abs_diff_eq!(m.to_affine().apply(a), m.apply(a), epsilon = EPS);
+                    }
+                }
+
+                #[test]
+                fn chaining() {
+                    let mut rng = XorShiftRng::seed_from_u64($seed + 1);
+                    for _ in 0..SAMPLE_ATTEMPTS {
+                        let a: $X<f64> = rng.sample(&Normal);
+                        let b: $X<f64> = rng.sample(&Normal);
+                        let c: $V<f64> = rng.sample(&Normal);
+                        let z = $V::<f64>::zero();
+
+                        assert_abs_diff_eq!(
+                            a.chain(b).apply(c),
+                            a.apply(b.apply(c)),
+                            epsilon = EPS
+                        );
+                        assert_abs_diff_eq!(
+                            a.chain(b).deriv(z, c),
+                            a.deriv(z, b.deriv(z, c)),
+                            epsilon = EPS
+                        );
+                    }
+                }
+
+                #[test]
+                fn inversion() {
+                    let mut rng = XorShiftRng::seed_from_u64($seed + 2);
+                    for _ in 0..SAMPLE_ATTEMPTS {
+                        let a: $X<f64> = rng.sample(&Normal);
+                        let x: $V<f64> = rng.sample(&Normal);
+
+                        assert_abs_diff_eq!(a.chain(a.inv()), $X::identity(), epsilon = EPS);
+                        assert_abs_diff_eq!(a.inv().apply(a.apply(x)), x, epsilon = EPS);
+                        assert_abs_diff_eq!(a.inverse_apply(x), a.inv().apply(x), epsilon = EPS);
+                        assert_abs_diff_eq!(a.inverse_apply(a.apply(x)), x, epsilon = EPS);
+                    }
+                }
+
+                #[test]
+                fn directional() {
+                    let mut rng = XorShiftRng::seed_from_u64($seed + 3);
+                    for _ in 0..SAMPLE_ATTEMPTS {
+                        let a: $X<f64> = rng.sample(&Normal);
+                        let p: $V<f64> = rng.sample(&Normal);
+                        let d: $V<f64> = rng.sample(&Unit);
+
+                        assert_abs_diff_eq!(
+                            a.apply_dir(p, d),
+                            a.rotation().apply(d),
+                            epsilon = EPS
+                        );
+                        assert_abs_diff_eq!(a.apply_normal(p, d), a.apply_dir(p, d), epsilon = EPS);
+                    }
+                }
+            }
+        };
+    }
+
+    isometry_tests!(i2d, Isometry2, Vector2, 0x150);
+    isometry_tests!(i3d, Isometry3, Vector3, 0x1530);
+}
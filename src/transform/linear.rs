@@ -2,7 +2,7 @@
 use crate::distr::{Invertible, Normal};
 use crate::{
     traits::{Dot, Normalize},
-    transform::{Reorder, Shift, Directional},
+    transform::{Reorder, Shift, Directional, Jacobian, Map},
     Matrix, Transform, Vector,
 };
 #[cfg(feature = "approx")]
@@ -24,7 +24,7 @@ pub type Linear3<T> = Linear<T, 3>;
 pub type Linear4<T> = Linear<T, 4>;
 
 impl<T, const N: usize> Linear<T, N> {
-    pub fn from_matrix(lin: Matrix<T, N, N>) -> Self {
+    pub const fn from_matrix(lin: Matrix<T, N, N>) -> Self {
         Self { lin }
     }
     pub fn into_matrix(self) -> Matrix<T, N, N> {
@@ -42,6 +42,18 @@ impl<T, const N: usize> From<Linear<T, N>> for Matrix<T, N, N> {
     }
 }
 
+impl<T, const N: usize> Map<Vector<T, N>> for Linear<T, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
+        self.lin.dot(pos)
+    }
+    fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        self.apply(dir)
+    }
+}
+
 impl<T, const N: usize> Transform<Vector<T, N>> for Linear<T, N>
 where
     T: Neg<Output = T> + Num + Copy,
@@ -54,12 +66,6 @@ where
             lin: self.lin.inv(),
         }
     }
-    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
-        self.lin.dot(pos)
-    }
-    fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
-        self.apply(dir)
-    }
     fn chain(self, other: Self) -> Self {
         Self {
             lin: self.lin.dot(other.lin),
@@ -67,6 +73,20 @@ where
     }
 }
 
+impl<T, const N: usize> Jacobian<Vector<T, N>> for Linear<T, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    type Output = Matrix<T, N, N>;
+    type Scalar = T;
+    fn jacobian(&self, _: Vector<T, N>) -> Matrix<T, N, N> {
+        self.lin
+    }
+    fn jacobian_det(&self, _: Vector<T, N>) -> T {
+        self.lin.det()
+    }
+}
+
 impl<T, const N: usize> Linear<T, N>
 where
     T: Neg<Output = T> + Num + Copy,
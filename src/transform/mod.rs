@@ -1,5 +1,9 @@
 mod affine;
+mod batch;
+mod boost;
 mod chain;
+mod compose;
+mod isometry;
 mod linear;
 mod rotation;
 mod shift;
@@ -7,34 +11,69 @@ mod scale;
 mod moebius;
 
 pub use affine::*;
+pub use boost::*;
 pub use chain::*;
+pub use compose::*;
+pub use isometry::*;
 pub use linear::*;
 pub use rotation::*;
 pub use shift::*;
 pub use scale::*;
 pub use moebius::*;
 
+/// Mapping of points which derivative is known.
+///
+/// Unlike `Transform` it isn't required to form a group,
+/// so mappings of different types could be freely composed.
+pub trait Map<T> {
+    /// Perform the mapping itself.
+    fn apply(&self, pos: T) -> T;
+    /// Find mapping directional derivative at specified point.
+    fn deriv(&self, pos: T, dir: T) -> T;
+
+    /// Compose with the mapping which is applied after this one.
+    ///
+    /// `C = A.then(B)` means that `C(x) = B(A(x))`.
+    fn then<B: Map<T>>(self, next: B) -> Compose<B, Self, T>
+    where
+        Self: Sized,
+    {
+        Compose::new(next, self)
+    }
+}
+
 /// General tansformation trait.
 ///
 /// It's assumed that transfomation is a group.
-pub trait Transform<T> {
+pub trait Transform<T>: Map<T> {
     /// Identity transformation.
     fn identity() -> Self;
     /// Inverse transformation.
     fn inv(self) -> Self;
 
-    /// Perform the transformation itself.
-    fn apply(&self, pos: T) -> T;
-    /// Find transformation directional derivative at specified point.
-    fn deriv(&self, pos: T, dir: T) -> T;
-
     /// Chain two transformations into a new one.
     ///
     /// `C = A.chain(B)` means that `C(x) = A(B(x))`.
     fn chain(self, other: Self) -> Self;
 }
 
-pub trait Directional<T>: Transform<T> {
+/// Mapping which Jacobian matrix could be computed directly.
+pub trait Jacobian<T>: Map<T> {
+    /// Type of the Jacobian matrix.
+    type Output;
+    /// Type of the Jacobian determinant.
+    type Scalar;
+
+    /// Jacobian matrix at specified point.
+    ///
+    /// Its product with any direction is equal to `deriv` at the same point.
+    fn jacobian(&self, pos: T) -> Self::Output;
+
+    /// Determinant of the Jacobian matrix at specified point, i.e. local volume change factor.
+    fn jacobian_det(&self, pos: T) -> Self::Scalar;
+}
+
+pub trait Directional<T>: Map<T> {
     /// Returns the result of the direction transformation at the specified position.
     fn apply_dir(&self, pos: T, dir: T) -> T;
 
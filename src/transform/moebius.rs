@@ -1,35 +1,24 @@
 pub use crate::complex::Moebius;
-use crate::{complex::{Complex, Quaternion}, Transform, transform::Directional, traits::Normalize};
+use crate::{complex::{Complex, Quaternion}, matrix::Matrix2x2, Transform, transform::{Directional, Jacobian, Map}, traits::Normalize};
 use core::ops::Neg;
 use num_traits::{Num, NumCast};
 
 
-impl<T> Transform<Complex<T>> for Moebius<Complex<T>>
+impl<T> Map<Complex<T>> for Moebius<Complex<T>>
 where
     T: Neg<Output = T> + Num + Copy
 {
-    fn identity() -> Self {
-        Moebius::identity()
-    }
-    fn inv(self) -> Self {
-        Moebius::inv(self)
-    }
-
     fn apply(&self, pos: Complex<T>) -> Complex<T> {
         Moebius::apply(self, pos)
     }
     fn deriv(&self, pos: Complex<T>, dir: Complex<T>) -> Complex<T> {
         Moebius::deriv(self, pos) * dir
     }
-
-    fn chain(self, other: Self) -> Self {
-        Moebius::chain(self, other)
-    }
 }
 
-impl<T> Transform<Quaternion<T>> for Moebius<Complex<T>>
+impl<T> Transform<Complex<T>> for Moebius<Complex<T>>
 where
-    T: Neg<Output = T> + Num + NumCast + Copy
+    T: Neg<Output = T> + Num + Copy
 {
     fn identity() -> Self {
         Moebius::identity()
@@ -38,18 +27,54 @@ where
         Moebius::inv(self)
     }
 
+    fn chain(self, other: Self) -> Self {
+        Moebius::chain(self, other)
+    }
+}
+
+impl<T> Map<Quaternion<T>> for Moebius<Complex<T>>
+where
+    T: Neg<Output = T> + Num + NumCast + Copy
+{
     fn apply(&self, pos: Quaternion<T>) -> Quaternion<T> {
         Moebius::apply(self, pos)
     }
     fn deriv(&self, pos: Quaternion<T>, dir: Quaternion<T>) -> Quaternion<T> {
         Moebius::deriv_dir(self, pos, dir)
     }
+}
+
+impl<T> Transform<Quaternion<T>> for Moebius<Complex<T>>
+where
+    T: Neg<Output = T> + Num + NumCast + Copy
+{
+    fn identity() -> Self {
+        Moebius::identity()
+    }
+    fn inv(self) -> Self {
+        Moebius::inv(self)
+    }
 
     fn chain(self, other: Self) -> Self {
         Moebius::chain(self, other)
     }
 }
 
+/// Jacobian of the complex plane mapping treated as a real two-dimensional one.
+impl<T> Jacobian<Complex<T>> for Moebius<Complex<T>>
+where
+    T: Neg<Output = T> + Num + Copy
+{
+    type Output = Matrix2x2<T>;
+    type Scalar = T;
+    fn jacobian(&self, pos: Complex<T>) -> Matrix2x2<T> {
+        Moebius::deriv(self, pos).into_matrix()
+    }
+    fn jacobian_det(&self, pos: Complex<T>) -> T {
+        Moebius::deriv(self, pos).norm_sqr()
+    }
+}
+
 impl<T> Directional<Complex<T>> for Moebius<Complex<T>>
 where
     Self: Transform<Complex<T>>,
@@ -1,7 +1,7 @@
 #[cfg(feature = "rand")]
 use crate::distr::{Uniform, Unit};
 use crate::{
-    transform::{Linear, Reorder, Shift, Directional},
+    transform::{Linear, Reorder, Shift, Directional, Jacobian, Map},
     Complex, Matrix, Quaternion, Transform, Vector,
     traits::Dot,
 };
@@ -23,7 +23,7 @@ pub struct Rotation2<T> {
 }
 
 impl<T> Rotation2<T> {
-    pub fn from_complex(comp: Complex<T>) -> Self {
+    pub const fn from_complex(comp: Complex<T>) -> Self {
         Self { comp }
     }
     pub fn into_complex(self) -> Complex<T> {
@@ -56,6 +56,18 @@ where
     }
 }
 
+impl<T> Map<Vector<T, 2>> for Rotation2<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: Vector<T, 2>) -> Vector<T, 2> {
+        (<Vector<T, 2> as Into<Complex<T>>>::into(pos) * self.into_complex()).into()
+    }
+    fn deriv(&self, _pos: Vector<T, 2>, dir: Vector<T, 2>) -> Vector<T, 2> {
+        self.apply(dir)
+    }
+}
+
 impl<T> Transform<Vector<T, 2>> for Rotation2<T>
 where
     T: Neg<Output = T> + Num + Copy,
@@ -70,12 +82,6 @@ where
             comp: self.comp.conj(),
         }
     }
-    fn apply(&self, pos: Vector<T, 2>) -> Vector<T, 2> {
-        (<Vector<T, 2> as Into<Complex<T>>>::into(pos) * self.into_complex()).into()
-    }
-    fn deriv(&self, _pos: Vector<T, 2>, dir: Vector<T, 2>) -> Vector<T, 2> {
-        self.apply(dir)
-    }
     fn chain(self, other: Self) -> Self {
         Self {
             comp: self.comp * other.comp,
@@ -83,6 +89,21 @@ where
     }
 }
 
+impl<T> Jacobian<Vector<T, 2>> for Rotation2<T>
+where
+    Self: Map<Vector<T, 2>>,
+    T: Neg<Output = T> + One + Copy,
+{
+    type Output = Matrix<T, 2, 2>;
+    type Scalar = T;
+    fn jacobian(&self, _: Vector<T, 2>) -> Matrix<T, 2, 2> {
+        self.to_linear().into_matrix()
+    }
+    fn jacobian_det(&self, _: Vector<T, 2>) -> T {
+        T::one()
+    }
+}
+
 impl<T> Directional<Vector<T, 2>> for Rotation2<T>
 where
     Self: Transform<Vector<T, 2>>,
@@ -142,7 +163,7 @@ pub struct Rotation3<T> {
 }
 
 impl<T> Rotation3<T> {
-    pub fn from_quaternion(quat: Quaternion<T>) -> Self {
+    pub const fn from_quaternion(quat: Quaternion<T>) -> Self {
         Self { quat }
     }
     pub fn into_quaternion(self) -> Quaternion<T> {
@@ -181,6 +202,21 @@ where
     }
 }
 
+impl<T> Map<Vector<T, 3>> for Rotation3<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: Vector<T, 3>) -> Vector<T, 3> {
+        let qpos = Quaternion::from_scalar_and_vector3(T::zero(), pos);
+        let qres = self.quat * qpos * self.quat.conj();
+        let (_, res) = qres.into();
+        res
+    }
+    fn deriv(&self, _pos: Vector<T, 3>, dir: Vector<T, 3>) -> Vector<T, 3> {
+        self.apply(dir)
+    }
+}
+
 impl<T> Transform<Vector<T, 3>> for Rotation3<T>
 where
     T: Neg<Output = T> + Num + Copy,
@@ -195,15 +231,6 @@ where
             quat: self.quat.conj(),
         }
     }
-    fn apply(&self, pos: Vector<T, 3>) -> Vector<T, 3> {
-        let qpos = Quaternion::from_scalar_and_vector3(T::zero(), pos);
-        let qres = self.quat * qpos * self.quat.conj();
-        let (_, res) = qres.into();
-        res
-    }
-    fn deriv(&self, _pos: Vector<T, 3>, dir: Vector<T, 3>) -> Vector<T, 3> {
-        self.apply(dir)
-    }
     fn chain(self, other: Self) -> Self {
         Self {
             quat: self.quat * other.quat,
@@ -211,6 +238,20 @@ where
     }
 }
 
+impl<T> Jacobian<Vector<T, 3>> for Rotation3<T>
+where
+    T: Float + NumCast,
+{
+    type Output = Matrix<T, 3, 3>;
+    type Scalar = T;
+    fn jacobian(&self, _: Vector<T, 3>) -> Matrix<T, 3, 3> {
+        self.to_linear().into_matrix()
+    }
+    fn jacobian_det(&self, _: Vector<T, 3>) -> T {
+        T::one()
+    }
+}
+
 impl<T> Directional<Vector<T, 3>> for Rotation3<T>
 where
     Self: Transform<Vector<T, 3>>,
@@ -251,6 +292,54 @@ where
     }
 }
 
+impl<T> Rotation3<T>
+where
+    T: Float + NumCast,
+{
+    /// Rotation from the linear transformation, its matrix must be orthogonal with unit determinant.
+    pub fn from_linear(lin: Linear<T, 3>) -> Self {
+        let m = lin.into_matrix();
+        let (t1, t4) = (T::one(), T::from(4.0).unwrap());
+        let (m00, m11, m22) = (m[(0, 0)], m[(1, 1)], m[(2, 2)]);
+        let tr = m00 + m11 + m22;
+        // The largest of quaternion components is computed first to avoid cancellation.
+        let quat = if tr > T::zero() {
+            let s = (t1 + tr).sqrt() * (t1 + t1);
+            Quaternion::new(
+                s / t4,
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (t1 + m00 - m11 - m22).sqrt() * (t1 + t1);
+            Quaternion::new(
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                s / t4,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+            )
+        } else if m11 > m22 {
+            let s = (t1 + m11 - m00 - m22).sqrt() * (t1 + t1);
+            Quaternion::new(
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                s / t4,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+            )
+        } else {
+            let s = (t1 + m22 - m00 - m11).sqrt() * (t1 + t1);
+            Quaternion::new(
+                (m[(1, 0)] - m[(0, 1)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                s / t4,
+            )
+        };
+        Self::from_quaternion(quat)
+    }
+}
+
 impl<T> Rotation3<T>
 where
     T: Float + NumCast + FloatConst,
@@ -478,6 +567,16 @@ mod tests {
         }
 
 
+        #[test]
+        fn from_linear() {
+            let mut rng = XorShiftRng::seed_from_u64(0x2DE);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Rotation3<f64> = rng.sample(&Uniform);
+                let b = Rotation3::from_linear(a.to_linear());
+                assert_abs_diff_eq!(b.to_linear(), a.to_linear(), epsilon = 1e-12);
+            }
+        }
+
         #[test]
         fn look_to_the_direction() {
             const EPS: f64 = 1e-14;
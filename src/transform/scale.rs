@@ -1,12 +1,12 @@
 #[cfg(feature = "rand")]
 use crate::distr::Normal;
 use crate::{
-    transform::{Reorder, Shift, Directional},
-    Transform, Vector,
+    transform::{Reorder, Shift, Directional, Jacobian, Map},
+    Matrix, Transform, Vector,
 };
 #[cfg(feature = "approx")]
 use approx::{abs_diff_eq, AbsDiffEq};
-use num_traits::{Num, Inv};
+use num_traits::{Num, Inv, One};
 #[cfg(feature = "rand")]
 use rand_::{distributions::Distribution, Rng};
 
@@ -38,6 +38,18 @@ impl<T> From<Scale<T>> for T {
 }
 */
 
+impl<T, const N: usize> Map<Vector<T, N>> for Scale<T>
+where
+    T: Num + Copy,
+{
+    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
+        pos * self.mag
+    }
+    fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        self.apply(dir)
+    }
+}
+
 impl<T, const N: usize> Transform<Vector<T, N>> for Scale<T>
 where
     T: Num + Inv<Output = T> + Copy,
@@ -48,17 +60,26 @@ where
     fn inv(self) -> Self {
         Self { mag: self.mag.inv() }
     }
-    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
-        pos * self.mag
-    }
-    fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
-        self.apply(dir)
-    }
     fn chain(self, other: Self) -> Self {
         Self { mag: self.mag * other.mag }
     }
 }
 
+impl<T, const N: usize> Jacobian<Vector<T, N>> for Scale<T>
+where
+    Self: Map<Vector<T, N>>,
+    T: Num + Copy,
+{
+    type Output = Matrix<T, N, N>;
+    type Scalar = T;
+    fn jacobian(&self, _: Vector<T, N>) -> Matrix<T, N, N> {
+        Matrix::one() * self.mag
+    }
+    fn jacobian_det(&self, _: Vector<T, N>) -> T {
+        (0..N).fold(T::one(), |a, _| a * self.mag)
+    }
+}
+
 impl<T, const N: usize> Directional<Vector<T, N>> for Scale<T>
 where
     Self: Transform<Vector<T, N>>,
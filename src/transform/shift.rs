@@ -1,10 +1,10 @@
 #[cfg(feature = "rand")]
 use crate::distr::Normal;
-use crate::{Transform, Vector, transform::Directional};
+use crate::{Matrix, Transform, Vector, transform::{Directional, Jacobian, Map}};
 #[cfg(feature = "approx")]
 use approx::{abs_diff_eq, AbsDiffEq};
 use core::ops::Neg;
-use num_traits::{Num, Zero};
+use num_traits::{Num, One, Zero};
 #[cfg(feature = "rand")]
 use rand_::{distributions::Distribution, Rng};
 
@@ -20,7 +20,7 @@ pub type Shift3<T> = Shift<T, 3>;
 pub type Shift4<T> = Shift<T, 4>;
 
 impl<T, const N: usize> Shift<T, N> {
-    pub fn from_vector(pos: Vector<T, N>) -> Self {
+    pub const fn from_vector(pos: Vector<T, N>) -> Self {
         Self { pos }
     }
     pub fn into_vector(self) -> Vector<T, N> {
@@ -38,6 +38,18 @@ impl<T, const N: usize> From<Shift<T, N>> for Vector<T, N> {
     }
 }
 
+impl<T, const N: usize> Map<Vector<T, N>> for Shift<T, N>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
+        pos + self.pos
+    }
+    fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        dir
+    }
+}
+
 impl<T, const N: usize> Transform<Vector<T, N>> for Shift<T, N>
 where
     T: Neg<Output = T> + Num + Copy,
@@ -50,12 +62,6 @@ where
     fn inv(self) -> Self {
         Self { pos: -self.pos }
     }
-    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
-        pos + self.pos
-    }
-    fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
-        dir
-    }
     fn chain(self, other: Self) -> Self {
         Self {
             pos: self.pos + other.pos,
@@ -63,6 +69,21 @@ where
     }
 }
 
+impl<T, const N: usize> Jacobian<Vector<T, N>> for Shift<T, N>
+where
+    Self: Map<Vector<T, N>>,
+    T: One + Zero,
+{
+    type Output = Matrix<T, N, N>;
+    type Scalar = T;
+    fn jacobian(&self, _: Vector<T, N>) -> Matrix<T, N, N> {
+        Matrix::one()
+    }
+    fn jacobian_det(&self, _: Vector<T, N>) -> T {
+        T::one()
+    }
+}
+
 impl<T, const N: usize> Directional<Vector<T, N>> for Shift<T, N>
 where
     Self: Transform<Vector<T, N>>
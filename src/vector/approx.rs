@@ -3,9 +3,10 @@ use approx::{abs_diff_eq, AbsDiffEq};
 
 impl<T, const N: usize> AbsDiffEq for Vector<T, N>
 where
-    T: AbsDiffEq<Epsilon = T> + Copy,
+    T: AbsDiffEq + Copy,
+    T::Epsilon: Copy,
 {
-    type Epsilon = T;
+    type Epsilon = T::Epsilon;
     fn default_epsilon() -> Self::Epsilon {
         T::default_epsilon()
     }
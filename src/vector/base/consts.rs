@@ -0,0 +1,49 @@
+use super::Vector;
+use num_traits::{ConstOne, ConstZero};
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: ConstZero + Copy,
+{
+    /// Vector with all elements equal to zero.
+    pub const ZERO: Self = Self::from_array([T::ZERO; N]);
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: ConstZero + ConstOne + Copy,
+{
+    /// Unit vector along `i`-th axis.
+    pub const fn unit(i: usize) -> Self {
+        let mut array = [T::ZERO; N];
+        array[i] = T::ONE;
+        Self::from_array(array)
+    }
+}
+
+impl<T> Vector<T, 2>
+where
+    T: ConstZero + ConstOne + Copy,
+{
+    pub const X: Self = Self::unit(0);
+    pub const Y: Self = Self::unit(1);
+}
+
+impl<T> Vector<T, 3>
+where
+    T: ConstZero + ConstOne + Copy,
+{
+    pub const X: Self = Self::unit(0);
+    pub const Y: Self = Self::unit(1);
+    pub const Z: Self = Self::unit(2);
+}
+
+impl<T> Vector<T, 4>
+where
+    T: ConstZero + ConstOne + Copy,
+{
+    pub const X: Self = Self::unit(0);
+    pub const Y: Self = Self::unit(1);
+    pub const Z: Self = Self::unit(2);
+    pub const W: Self = Self::unit(3);
+}
@@ -77,7 +77,7 @@ where
 
 impl<T, const N: usize> Vector<T, N> {
     /// Create from array.
-    pub fn from_array(array: [T; N]) -> Self {
+    pub const fn from_array(array: [T; N]) -> Self {
         Self { data: array }
     }
     /// Convert to array.
@@ -1,3 +1,4 @@
+mod consts;
 mod format;
 mod init;
 mod iter;
@@ -133,3 +133,21 @@ mod iter {
         }
     }
 }
+
+mod consts {
+    use super::*;
+    use num_traits::Zero;
+
+    const A: Vector3<i32> = Vector::from_array([1, 2, 3]);
+    const ZERO: Vector4<f64> = Vector::ZERO;
+    const Z: Vector3<f32> = Vector3::Z;
+
+    #[test]
+    fn consts() {
+        assert_eq!(A, Vector::from([1, 2, 3]));
+        assert_eq!(ZERO, Vector4::zero());
+        assert_eq!(Z, Vector::from([0.0, 0.0, 1.0]));
+        assert_eq!(Vector2::<u8>::X + Vector2::Y, Vector::from([1, 1]));
+        assert_eq!(Vector4::<i64>::W, Vector4::unit(3));
+    }
+}
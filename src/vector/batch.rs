@@ -0,0 +1,375 @@
+use crate::{
+    traits::{Dot, Normalize},
+    Matrix, Vector,
+};
+use core::{
+    convert::TryFrom,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+use num_traits::{Float, Zero};
+
+/// Batch of `L` vectors of dimension `N` stored as structure of arrays.
+///
+/// Each of `N` components is stored in a separate lane vector of length `L`,
+/// so operations on the batch are performed component-wise over contiguous arrays.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VectorBatch<T, const N: usize, const L: usize> {
+    lanes: Vector<Vector<T, L>, N>,
+}
+
+impl<T, const N: usize, const L: usize> VectorBatch<T, N, L> {
+    /// Construct batch from lanes, `lanes[i]` contains `i`-th components of all vectors.
+    pub fn from_lanes(lanes: Vector<Vector<T, L>, N>) -> Self {
+        Self { lanes }
+    }
+    pub fn into_lanes(self) -> Vector<Vector<T, L>, N> {
+        self.lanes
+    }
+    /// Lane containing `i`-th components of all vectors.
+    pub fn lane(&self, i: usize) -> &Vector<T, L> {
+        &self.lanes[i]
+    }
+    pub fn lane_mut(&mut self, i: usize) -> &mut Vector<T, L> {
+        &mut self.lanes[i]
+    }
+}
+
+impl<T, const N: usize, const L: usize> VectorBatch<T, N, L>
+where
+    T: Copy,
+{
+    /// Batch of `L` copies of the same vector.
+    pub fn fill(vec: Vector<T, N>) -> Self {
+        Self::from_lanes(vec.map(Vector::fill))
+    }
+    /// Construct batch from array of vectors.
+    pub fn from_vectors(vecs: [Vector<T, N>; L]) -> Self {
+        Self::from_lanes(Vector::indices().map(|i| Vector::indices().map(|j| vecs[j][i])))
+    }
+    /// Convert batch to array of vectors.
+    pub fn into_vectors(self) -> [Vector<T, N>; L] {
+        Vector::<usize, L>::indices()
+            .map(|j| self.get(j))
+            .into_array()
+    }
+    /// Load batch from slice of vectors, the length of the slice must be equal to `L`.
+    pub fn from_slice(slice: &[Vector<T, N>]) -> Option<Self> {
+        if slice.len() == L {
+            Some(Self::from_lanes(
+                Vector::indices().map(|i| Vector::indices().map(|j| slice[j][i])),
+            ))
+        } else {
+            None
+        }
+    }
+    /// Store batch to slice of vectors, only first `min(L, slice.len())` vectors are written.
+    pub fn store(&self, slice: &mut [Vector<T, N>]) {
+        for (j, v) in slice.iter_mut().enumerate().take(L) {
+            *v = self.get(j);
+        }
+    }
+    /// Get `j`-th vector of the batch.
+    pub fn get(&self, j: usize) -> Vector<T, N> {
+        self.lanes.map(|lane| lane[j])
+    }
+    /// Replace `j`-th vector of the batch.
+    pub fn set(&mut self, j: usize, vec: Vector<T, N>) {
+        for (lane, x) in self.lanes.iter_mut().zip(vec) {
+            lane[j] = x;
+        }
+    }
+
+    /// Apply function to all vectors of the slice processing them in batches of `L`.
+    ///
+    /// The last batch is padded by the copies of its first vector if the slice length isn't multiple of `L`.
+    /// Does nothing if `L` is zero.
+    pub fn process_slice<F: FnMut(Self) -> Self>(slice: &mut [Vector<T, N>], mut f: F) {
+        if L == 0 {
+            return;
+        }
+        for chunk in slice.chunks_mut(L) {
+            let mut batch = Self::fill(chunk[0]);
+            for (j, v) in chunk.iter().enumerate() {
+                batch.set(j, *v);
+            }
+            f(batch).store(chunk);
+        }
+    }
+}
+
+impl<T, const N: usize, const L: usize> TryFrom<&[Vector<T, N>]> for VectorBatch<T, N, L>
+where
+    T: Copy,
+{
+    type Error = ();
+    fn try_from(slice: &[Vector<T, N>]) -> Result<Self, ()> {
+        Self::from_slice(slice).ok_or(())
+    }
+}
+
+impl<T, const N: usize, const L: usize> From<[Vector<T, N>; L]> for VectorBatch<T, N, L>
+where
+    T: Copy,
+{
+    fn from(vecs: [Vector<T, N>; L]) -> Self {
+        Self::from_vectors(vecs)
+    }
+}
+impl<T, const N: usize, const L: usize> From<VectorBatch<T, N, L>> for [Vector<T, N>; L]
+where
+    T: Copy,
+{
+    fn from(batch: VectorBatch<T, N, L>) -> Self {
+        batch.into_vectors()
+    }
+}
+
+impl<T, const N: usize, const L: usize> Neg for VectorBatch<T, N, L>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_lanes(-self.lanes)
+    }
+}
+impl<T, const N: usize, const L: usize> Add for VectorBatch<T, N, L>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::from_lanes(self.lanes + other.lanes)
+    }
+}
+impl<T, const N: usize, const L: usize> Sub for VectorBatch<T, N, L>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::from_lanes(self.lanes - other.lanes)
+    }
+}
+impl<T, const N: usize, const L: usize> Add<Vector<T, N>> for VectorBatch<T, N, L>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+    /// Add the same vector to each vector of the batch.
+    fn add(self, vec: Vector<T, N>) -> Self {
+        Self::from_lanes(self.lanes.zip(vec).map(|(lane, x)| lane + Vector::fill(x)))
+    }
+}
+impl<T, const N: usize, const L: usize> Sub<Vector<T, N>> for VectorBatch<T, N, L>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+    /// Subtract the same vector from each vector of the batch.
+    fn sub(self, vec: Vector<T, N>) -> Self {
+        Self::from_lanes(self.lanes.zip(vec).map(|(lane, x)| lane - Vector::fill(x)))
+    }
+}
+impl<T, const N: usize, const L: usize> Mul<T> for VectorBatch<T, N, L>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, a: T) -> Self {
+        Self::from_lanes(self.lanes.map(|lane| lane * a))
+    }
+}
+impl<T, const N: usize, const L: usize> Div<T> for VectorBatch<T, N, L>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    fn div(self, a: T) -> Self {
+        Self::from_lanes(self.lanes.map(|lane| lane / a))
+    }
+}
+impl<T, const N: usize, const L: usize> Mul<Vector<T, L>> for VectorBatch<T, N, L>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    /// Multiply each vector of the batch by its own scalar.
+    fn mul(self, a: Vector<T, L>) -> Self {
+        Self::from_lanes(self.lanes.map(|lane| lane * a))
+    }
+}
+impl<T, const N: usize, const L: usize> Div<Vector<T, L>> for VectorBatch<T, N, L>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    /// Divide each vector of the batch by its own scalar.
+    fn div(self, a: Vector<T, L>) -> Self {
+        Self::from_lanes(self.lanes.map(|lane| lane / a))
+    }
+}
+impl<T, const N: usize, const L: usize> AddAssign for VectorBatch<T, N, L>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.lanes += other.lanes;
+    }
+}
+impl<T, const N: usize, const L: usize> SubAssign for VectorBatch<T, N, L>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.lanes -= other.lanes;
+    }
+}
+
+impl<T, const N: usize, const L: usize> Zero for VectorBatch<T, N, L>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self::from_lanes(Vector::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.lanes.is_zero()
+    }
+}
+
+impl<T, const N: usize, const L: usize> Dot for VectorBatch<T, N, L>
+where
+    T: Mul<Output = T> + Add<Output = T> + Copy,
+{
+    type Output = Vector<T, L>;
+    /// Dot products of corresponding vectors.
+    fn dot(self, other: Self) -> Vector<T, L> {
+        self.lanes
+            .zip(other.lanes)
+            .map(|(a, b)| a * b)
+            .fold_first(|a, b| a + b)
+    }
+}
+
+impl<T, const M: usize, const N: usize, const L: usize> Dot<VectorBatch<T, N, L>>
+    for Matrix<T, M, N>
+where
+    T: Mul<Output = T> + Add<Output = T> + Copy,
+{
+    type Output = VectorBatch<T, M, L>;
+    /// Product of the matrix by each vector of the batch.
+    fn dot(self, batch: VectorBatch<T, N, L>) -> VectorBatch<T, M, L> {
+        VectorBatch::from_lanes(Vector::indices().map(|i| {
+            let row = self.row(i);
+            batch
+                .lanes
+                .zip(row)
+                .map(|(lane, x)| lane * x)
+                .fold_first(|a, b| a + b)
+        }))
+    }
+}
+
+impl<T, const N: usize, const L: usize> VectorBatch<T, N, L>
+where
+    T: Mul<Output = T> + Add<Output = T> + Copy,
+{
+    /// Squared lengths of the vectors.
+    pub fn square_length(self) -> Vector<T, L> {
+        self.dot(self)
+    }
+}
+
+impl<T, const N: usize, const L: usize> VectorBatch<T, N, L>
+where
+    T: Float,
+{
+    /// Lengths of the vectors.
+    pub fn length(self) -> Vector<T, L> {
+        self.square_length().map(T::sqrt)
+    }
+}
+
+impl<T, const N: usize, const L: usize> Normalize for VectorBatch<T, N, L>
+where
+    T: Float,
+{
+    /// Normalize each vector of the batch.
+    fn normalize(self) -> Self {
+        self / self.length()
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::distr::Normal;
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 64;
+
+    type B = VectorBatch<f64, 3, 8>;
+
+    fn sample(rng: &mut XorShiftRng) -> [Vector<f64, 3>; 8] {
+        Vector::<Vector<f64, 3>, 8>::init(|| rng.sample(&Normal)).into_array()
+    }
+
+    #[test]
+    fn convert() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBA7C);
+        let vs = sample(&mut rng);
+        let b = B::from_vectors(vs);
+        for (j, v) in vs.iter().enumerate() {
+            assert_eq!(b.get(j), *v);
+            assert_eq!(b.lane(1)[j], v[1]);
+        }
+        assert_eq!(b.into_vectors(), vs);
+        assert_eq!(B::try_from(&vs[..]), Ok(b));
+        assert!(B::try_from(&vs[..7]).is_err());
+
+        let mut out = [Vector::zero(); 8];
+        b.store(&mut out);
+        assert_eq!(out, vs);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBA7D);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (xs, ys) = (sample(&mut rng), sample(&mut rng));
+            let (a, b) = (B::from(xs), B::from(ys));
+            let c: Vector<f64, 3> = rng.sample(&Normal);
+            let m: Matrix<f64, 2, 3> = rng.sample(&Normal);
+            for j in 0..8 {
+                let (x, y) = (xs[j], ys[j]);
+                assert_abs_diff_eq!((a + b).get(j), x + y);
+                assert_abs_diff_eq!((a - b).get(j), x - y);
+                assert_abs_diff_eq!((-a + c).get(j), c - x);
+                assert_abs_diff_eq!((a * 2.0).get(j), x * 2.0);
+                assert_abs_diff_eq!(a.dot(b)[j], x.dot(y), epsilon = 1e-12);
+                assert_abs_diff_eq!(a.length()[j], x.length(), epsilon = 1e-12);
+                assert_abs_diff_eq!(a.normalize().get(j), x.normalize(), epsilon = 1e-12);
+                assert_abs_diff_eq!(m.dot(a).get(j), m.dot(x), epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn process_slice() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBA7E);
+        let vs: [Vector<f64, 3>; 8] = sample(&mut rng);
+        let mut out = vs;
+        VectorBatch::<f64, 3, 3>::process_slice(&mut out, |b| b * 2.0);
+        for (x, y) in vs.iter().zip(out.iter()) {
+            assert_abs_diff_eq!(*x * 2.0, *y);
+        }
+        VectorBatch::<f64, 3, 0>::process_slice(&mut out, |b| b * 2.0);
+        for (x, y) in vs.iter().zip(out.iter()) {
+            assert_abs_diff_eq!(*x * 2.0, *y);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{traits::{Dot, Normalize}, Vector};
+use crate::{traits::{Conj, Dot, Normalize}, Vector};
 use core::ops::{Add, Mul};
 use num_traits::Float;
 
@@ -12,6 +12,28 @@ where
     }
 }
 
+impl<T, const N: usize> Conj for Vector<T, N>
+where
+    T: Conj,
+{
+    /// Conjugate each component of the vector.
+    fn conj(self) -> Self {
+        self.map(T::conj)
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Conj + Mul<Output = T> + Add<Output = T>,
+{
+    /// Conjugate-linear dot product, i.e. `conj(self)` dotted with `other`.
+    ///
+    /// For real vectors it is the same as `dot`.
+    pub fn vdot(self, other: Vector<T, N>) -> T {
+        self.conj().dot(other)
+    }
+}
+
 impl<T, const N: usize> Vector<T, N>
 where
     T: Add<Output = T> + Mul<Output = T> + Copy,
@@ -1,6 +1,7 @@
 #[cfg(feature = "approx")]
 mod approx;
 mod base;
+mod batch;
 #[cfg(feature = "rand")]
 mod distr;
 mod dot;
@@ -11,6 +12,7 @@ mod tests;
 mod math;
 
 pub use base::*;
+pub use batch::*;
 #[cfg(feature = "rand")]
 pub use distr::*;
 